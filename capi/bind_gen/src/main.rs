@@ -119,6 +119,8 @@ fn get_type(ty: &SynType) -> Type {
                     name = String::from("u8");
                 } else if name == "TimerPhase" {
                     name = String::from("u8");
+                } else if name == "LaterSplitTimes" {
+                    name = String::from("u8");
                 }
                 let is_custom = match &name as &str {
                     "u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64" | "()" | "bool"
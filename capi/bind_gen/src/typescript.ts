@@ -1,14 +1,24 @@
 /** The state object for one of the components available. */
 export type ComponentStateJson =
     { BlankSpace: BlankSpaceComponentStateJson } |
+    { Clock: ClockComponentStateJson } |
+    { ComparisonTable: ComparisonTableComponentStateJson } |
+    { Counter: CounterComponentStateJson } |
     { CurrentComparison: CurrentComparisonComponentStateJson } |
     { CurrentPace: CurrentPaceComponentStateJson } |
     { Delta: DeltaComponentStateJson } |
     { DetailedTimer: DetailedTimerComponentStateJson } |
+    { FinishTime: FinishTimeComponentStateJson } |
     { Graph: GraphComponentStateJson } |
+    { Image: ImageComponentStateJson } |
     { PbChance: PbChanceComponentStateJson } |
     { PossibleTimeSave: PossibleTimeSaveComponentStateJson } |
     { PreviousSegment: PreviousSegmentComponentStateJson } |
+    { RunHistory: RunHistoryComponentStateJson } |
+    { RunMetadata: RunMetadataComponentStateJson } |
+    { SegmentPotential: SegmentPotentialComponentStateJson } |
+    { SegmentTime: SegmentTimeComponentStateJson } |
+    { SegmentTrend: SegmentTrendComponentStateJson } |
     { Separator: null } |
     { Splits: SplitsComponentStateJson } |
     { SumOfBest: SumOfBestComponentStateJson } |
@@ -92,6 +102,28 @@ export enum TimerPhase {
     Paused = 3,
 }
 
+/**
+ * Describes what happens to the split times of the later segments when the
+ * split time of a segment gets corrected.
+ */
+export enum LaterSplitTimes {
+    /**
+     * The split times of the later segments are kept as they are. The
+     * correction is rejected if they wouldn't be in order anymore.
+     */
+    Keep = 0,
+    /**
+     * The split times of the later segments are shifted by the same amount as
+     * the corrected split time, so that their segment times are kept.
+     */
+    Shift = 1,
+    /**
+     * The split times of the later segments that would be lower than the
+     * corrected split time are cleared, as if the segments were skipped.
+     */
+    Clear = 2,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface BlankSpaceComponentStateJson {
     /** The background shown behind the component. */
@@ -118,6 +150,35 @@ export interface TimerComponentStateJson {
     height: number,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface ImageComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The image encoded as a Data URL. This value is only specified whenever
+     * the image changes. If you explicitly want to query this value, remount
+     * the component. The String itself may be empty. This indicates that there
+     * is no image.
+     */
+    image_change: string | null,
+    /**
+     * The unique ID of the image. Since the image itself is only provided
+     * whenever it changes, this can be used to associate a cached version of
+     * the image with the component, even if the components of the layout get
+     * reordered.
+     */
+    image_id: number,
+    /** Specifies how the image is scaled to the size of the component. */
+    scaling: ImageScaling,
+    /**
+     * Specifies whether the image should be centered or aligned to the left
+     * instead, if it doesn't fill the whole width of the component.
+     */
+    is_centered: boolean,
+    /** The height of the component. */
+    height: number,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface TitleComponentStateJson {
     /** The background shown behind the component. */
@@ -162,6 +223,117 @@ export interface TitleComponentStateJson {
     attempts: number | null,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface RunMetadataComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the values. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /**
+     * The rows to show, one for each of the values that are enabled and
+     * specified by the run's metadata.
+     */
+    rows: RunMetadataComponentStateRowJson[],
+    /**
+     * Specifies whether to display the label of each row and its value in two
+     * separate rows.
+     */
+    display_two_rows: boolean,
+}
+
+/** The state object of a single row of the component. */
+export interface RunMetadataComponentStateRowJson {
+    /** The label of the row. */
+    label: string,
+    /** The value of the row. */
+    value: string,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface SegmentPotentialComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the value. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /**
+     * The label's text, which includes the name of the segment with the most
+     * potential if there is one.
+     */
+    text: string,
+    /**
+     * The time that is expected to be saved on the segment and the probability
+     * of getting a new best segment on it.
+     */
+    value: string,
+    /**
+     * Specifies whether to display the name of the component and its value in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+}
+
+/**
+ * The state object describes the information to visualize for this component.
+ * All the coordinates are in the range 0..1.
+ */
+export interface SegmentTrendComponentStateJson {
+    /** The name of the segment that is visualized. */
+    segment_name: string,
+    /**
+     * The individual segment times. Each of them is to be visualized as a
+     * separate point.
+     */
+    times: SegmentTrendComponentStatePointJson[],
+    /** The points of the rolling average. Connect all of them to visualize it. */
+    rolling: SegmentTrendComponentStatePointJson[],
+    /**
+     * The points of the best segment time so far. Connect all of them to
+     * visualize it.
+     */
+    best: SegmentTrendComponentStatePointJson[],
+    /**
+     * The points of the trend. Connect all of them to visualize it. This is
+     * empty if there is no trend.
+     */
+    trend: SegmentTrendComponentStatePointJson[],
+    /** The background color of the graph. */
+    background_color: Color,
+    /** The color of the individual segment times. */
+    times_color: Color,
+    /** The color of the rolling average. */
+    rolling_color: Color,
+    /** The color of the best segment time so far. */
+    best_color: Color,
+    /** The color of the trend. */
+    trend_color: Color,
+    /** The height of the graph. */
+    height: number,
+}
+
+/** Describes a point on the graph to visualize. */
+export interface SegmentTrendComponentStatePointJson {
+    /** The x coordinate of the point. */
+    x: number,
+    /** The y coordinate of the point. */
+    y: number,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface SplitsComponentStateJson {
     /** The background shown behind the splits. */
@@ -288,6 +460,40 @@ export interface PreviousSegmentComponentStateJson {
     display_two_rows: boolean,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface SegmentTimeComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /** The label's text. */
+    text: string,
+    /** The live segment time. */
+    time: string,
+    /** The semantic coloring information the live segment time carries. */
+    semantic_color: SemanticColor,
+    /** The visual color of the live segment time. */
+    visual_color: Color,
+    /**
+     * The comparison's segment time. This is `null` if it's not supposed to be
+     * visualized.
+     */
+    comparison_time: string | null,
+    /**
+     * The best segment time. This is `null` if it's not supposed to be
+     * visualized.
+     */
+    best_time: string | null,
+    /**
+     * Specifies whether to display the name of the component and its value in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface SumOfBestComponentStateJson {
     /** The background shown behind the component. */
@@ -363,6 +569,31 @@ export interface PossibleTimeSaveComponentStateJson {
     display_two_rows: boolean,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface FinishTimeComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the value. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The label's text. */
+    text: string,
+    /** The predicted final times. */
+    finish_time: string,
+    /**
+     * Specifies whether to display the name of the component and its value in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+}
+
 /**
  * The state object describes the information to visualize for this component.
  * All the coordinates are in the range 0..1.
@@ -424,6 +655,12 @@ export interface GraphComponentStateJson {
     best_segment_color: Color,
     /** The height of the graph. */
     height: number,
+    /**
+     * Describes whether the graph is a bar graph. In that case every two
+     * consecutive points describe the top edge of a bar. The points are not
+     * meant to be visualized as circles then.
+     */
+    is_bar_graph: boolean,
 }
 
 /** Describes a point on the graph to visualize. */
@@ -439,6 +676,59 @@ export interface GraphComponentStatePointJson {
     is_best_segment: boolean,
 }
 
+/**
+ * The state object describes the information to visualize for this component.
+ * All the coordinates are in the range 0..1.
+ */
+export interface RunHistoryComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The points of the sparkline, one for each finished attempt shown, from
+     * the oldest to the most recent attempt. Connect all of them to visualize
+     * the sparkline. Longer final times are further up.
+     */
+    points: RunHistoryComponentStatePointJson[],
+    /**
+     * The y coordinate of the Personal Best's final time. This is meant to be
+     * visualized as a horizontal marker line. If there is no Personal Best,
+     * this is null.
+     */
+    personal_best: number | null,
+    /**
+     * The point describing the time the current attempt is projected to finish
+     * with. This is meant to be connected to the last point of the sparkline.
+     * If there is no active attempt or the projected time is not supposed to
+     * be shown, this is null.
+     */
+    projected: RunHistoryComponentStatePointJson | null,
+    /** The color of the sparkline. */
+    line_color: Color,
+    /**
+     * The color of the Personal Best marker and of the points of attempts that
+     * finished with the Personal Best's final time.
+     */
+    personal_best_color: Color,
+    /** The color of the projected time of the current attempt. */
+    projected_color: Color,
+    /** The height of the component. */
+    height: number,
+}
+
+/** Describes a point of the sparkline to visualize. */
+export interface RunHistoryComponentStatePointJson {
+    /** The x coordinate of the point. */
+    x: number,
+    /** The y coordinate of the point. */
+    y: number,
+    /**
+     * Describes whether the attempt this point is visualizing finished with the
+     * Personal Best's final time. Use the Personal Best color for it, in that
+     * case.
+     */
+    is_personal_best: boolean,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface TextComponentStateJson {
     /** The background shown behind the component. */
@@ -494,6 +784,98 @@ export interface TotalPlaytimeComponentStateJson {
     display_two_rows: boolean,
 }
 
+/** The state object describes the information to visualize for this component. */
+export interface ClockComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the values. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The rows to show, one for each of the values that are enabled. */
+    rows: ClockComponentStateRowJson[],
+}
+
+/** The state object of a single row of the component. */
+export interface ClockComponentStateRowJson {
+    /** The label of the row. */
+    label: string,
+    /** The value of the row. */
+    value: string,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface ComparisonTableComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the labels. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The name of the split the table is about. This is meant to be shown
+     * above the names of the comparisons.
+     */
+    split_name: string,
+    /**
+     * The labels of the columns of the table. They are meant to be shown above
+     * the cells of the rows.
+     */
+    column_labels: string[],
+    /** The rows of the table, one for each comparison. */
+    rows: ComparisonTableRowStateJson[],
+}
+
+/** The state object of a single row of the table. */
+export interface ComparisonTableRowStateJson {
+    /** The shortened name of the comparison. */
+    name: string,
+    /** The cells of the row. There is one cell for each column label. */
+    cells: ComparisonTableCellStateJson[],
+}
+
+/** The state object of a single cell of the table. */
+export interface ComparisonTableCellStateJson {
+    /** The value shown in the cell. */
+    value: string,
+    /** The semantic coloring information the value carries. */
+    semantic_color: SemanticColor,
+    /** The visual color of the value. */
+    visual_color: Color,
+}
+
+/** The state object describes the information to visualize for this component. */
+export interface CounterComponentStateJson {
+    /** The background shown behind the component. */
+    background: Gradient,
+    /**
+     * The color of the label. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    label_color: Color | null,
+    /**
+     * The color of the value. If `null` is specified, the color is taken from
+     * the layout.
+     */
+    value_color: Color | null,
+    /** The label's text. */
+    text: string,
+    /** The current value of the counter. */
+    value: string,
+    /**
+     * Specifies whether to display the name of the component and its value in
+     * two separate rows.
+     */
+    display_two_rows: boolean,
+}
+
 /** The state object describes the information to visualize for this component. */
 export interface CurrentPaceComponentStateJson {
     /** The background shown behind the component. */
@@ -621,6 +1003,11 @@ export interface LayoutEditorStateJson {
      * component and their current values.
      */
     component_settings: SettingsDescriptionJson,
+    /**
+     * A generic description of the conditions under which the selected
+     * component is visible and their current values.
+     */
+    component_visibility: SettingsDescriptionJson,
     /**
      * A generic description of the general settings available for the layout
      * and their current values.
@@ -692,8 +1079,17 @@ export type SettingsDescriptionValueJson =
     { ColumnUpdateTrigger: ColumnUpdateTrigger } |
     { Hotkey: string } |
     { LayoutDirection: LayoutDirection } |
+    { Image: string } |
+    { ImageScaling: ImageScaling } |
+    { GraphMode: GraphMode } |
     { CustomCombobox: CustomCombobox };
 
+/** Describes how an image is scaled to the size of the component. */
+export type ImageScaling = "Fit" | "Stretch";
+
+/** Describes what the Graph Component visualizes. */
+export type GraphMode = "Delta" | "SegmentDelta" | "PossibleTimeSave" | "History";
+
 /** Describes the direction the components of a layout are laid out in. */
 export type LayoutDirection = "Vertical" | "Horizontal";
 
@@ -715,7 +1111,13 @@ export type ColumnStartWith =
     "Empty" |
     "ComparisonTime" |
     "ComparisonSegmentTime" |
-    "PossibleTimeSave";
+    "PossibleTimeSave" |
+    "GoldProbability" |
+    "ExpectedTimeSave" |
+    "BestSegmentTime" |
+    "AverageSegmentTime" |
+    "ResetCount" |
+    "ReachedCount";
 
 /**
  * Once a certain condition is met, which is usually being on the split or
@@ -729,7 +1131,8 @@ export type ColumnUpdateWith =
     "DeltaWithFallback" |
     "SegmentTime" |
     "SegmentDelta" |
-    "SegmentDeltaWithFallback";
+    "SegmentDeltaWithFallback" |
+    "BestSegmentDelta";
 
 /** Specifies when a column's value gets updated. */
 export type ColumnUpdateTrigger =
@@ -903,3 +1306,51 @@ export type SemanticColor = "Default" |
     "NotRunning" |
     "Paused" |
     "PersonalBest";
+
+/** Describes where the attempts of a Run got reset. */
+export interface ResetPointsJson {
+    /** The amount of attempts that are considered. */
+    attempts: number,
+    /** The amount of attempts that completed every segment. */
+    finished: number,
+    /** How the attempts progressed through each of the segments. */
+    segments: SegmentResetsJson[],
+}
+
+/** Describes how the attempts progressed through a single segment. */
+export interface SegmentResetsJson {
+    /** The amount of attempts that reached the segment. */
+    reached: number,
+    /** The amount of attempts that got reset during the segment. */
+    resets: number,
+    /**
+     * The share of all the attempts that completed the segment, as a value
+     * from 0 (0%) to 1 (100%).
+     */
+    survival: number,
+}
+
+/** Describes the statistics of a single session. */
+export interface SessionJson {
+    /** The index of the first attempt of the session. */
+    first_attempt: number,
+    /** The index of the last attempt of the session. */
+    last_attempt: number,
+    /** The point in time the session started at as an RFC 3339 string. */
+    started: string | null,
+    /** The point in time the session ended at as an RFC 3339 string. */
+    ended: string | null,
+    /** The amount of attempts in the session. */
+    attempts: number,
+    /** The amount of attempts in the session that got finished. */
+    completed_runs: number,
+    /** The total playtime of all the attempts in the session in seconds. */
+    playtime: number,
+    /**
+     * The amount of segment times in the session that were faster than all
+     * the segment times achieved before them.
+     */
+    golds: number,
+    /** The fastest final time of all the attempts in the session in seconds. */
+    best_time: number | null,
+}
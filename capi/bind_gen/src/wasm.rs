@@ -438,6 +438,11 @@ export async function load(path?: string) {
                 u32Slice[1] = secondsHigh;
                 u32Slice[2] = nanos;
             },
+            Date_utc_offset: function (seconds: number): number {
+                // The offset is specified in minutes west of UTC, but seconds
+                // east of UTC are expected.
+                return -new Date(seconds * 1000).getTimezoneOffset() * 60;
+            },
             HotkeyHook_new: function (handle: number) {
                 const listener = (ev: KeyboardEvent) => {
                     const { ptr, len } = allocString(ev.code);
@@ -611,6 +616,11 @@ exports.load = async function (path) {
                 u32Slice[1] = secondsHigh;
                 u32Slice[2] = nanos;
             },
+            Date_utc_offset: function (seconds) {
+                // The offset is specified in minutes west of UTC, but seconds
+                // east of UTC are expected.
+                return -new Date(seconds * 1000).getTimezoneOffset() * 60;
+            },
             HotkeyHook_new: function (handle) {
                 const listener = (ev: KeyboardEvent) => {
                     const { ptr, len } = allocString(ev.code);
@@ -2,10 +2,12 @@
 //! information about runs.
 
 use super::time_span::{NullableOwnedTimeSpan, OwnedTimeSpan};
+use super::{output_vec, Json};
 use livesplit_core::analysis::sum_of_segments::calculate_best;
 use livesplit_core::analysis::total_playtime::calculate;
-use livesplit_core::TimingMethod;
+use livesplit_core::analysis::{finish_time, reset_points, sessions};
 use livesplit_core::{Run, Timer};
+use livesplit_core::{TimeSpan, TimingMethod};
 
 /// Calculates the Sum of Best Segments for the timing method provided. This is
 /// the fastest time possible to complete a run of a category, based on
@@ -43,3 +45,72 @@ pub extern "C" fn Analysis_calculate_total_playtime_for_run(run: &Run) -> OwnedT
 pub extern "C" fn Analysis_calculate_total_playtime_for_timer(timer: &Timer) -> OwnedTimeSpan {
     Box::new(calculate(timer))
 }
+
+/// Predicts the final time of the Timer's current attempt that is beaten or
+/// tied with the probability provided. The probability is a value from 0 (0%)
+/// to 1 (100%). If there is no attempt in progress, the final time of a
+/// general attempt is predicted. Returns <NULL> if there are segments without
+/// any segment times in their history.
+#[no_mangle]
+pub extern "C" fn Analysis_predict_finish_time_for_timer(
+    timer: &Timer,
+    probability: f64,
+) -> NullableOwnedTimeSpan {
+    finish_time::for_timer(timer).map(|d| Box::new(d.quantile(probability)))
+}
+
+/// Calculates the chance to beat the target time provided with the final time
+/// of the Timer's current attempt. The chance is a value from 0 (0%) to 1
+/// (100%). If there is no attempt in progress, the chance of a general attempt
+/// is calculated. Returns a negative value if there are segments without any
+/// segment times in their history.
+#[no_mangle]
+pub extern "C" fn Analysis_chance_to_beat_for_timer(timer: &Timer, target: &TimeSpan) -> f64 {
+    finish_time::for_timer(timer).map_or(-1.0, |d| d.chance_to_beat(*target))
+}
+
+/// Calculates where the attempts of the passed Run got reset and encodes the
+/// result as JSON. For each segment it is determined how many attempts reached
+/// it, how many of them got reset during it and the share of all the attempts
+/// that survived it. The progress of an attempt is determined by the last
+/// segment that has a segment time for the timing method provided.
+#[no_mangle]
+pub extern "C" fn Analysis_reset_points_as_json(run: &Run, method: TimingMethod) -> Json {
+    output_vec(|o| {
+        serde_json::to_writer(o, &reset_points::calculate(run, method)).unwrap();
+    })
+}
+
+/// Groups the Attempt History of the Run into sessions and calculates
+/// statistics for each of them. The result is returned as a JSON array. A new
+/// session starts whenever an attempt started more than the idle gap provided
+/// in seconds after the previous attempt ended. The final times, the golds and
+/// the best time are based on the timing method provided.
+#[no_mangle]
+pub extern "C" fn Analysis_sessions_as_json(
+    run: &Run,
+    idle_gap_seconds: f64,
+    method: TimingMethod,
+) -> Json {
+    let sessions = sessions::calculate(run, TimeSpan::from_seconds(idle_gap_seconds), method);
+    let sessions = sessions
+        .into_iter()
+        .map(|s| {
+            serde_json::json!({
+                "first_attempt": s.first_attempt,
+                "last_attempt": s.last_attempt,
+                "started": s.started.map(|t| t.time.to_rfc3339()),
+                "ended": s.ended.map(|t| t.time.to_rfc3339()),
+                "attempts": s.attempts,
+                "completed_runs": s.completed_runs,
+                "playtime": s.playtime.total_seconds(),
+                "golds": s.golds,
+                "best_time": s.best_time.map(|t| t.total_seconds()),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    output_vec(|o| {
+        serde_json::to_writer(o, &sessions).unwrap();
+    })
+}
@@ -0,0 +1,48 @@
+//! The Clock Component shows the current local time, how long the current
+//! session has been going on since the first attempt of the day and how much
+//! time has been spent playing today.
+
+use super::{output_vec, Json};
+use crate::clock_component_state::OwnedClockComponentState;
+use crate::component::OwnedComponent;
+use livesplit_core::component::clock::Component as ClockComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedClockComponent = Box<ClockComponent>;
+
+/// Creates a new Clock Component.
+#[no_mangle]
+pub extern "C" fn ClockComponent_new() -> OwnedClockComponent {
+    Box::new(ClockComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ClockComponent_drop(this: OwnedClockComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn ClockComponent_into_generic(this: OwnedClockComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn ClockComponent_state_as_json(this: &ClockComponent, timer: &Timer) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn ClockComponent_state(
+    this: &ClockComponent,
+    timer: &Timer,
+) -> OwnedClockComponentState {
+    Box::new(this.state(timer))
+}
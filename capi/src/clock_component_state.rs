@@ -0,0 +1,40 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::clock::State as ClockComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedClockComponentState = Box<ClockComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ClockComponentState_drop(this: OwnedClockComponentState) {
+    drop(this);
+}
+
+/// The amount of rows to show, one for each of the values that are enabled.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_len(this: &ClockComponentState) -> usize {
+    this.rows.len()
+}
+
+/// The label of the row with the specified index. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_label(
+    this: &ClockComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].label)
+}
+
+/// The value of the row with the specified index. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn ClockComponentState_value(
+    this: &ClockComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].value)
+}
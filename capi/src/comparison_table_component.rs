@@ -0,0 +1,58 @@
+//! The Comparison Table Component is a component that shows a small table with
+//! the split time, the segment time and the delta of several comparisons at
+//! once for a single split. This way multiple Delta or Previous Segment
+//! Components don't need to be stacked in order to compare against multiple
+//! comparisons.
+
+use super::{output_vec, Json};
+use crate::comparison_table_component_state::OwnedComparisonTableComponentState;
+use crate::component::OwnedComponent;
+use livesplit_core::component::comparison_table::Component as ComparisonTableComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedComparisonTableComponent = Box<ComparisonTableComponent>;
+
+/// Creates a new Comparison Table Component.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponent_new() -> OwnedComparisonTableComponent {
+    Box::new(ComparisonTableComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponent_drop(this: OwnedComparisonTableComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponent_into_generic(
+    this: OwnedComparisonTableComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponent_state_as_json(
+    this: &ComparisonTableComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer, layout_settings).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponent_state(
+    this: &ComparisonTableComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedComparisonTableComponentState {
+    Box::new(this.state(timer, layout_settings))
+}
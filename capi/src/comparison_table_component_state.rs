@@ -0,0 +1,86 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, output_vec};
+use livesplit_core::component::comparison_table::State as ComparisonTableComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedComparisonTableComponentState = Box<ComparisonTableComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_drop(this: OwnedComparisonTableComponentState) {
+    drop(this);
+}
+
+/// The name of the split the table is about.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_split_name(
+    this: &ComparisonTableComponentState,
+) -> *const c_char {
+    output_str(&this.split_name)
+}
+
+/// The amount of columns of the table.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_columns_len(
+    this: &ComparisonTableComponentState,
+) -> usize {
+    this.column_labels.len()
+}
+
+/// The label of the column with the specified index. You may not provide an
+/// out of bounds index.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_column_label(
+    this: &ComparisonTableComponentState,
+    column_index: usize,
+) -> *const c_char {
+    output_str(&this.column_labels[column_index])
+}
+
+/// The amount of rows of the table. There is one row for each comparison.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_len(this: &ComparisonTableComponentState) -> usize {
+    this.rows.len()
+}
+
+/// The shortened name of the comparison of the row with the specified index.
+/// You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_name(
+    this: &ComparisonTableComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].name)
+}
+
+/// The value of the cell with the specified row and column index. You may not
+/// provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_cell_value(
+    this: &ComparisonTableComponentState,
+    index: usize,
+    column_index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].cells[column_index].value)
+}
+
+/// The semantic coloring information the value of the cell with the specified
+/// row and column index carries. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn ComparisonTableComponentState_cell_semantic_color(
+    this: &ComparisonTableComponentState,
+    index: usize,
+    column_index: usize,
+) -> *const c_char {
+    output_vec(|f| {
+        write!(
+            f,
+            "{:?}",
+            this.rows[index].cells[column_index].semantic_color
+        )
+        .unwrap()
+    })
+}
@@ -0,0 +1,62 @@
+//! The Counter Component is a component that shows a number that can be
+//! incremented, decremented and reset, like the amount of deaths or
+//! collectibles.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::counter_component_state::OwnedCounterComponentState;
+use livesplit_core::component::counter::Component as CounterComponent;
+
+/// type
+pub type OwnedCounterComponent = Box<CounterComponent>;
+
+/// Creates a new Counter Component.
+#[no_mangle]
+pub extern "C" fn CounterComponent_new() -> OwnedCounterComponent {
+    Box::new(CounterComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CounterComponent_drop(this: OwnedCounterComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn CounterComponent_into_generic(this: OwnedCounterComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn CounterComponent_state_as_json(this: &CounterComponent) -> Json {
+    output_vec(|o| {
+        this.state().write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state.
+#[no_mangle]
+pub extern "C" fn CounterComponent_state(this: &CounterComponent) -> OwnedCounterComponentState {
+    Box::new(this.state())
+}
+
+/// Increments the counter by the step configured.
+#[no_mangle]
+pub extern "C" fn CounterComponent_increment(this: &mut CounterComponent) {
+    this.increment();
+}
+
+/// Decrements the counter by the step configured.
+#[no_mangle]
+pub extern "C" fn CounterComponent_decrement(this: &mut CounterComponent) {
+    this.decrement();
+}
+
+/// Resets the counter to the start value configured.
+#[no_mangle]
+pub extern "C" fn CounterComponent_reset(this: &mut CounterComponent) {
+    this.reset();
+}
@@ -0,0 +1,26 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::counter::State as CounterComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedCounterComponentState = Box<CounterComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn CounterComponentState_drop(this: OwnedCounterComponentState) {
+    drop(this);
+}
+
+/// The label's text.
+#[no_mangle]
+pub extern "C" fn CounterComponentState_text(this: &CounterComponentState) -> *const c_char {
+    output_str(&this.text)
+}
+
+/// The current value of the counter.
+#[no_mangle]
+pub extern "C" fn CounterComponentState_value(this: &CounterComponentState) -> *const c_char {
+    output_str(&this.value)
+}
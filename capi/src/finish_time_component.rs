@@ -0,0 +1,54 @@
+//! The Finish Time Component is a component that shows the predicted final
+//! times of the current attempt for two probabilities, such as `50% ≤ 1:23:45,
+//! 90% ≤ 1:25:10`. If there is no active attempt it shows the predicted final
+//! times of a general attempt.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::finish_time_component_state::OwnedFinishTimeComponentState;
+use livesplit_core::component::finish_time::Component as FinishTimeComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedFinishTimeComponent = Box<FinishTimeComponent>;
+
+/// Creates a new Finish Time Component.
+#[no_mangle]
+pub extern "C" fn FinishTimeComponent_new() -> OwnedFinishTimeComponent {
+    Box::new(FinishTimeComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn FinishTimeComponent_drop(this: OwnedFinishTimeComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn FinishTimeComponent_into_generic(
+    this: OwnedFinishTimeComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn FinishTimeComponent_state_as_json(
+    this: &FinishTimeComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn FinishTimeComponent_state(
+    this: &FinishTimeComponent,
+    timer: &Timer,
+) -> OwnedFinishTimeComponentState {
+    Box::new(this.state(timer))
+}
@@ -0,0 +1,28 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::finish_time::State as FinishTimeComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedFinishTimeComponentState = Box<FinishTimeComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn FinishTimeComponentState_drop(this: OwnedFinishTimeComponentState) {
+    drop(this);
+}
+
+/// The label's text.
+#[no_mangle]
+pub extern "C" fn FinishTimeComponentState_text(this: &FinishTimeComponentState) -> *const c_char {
+    output_str(&this.text)
+}
+
+/// The predicted final times.
+#[no_mangle]
+pub extern "C" fn FinishTimeComponentState_finish_time(
+    this: &FinishTimeComponentState,
+) -> *const c_char {
+    output_str(&this.finish_time)
+}
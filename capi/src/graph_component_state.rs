@@ -102,3 +102,11 @@ pub extern "C" fn GraphComponentState_is_live_delta_active(this: &GraphComponent
 pub extern "C" fn GraphComponentState_is_flipped(this: &GraphComponentState) -> bool {
     this.is_flipped
 }
+
+/// Describes whether the graph is a bar graph. In that case every two
+/// consecutive points describe the top edge of a bar. The points are not meant
+/// to be visualized as circles then.
+#[no_mangle]
+pub extern "C" fn GraphComponentState_is_bar_graph(this: &GraphComponentState) -> bool {
+    this.is_bar_graph
+}
@@ -6,6 +6,7 @@
 
 use crate::hotkey_config::OwnedHotkeyConfig;
 use crate::shared_timer::OwnedSharedTimer;
+use livesplit_core::component::counter::Component as CounterComponent;
 use livesplit_core::HotkeySystem;
 
 /// type
@@ -68,3 +69,27 @@ pub extern "C" fn HotkeySystem_set_config(
 ) -> bool {
     this.set_config(*config).is_ok()
 }
+
+/// Connects the counter of the Counter Component provided to the Hotkey
+/// System, so that the hotkeys configured in the component's settings modify
+/// it. Any number of counters can be connected at the same time. If the hotkeys
+/// of a connected counter change, it needs to be connected again. Returns
+/// <FALSE> if one of the hotkeys couldn't be registered.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_connect_counter(
+    this: &mut HotkeySystem,
+    component: &CounterComponent,
+) -> bool {
+    this.connect_counter(component.counter().clone()).is_ok()
+}
+
+/// Disconnects the counter of the Counter Component provided from the Hotkey
+/// System, so that its hotkeys don't modify it anymore. Returns <FALSE> if the
+/// operation failed.
+#[no_mangle]
+pub extern "C" fn HotkeySystem_disconnect_counter(
+    this: &mut HotkeySystem,
+    component: &CounterComponent,
+) -> bool {
+    this.disconnect_counter(component.counter()).is_ok()
+}
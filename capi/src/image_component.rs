@@ -0,0 +1,55 @@
+//! The Image Component is a component that shows an arbitrary image, like a
+//! logo, a route map or a sponsor.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::image_component_state::OwnedImageComponentState;
+use livesplit_core::component::image::Component as ImageComponent;
+use std::slice;
+
+/// type
+pub type OwnedImageComponent = Box<ImageComponent>;
+
+/// Creates a new Image Component.
+#[no_mangle]
+pub extern "C" fn ImageComponent_new() -> OwnedImageComponent {
+    Box::new(ImageComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ImageComponent_drop(this: OwnedImageComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn ImageComponent_into_generic(this: OwnedImageComponent) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Sets the image to show. The image is shrunk if its width or height exceed
+/// the maximum image size. If the data is empty, no image is shown.
+#[no_mangle]
+pub unsafe extern "C" fn ImageComponent_set_image(
+    this: &mut ImageComponent,
+    data: *const u8,
+    length: usize,
+) {
+    this.set_image(slice::from_raw_parts(data, length));
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn ImageComponent_state_as_json(this: &mut ImageComponent) -> Json {
+    output_vec(|o| {
+        this.state().write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state.
+#[no_mangle]
+pub extern "C" fn ImageComponent_state(this: &mut ImageComponent) -> OwnedImageComponentState {
+    Box::new(this.state())
+}
@@ -0,0 +1,58 @@
+//! The state object describes the information to visualize for this component.
+
+use livesplit_core::component::image::{ImageScaling, State as ImageComponentState};
+use std::ptr;
+
+/// type
+pub type OwnedImageComponentState = Box<ImageComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn ImageComponentState_drop(this: OwnedImageComponentState) {
+    drop(this);
+}
+
+/// The data of the image. This value is only specified whenever the image
+/// changes. If you explicitly want to query this value, remount the component.
+/// The buffer may be empty. This indicates that there is no image. If no change
+/// occurred, <NULL> is returned instead.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_change_ptr(this: &ImageComponentState) -> *const u8 {
+    this.image_change
+        .as_ref()
+        .map_or_else(ptr::null, |i| i.as_ptr())
+}
+
+/// The length of the image data.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_change_len(this: &ImageComponentState) -> usize {
+    this.image_change.as_ref().map_or(0, |i| i.len())
+}
+
+/// The unique ID of the image. Since the image itself is only provided whenever
+/// it changes, this can be used to associate a cached version of the image with
+/// the component, even if the components of the layout get reordered.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_image_id(this: &ImageComponentState) -> usize {
+    this.image_id
+}
+
+/// Specifies whether the image should be stretched to fill the whole component,
+/// ignoring its aspect ratio.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_is_stretched(this: &ImageComponentState) -> bool {
+    this.scaling == ImageScaling::Stretch
+}
+
+/// Specifies whether the image should be centered or aligned to the left
+/// instead, if it doesn't fill the whole width of the component.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_is_centered(this: &ImageComponentState) -> bool {
+    this.is_centered
+}
+
+/// The height of the component.
+#[no_mangle]
+pub extern "C" fn ImageComponentState_height(this: &ImageComponentState) -> u32 {
+    this.height
+}
@@ -13,7 +13,13 @@ pub mod atomic_date_time;
 pub mod attempt;
 pub mod blank_space_component;
 pub mod blank_space_component_state;
+pub mod clock_component;
+pub mod clock_component_state;
+pub mod comparison_table_component;
+pub mod comparison_table_component_state;
 pub mod component;
+pub mod counter_component;
+pub mod counter_component_state;
 pub mod current_comparison_component;
 pub mod current_comparison_component_state;
 pub mod current_pace_component;
@@ -22,12 +28,16 @@ pub mod delta_component;
 pub mod delta_component_state;
 pub mod detailed_timer_component;
 pub mod detailed_timer_component_state;
+pub mod finish_time_component;
+pub mod finish_time_component_state;
 pub mod fuzzy_list;
 pub mod general_layout_settings;
 pub mod graph_component;
 pub mod graph_component_state;
 pub mod hotkey_config;
 pub mod hotkey_system;
+pub mod image_component;
+pub mod image_component_state;
 pub mod layout;
 pub mod layout_editor;
 pub mod parse_run_result;
@@ -40,13 +50,22 @@ pub mod previous_segment_component;
 pub mod previous_segment_component_state;
 pub mod run;
 pub mod run_editor;
+pub mod run_history_component;
+pub mod run_history_component_state;
 pub mod run_metadata;
+pub mod run_metadata_component;
+pub mod run_metadata_component_state;
 pub mod run_metadata_variable;
 pub mod run_metadata_variables_iter;
 pub mod segment;
 pub mod segment_history;
 pub mod segment_history_element;
 pub mod segment_history_iter;
+pub mod segment_potential_component;
+pub mod segment_potential_component_state;
+pub mod segment_time_component;
+pub mod segment_time_component_state;
+pub mod segment_trend_component;
 pub mod separator_component;
 pub mod setting_value;
 pub mod shared_timer;
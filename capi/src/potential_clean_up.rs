@@ -25,3 +25,20 @@ pub extern "C" fn PotentialCleanUp_drop(this: OwnedPotentialCleanUp) {
 pub extern "C" fn PotentialCleanUp_message(this: &PotentialCleanUp<'static>) -> *const c_char {
     output_vec(|s| write!(s, "{}", this).unwrap())
 }
+
+/// Returns whether the potential clean up is about a segment time that is far
+/// outside of the segment's usual segment times, rather than one that is faster
+/// than the Combined Best Segments.
+#[no_mangle]
+pub extern "C" fn PotentialCleanUp_is_outlier(this: &PotentialCleanUp<'static>) -> bool {
+    this.is_outlier()
+}
+
+/// Returns how confident the Sum of Best Cleaner is that the segment time is
+/// inaccurate, as a value from 0 (0%) to 1 (100%). Segment times that are
+/// faster than the Combined Best Segments are always reported with a confidence
+/// of 1.
+#[no_mangle]
+pub extern "C" fn PotentialCleanUp_confidence(this: &PotentialCleanUp<'static>) -> f64 {
+    this.confidence()
+}
@@ -5,8 +5,9 @@
 
 use super::{output_vec, str, Json};
 use crate::run::OwnedRun;
+use crate::setting_value::OwnedSettingValue;
 use crate::sum_of_best_cleaner::OwnedSumOfBestCleaner;
-use livesplit_core::{Run, RunEditor, TimingMethod};
+use livesplit_core::{DateTime, HistoryFilter, Run, RunEditor, TimingMethod, Utc};
 use std::os::raw::c_char;
 use std::slice;
 
@@ -330,6 +331,113 @@ pub unsafe extern "C" fn RunEditor_remove_comparison(
     this.remove_comparison(str(comparison));
 }
 
+/// Enables or disables the Comparison Generator with the name provided. A
+/// Comparison Generator that gets enabled starts out with its default
+/// settings. Returns `false` if there's no such Comparison Generator or if
+/// there's a custom comparison with the same name.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_set_comparison_generator_enabled(
+    this: &mut RunEditor,
+    name: *const c_char,
+    enabled: bool,
+) -> bool {
+    this.set_comparison_generator_enabled(str(name), enabled)
+        .is_ok()
+}
+
+/// Encodes the settings of the Comparison Generator with the name provided as
+/// JSON. If the Comparison Generator is not enabled, `null` is encoded
+/// instead.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_comparison_generator_settings_as_json(
+    this: &RunEditor,
+    name: *const c_char,
+) -> Json {
+    let settings = this.comparison_generator_settings_description(str(name));
+    output_vec(|o| {
+        serde_json::to_writer(o, &settings).unwrap();
+    })
+}
+
+/// Sets a setting's value of the Comparison Generator with the name provided
+/// by its setting index to the given value. If the Comparison Generator is not
+/// enabled, nothing happens.
+///
+/// This panics if the type of the value to be set is not compatible with the
+/// type of the setting's value. A panic can also occur if the index of the
+/// setting provided is out of bounds.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_set_comparison_generator_value(
+    this: &mut RunEditor,
+    name: *const c_char,
+    index: usize,
+    value: OwnedSettingValue,
+) {
+    this.set_comparison_generator_value(str(name), index, *value);
+}
+
+/// Sets the point in time from which on attempts are considered by the History
+/// Filter, encoded as an RFC 3339 date time. Attempts that started earlier are
+/// filtered out. An empty string removes this restriction. Returns <FALSE> if
+/// the date time couldn't be parsed.
+#[no_mangle]
+pub unsafe extern "C" fn RunEditor_set_history_filter_since(
+    this: &mut RunEditor,
+    since: *const c_char,
+) -> bool {
+    let since = str(since);
+    let since = if since.is_empty() {
+        None
+    } else if let Ok(since) = DateTime::parse_from_rfc3339(since) {
+        Some(since.with_timezone(&Utc))
+    } else {
+        return false;
+    };
+    let mut history_filter = this.history_filter().clone();
+    history_filter.set_since(since);
+    this.set_history_filter(history_filter);
+    true
+}
+
+/// Sets the amount of most recent attempts that are considered by the History
+/// Filter. 0 removes this restriction.
+#[no_mangle]
+pub extern "C" fn RunEditor_set_history_filter_last_attempts(
+    this: &mut RunEditor,
+    last_attempts: u32,
+) {
+    let mut history_filter = this.history_filter().clone();
+    history_filter.set_last_attempts(Some(last_attempts).filter(|&n| n != 0));
+    this.set_history_filter(history_filter);
+}
+
+/// Sets the index of the first attempt that is considered by the History
+/// Filter. As attempt indices start at 1, any index below 1 removes this
+/// restriction.
+#[no_mangle]
+pub extern "C" fn RunEditor_set_history_filter_since_attempt(
+    this: &mut RunEditor,
+    since_attempt: i32,
+) {
+    let mut history_filter = this.history_filter().clone();
+    history_filter.set_since_attempt(Some(since_attempt).filter(|&i| i >= 1));
+    this.set_history_filter(history_filter);
+}
+
+/// Flags or unflags the attempt with the index provided to be excluded by the
+/// History Filter.
+#[no_mangle]
+pub extern "C" fn RunEditor_set_attempt_excluded(this: &mut RunEditor, index: i32, excluded: bool) {
+    this.set_attempt_excluded(index, excluded);
+}
+
+/// Removes all the restrictions of the History Filter, so that all the attempts
+/// are considered again.
+#[no_mangle]
+pub extern "C" fn RunEditor_clear_history_filter(this: &mut RunEditor) {
+    this.set_history_filter(HistoryFilter::new());
+}
+
 /// Renames a comparison. The comparison can't be renamed if the new name of
 /// the comparison starts with `[Race]` or it already exists.
 #[no_mangle]
@@ -0,0 +1,57 @@
+//! The Run History Component visualizes the final times of the most recent
+//! finished attempts as a sparkline. The Personal Best and the time the
+//! current attempt is projected to finish with are marked as well, which gives
+//! a quick overview of how consistent the recent attempts have been.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::run_history_component_state::OwnedRunHistoryComponentState;
+use livesplit_core::component::run_history::Component as RunHistoryComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedRunHistoryComponent = Box<RunHistoryComponent>;
+
+/// Creates a new Run History Component.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponent_new() -> OwnedRunHistoryComponent {
+    Box::new(RunHistoryComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunHistoryComponent_drop(this: OwnedRunHistoryComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponent_into_generic(
+    this: OwnedRunHistoryComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponent_state_as_json(
+    this: &RunHistoryComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer, layout_settings).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and layout settings
+/// provided.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponent_state(
+    this: &RunHistoryComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedRunHistoryComponentState {
+    Box::new(this.state(timer, layout_settings))
+}
@@ -0,0 +1,95 @@
+//! The state object describes the information to visualize for this component.
+//! All the coordinates are in the range 0..1.
+
+use livesplit_core::component::run_history::State as RunHistoryComponentState;
+
+/// type
+pub type OwnedRunHistoryComponentState = Box<RunHistoryComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_drop(this: OwnedRunHistoryComponentState) {
+    drop(this);
+}
+
+/// Returns the amount of points of the sparkline, one for each finished attempt
+/// shown, from the oldest to the most recent attempt. Connect all of them to
+/// visualize the sparkline.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_points_len(this: &RunHistoryComponentState) -> usize {
+    this.points.len()
+}
+
+/// Returns the x coordinate of the point specified. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_point_x(
+    this: &RunHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.points[index].x
+}
+
+/// Returns the y coordinate of the point specified. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_point_y(
+    this: &RunHistoryComponentState,
+    index: usize,
+) -> f32 {
+    this.points[index].y
+}
+
+/// Describes whether the attempt the point specified is visualizing finished
+/// with the Personal Best's final time. Use the Personal Best color for it, in
+/// that case. You may not provide an out of bounds index.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_point_is_personal_best(
+    this: &RunHistoryComponentState,
+    index: usize,
+) -> bool {
+    this.points[index].is_personal_best
+}
+
+/// Describes whether there is a Personal Best to mark.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_has_personal_best(
+    this: &RunHistoryComponentState,
+) -> bool {
+    this.personal_best.is_some()
+}
+
+/// Returns the y coordinate of the Personal Best's final time. This is meant to
+/// be visualized as a horizontal marker line. If there is no Personal Best,
+/// this is 0.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_personal_best(this: &RunHistoryComponentState) -> f32 {
+    this.personal_best.unwrap_or_default()
+}
+
+/// Describes whether the time the current attempt is projected to finish with
+/// is to be shown.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_has_projected(this: &RunHistoryComponentState) -> bool {
+    this.projected.is_some()
+}
+
+/// Returns the x coordinate of the point describing the time the current
+/// attempt is projected to finish with. If there is none, this is 0.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_projected_x(this: &RunHistoryComponentState) -> f32 {
+    this.projected.as_ref().map_or(0.0, |p| p.x)
+}
+
+/// Returns the y coordinate of the point describing the time the current
+/// attempt is projected to finish with. If there is none, this is 0.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_projected_y(this: &RunHistoryComponentState) -> f32 {
+    this.projected.as_ref().map_or(0.0, |p| p.y)
+}
+
+/// The height of the component.
+#[no_mangle]
+pub extern "C" fn RunHistoryComponentState_height(this: &RunHistoryComponentState) -> u32 {
+    this.height
+}
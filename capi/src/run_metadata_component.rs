@@ -0,0 +1,54 @@
+//! The Run Metadata Component shows the information stored in the run's
+//! metadata, like the platform and region of the game, whether an emulator is
+//! used and the values of the speedrun.com variables chosen, each in its own
+//! row.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::run_metadata_component_state::OwnedRunMetadataComponentState;
+use livesplit_core::component::run_metadata::Component as RunMetadataComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedRunMetadataComponent = Box<RunMetadataComponent>;
+
+/// Creates a new Run Metadata Component.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponent_new() -> OwnedRunMetadataComponent {
+    Box::new(RunMetadataComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunMetadataComponent_drop(this: OwnedRunMetadataComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponent_into_generic(
+    this: OwnedRunMetadataComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponent_state_as_json(
+    this: &RunMetadataComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponent_state(
+    this: &RunMetadataComponent,
+    timer: &Timer,
+) -> OwnedRunMetadataComponentState {
+    Box::new(this.state(timer))
+}
@@ -0,0 +1,50 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::run_metadata::State as RunMetadataComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedRunMetadataComponentState = Box<RunMetadataComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn RunMetadataComponentState_drop(this: OwnedRunMetadataComponentState) {
+    drop(this);
+}
+
+/// The amount of rows to show, one for each of the values that are enabled and
+/// specified by the run's metadata.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponentState_len(this: &RunMetadataComponentState) -> usize {
+    this.rows.len()
+}
+
+/// The label of the row with the specified index. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponentState_label(
+    this: &RunMetadataComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].label)
+}
+
+/// The value of the row with the specified index. You may not provide an out
+/// of bounds index.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponentState_value(
+    this: &RunMetadataComponentState,
+    index: usize,
+) -> *const c_char {
+    output_str(&this.rows[index].value)
+}
+
+/// Specifies whether to display the label of each row and its value in two
+/// separate rows.
+#[no_mangle]
+pub extern "C" fn RunMetadataComponentState_display_two_rows(
+    this: &RunMetadataComponentState,
+) -> bool {
+    this.display_two_rows
+}
@@ -0,0 +1,55 @@
+//! The Segment Potential Component is a component that shows which of the
+//! remaining segments has the most time that is expected to be saved compared
+//! to the chosen comparison, along with the empirical probability of getting a
+//! new best segment on it. This helps deciding which segments to focus on when
+//! practicing.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::segment_potential_component_state::OwnedSegmentPotentialComponentState;
+use livesplit_core::component::segment_potential::Component as SegmentPotentialComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSegmentPotentialComponent = Box<SegmentPotentialComponent>;
+
+/// Creates a new Segment Potential Component.
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponent_new() -> OwnedSegmentPotentialComponent {
+    Box::new(SegmentPotentialComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponent_drop(this: OwnedSegmentPotentialComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponent_into_generic(
+    this: OwnedSegmentPotentialComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponent_state_as_json(
+    this: &SegmentPotentialComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer provided.
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponent_state(
+    this: &SegmentPotentialComponent,
+    timer: &Timer,
+) -> OwnedSegmentPotentialComponentState {
+    Box::new(this.state(timer))
+}
@@ -0,0 +1,32 @@
+//! The state object describes the information to visualize for this component.
+
+use super::output_str;
+use livesplit_core::component::segment_potential::State as SegmentPotentialComponentState;
+use std::os::raw::c_char;
+
+/// type
+pub type OwnedSegmentPotentialComponentState = Box<SegmentPotentialComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponentState_drop(this: OwnedSegmentPotentialComponentState) {
+    drop(this);
+}
+
+/// The label's text, which includes the name of the segment with the most
+/// potential if there is one.
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponentState_text(
+    this: &SegmentPotentialComponentState,
+) -> *const c_char {
+    output_str(&this.text)
+}
+
+/// The time that is expected to be saved on the segment and the probability of
+/// getting a new best segment on it.
+#[no_mangle]
+pub extern "C" fn SegmentPotentialComponentState_value(
+    this: &SegmentPotentialComponentState,
+) -> *const c_char {
+    output_str(&this.value)
+}
@@ -0,0 +1,58 @@
+//! The Segment Time Component is a component that shows the time of the
+//! current segment as it is ticking. Next to it, the comparison's segment time
+//! and the best segment time of the segment can be displayed. The live segment
+//! time is colored based on whether the runner is ahead or behind the
+//! comparison's segment time.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use crate::segment_time_component_state::OwnedSegmentTimeComponentState;
+use livesplit_core::component::segment_time::Component as SegmentTimeComponent;
+use livesplit_core::{GeneralLayoutSettings, Timer};
+
+/// type
+pub type OwnedSegmentTimeComponent = Box<SegmentTimeComponent>;
+
+/// Creates a new Segment Time Component.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponent_new() -> OwnedSegmentTimeComponent {
+    Box::new(SegmentTimeComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponent_drop(this: OwnedSegmentTimeComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponent_into_generic(
+    this: OwnedSegmentTimeComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponent_state_as_json(
+    this: &SegmentTimeComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer, layout_settings).write_json(o).unwrap();
+    })
+}
+
+/// Calculates the component's state based on the timer and the layout
+/// settings provided.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponent_state(
+    this: &SegmentTimeComponent,
+    timer: &Timer,
+    layout_settings: &GeneralLayoutSettings,
+) -> OwnedSegmentTimeComponentState {
+    Box::new(this.state(timer, layout_settings))
+}
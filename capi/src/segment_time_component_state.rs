@@ -0,0 +1,60 @@
+//! The state object describes the information to visualize for this component.
+
+use super::{output_str, output_vec, Nullablec_char};
+use livesplit_core::component::segment_time::State as SegmentTimeComponentState;
+use std::io::Write;
+use std::os::raw::c_char;
+use std::ptr;
+
+/// type
+pub type OwnedSegmentTimeComponentState = Box<SegmentTimeComponentState>;
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponentState_drop(this: OwnedSegmentTimeComponentState) {
+    drop(this);
+}
+
+/// The label's text.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponentState_text(
+    this: &SegmentTimeComponentState,
+) -> *const c_char {
+    output_str(&this.text)
+}
+
+/// The live segment time.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponentState_time(
+    this: &SegmentTimeComponentState,
+) -> *const c_char {
+    output_str(&this.time)
+}
+
+/// The semantic coloring information the live segment time carries.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponentState_semantic_color(
+    this: &SegmentTimeComponentState,
+) -> *const c_char {
+    output_vec(|f| write!(f, "{:?}", this.semantic_color).unwrap())
+}
+
+/// The comparison's segment time. This may be <NULL> if it's not supposed to
+/// be visualized.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponentState_comparison_time(
+    this: &SegmentTimeComponentState,
+) -> *const Nullablec_char {
+    this.comparison_time
+        .as_ref()
+        .map_or_else(ptr::null, output_str)
+}
+
+/// The best segment time. This may be <NULL> if it's not supposed to be
+/// visualized.
+#[no_mangle]
+pub extern "C" fn SegmentTimeComponentState_best_time(
+    this: &SegmentTimeComponentState,
+) -> *const Nullablec_char {
+    this.best_time.as_ref().map_or_else(ptr::null, output_str)
+}
@@ -0,0 +1,44 @@
+//! The Segment Trend Component is a graph that visualizes how the segment times
+//! of a segment evolve over time. Next to the individual segment times, a
+//! rolling average, the best segment time so far and the fitted trend are
+//! plotted. This helps seeing whether practicing a segment is paying off.
+
+use super::{output_vec, Json};
+use crate::component::OwnedComponent;
+use livesplit_core::component::segment_trend::Component as SegmentTrendComponent;
+use livesplit_core::Timer;
+
+/// type
+pub type OwnedSegmentTrendComponent = Box<SegmentTrendComponent>;
+
+/// Creates a new Segment Trend Component.
+#[no_mangle]
+pub extern "C" fn SegmentTrendComponent_new() -> OwnedSegmentTrendComponent {
+    Box::new(SegmentTrendComponent::new())
+}
+
+/// drop
+#[no_mangle]
+pub extern "C" fn SegmentTrendComponent_drop(this: OwnedSegmentTrendComponent) {
+    drop(this);
+}
+
+/// Converts the component into a generic component suitable for using with a
+/// layout.
+#[no_mangle]
+pub extern "C" fn SegmentTrendComponent_into_generic(
+    this: OwnedSegmentTrendComponent,
+) -> OwnedComponent {
+    Box::new((*this).into())
+}
+
+/// Encodes the component's state information as JSON.
+#[no_mangle]
+pub extern "C" fn SegmentTrendComponent_state_as_json(
+    this: &SegmentTrendComponent,
+    timer: &Timer,
+) -> Json {
+    output_vec(|o| {
+        this.state(timer).write_json(o).unwrap();
+    })
+}
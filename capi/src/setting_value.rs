@@ -2,11 +2,16 @@
 //! types.
 
 use crate::str;
+use livesplit_core::component::graph::GraphMode;
+use livesplit_core::component::image::{self, ImageScaling};
 use livesplit_core::component::splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith};
-use livesplit_core::settings::{Alignment, Color, Gradient, ListGradient, Value as SettingValue};
+use livesplit_core::settings::{
+    Alignment, Color, Gradient, Image, ListGradient, Value as SettingValue,
+};
 use livesplit_core::timing::formatter::{Accuracy, DigitsFormat};
 use livesplit_core::{layout::LayoutDirection, TimingMethod};
 use std::os::raw::c_char;
+use std::slice;
 
 /// type
 pub type OwnedSettingValue = Box<SettingValue>;
@@ -240,6 +245,12 @@ pub unsafe extern "C" fn SettingValue_from_column_start_with(
         "ComparisonTime" => ColumnStartWith::ComparisonTime,
         "ComparisonSegmentTime" => ColumnStartWith::ComparisonSegmentTime,
         "PossibleTimeSave" => ColumnStartWith::PossibleTimeSave,
+        "GoldProbability" => ColumnStartWith::GoldProbability,
+        "ExpectedTimeSave" => ColumnStartWith::ExpectedTimeSave,
+        "BestSegmentTime" => ColumnStartWith::BestSegmentTime,
+        "AverageSegmentTime" => ColumnStartWith::AverageSegmentTime,
+        "ResetCount" => ColumnStartWith::ResetCount,
+        "ReachedCount" => ColumnStartWith::ReachedCount,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -260,6 +271,7 @@ pub unsafe extern "C" fn SettingValue_from_column_update_with(
         "SegmentTime" => ColumnUpdateWith::SegmentTime,
         "SegmentDelta" => ColumnUpdateWith::SegmentDelta,
         "SegmentDeltaWithFallback" => ColumnUpdateWith::SegmentDeltaWithFallback,
+        "BestSegmentDelta" => ColumnUpdateWith::BestSegmentDelta,
         _ => return None,
     };
     Some(Box::new(value.into()))
@@ -295,3 +307,51 @@ pub unsafe extern "C" fn SettingValue_from_layout_direction(
     };
     Some(Box::new(value.into()))
 }
+
+/// Creates a new setting value from the raw file bytes of an image. The image is
+/// shrunk if its width or height exceed the maximum size of images shown by the
+/// Image Component. If the buffer is empty, the setting value has no image.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image(
+    data: *const u8,
+    length: usize,
+) -> OwnedSettingValue {
+    let image = if length == 0 {
+        Image::default()
+    } else {
+        Image::with_max_size(slice::from_raw_parts(data, length), image::MAX_IMAGE_SIZE)
+    };
+    Box::new(image.into())
+}
+
+/// Creates a new setting value from the image scaling name provided. If it
+/// doesn't match a known image scaling, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_image_scaling(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Fit" => ImageScaling::Fit,
+        "Stretch" => ImageScaling::Stretch,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
+
+/// Creates a new setting value from the graph mode name provided. If it doesn't
+/// match a known graph mode, <NULL> is returned.
+#[no_mangle]
+pub unsafe extern "C" fn SettingValue_from_graph_mode(
+    value: *const c_char,
+) -> NullableOwnedSettingValue {
+    let value = str(value);
+    let value = match value {
+        "Delta" => GraphMode::Delta,
+        "SegmentDelta" => GraphMode::SegmentDelta,
+        "PossibleTimeSave" => GraphMode::PossibleTimeSave,
+        "History" => GraphMode::History,
+        _ => return None,
+    };
+    Some(Box::new(value.into()))
+}
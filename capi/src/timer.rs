@@ -1,9 +1,10 @@
 //! A Timer provides all the capabilities necessary for doing speedrun attempts.
 
-use super::{output_str, output_time, output_time_span, output_vec};
+use super::{output_str, output_time, output_time_span, output_vec, str};
 use crate::run::{NullableOwnedRun, OwnedRun};
 use crate::shared_timer::OwnedSharedTimer;
 use livesplit_core::run::saver;
+use livesplit_core::timing::LaterSplitTimes;
 use livesplit_core::{Run, Time, TimeSpan, Timer, TimerPhase, TimingMethod};
 use std::os::raw::c_char;
 use std::ptr;
@@ -105,6 +106,31 @@ pub extern "C" fn Timer_skip_split(this: &mut Timer) {
     this.skip_split();
 }
 
+/// Returns whether the current attempt is counting down. This is the case if
+/// the attempt got started with a negative offset and the current time is
+/// still negative.
+#[no_mangle]
+pub extern "C" fn Timer_is_counting_down(this: &Timer) -> bool {
+    this.is_counting_down()
+}
+
+/// Checks whether the countdown of the current attempt has reached zero. This
+/// returns `true` exactly once for each attempt that started with a countdown,
+/// the first time this gets checked after the countdown ended while the Timer
+/// is running.
+#[no_mangle]
+pub extern "C" fn Timer_poll_countdown_end(this: &mut Timer) -> bool {
+    this.poll_countdown_end()
+}
+
+/// Cancels the current attempt if it's still counting down. Unlike resetting,
+/// this doesn't consume an attempt. If the Timer is not counting down, nothing
+/// happens.
+#[no_mangle]
+pub extern "C" fn Timer_cancel_countdown(this: &mut Timer) {
+    this.cancel_countdown();
+}
+
 /// Removes the split time from the last split if an attempt is in progress
 /// and there is a previous split. The Timer Phase also switches to
 /// `Running` if it previously was `Ended`.
@@ -251,6 +277,22 @@ pub extern "C" fn Timer_set_game_time(this: &mut Timer, time: &TimeSpan) {
     this.set_game_time(*time);
 }
 
+/// Corrects the split time of an already completed segment of the current
+/// attempt for the Timing Method provided. The split times of the later
+/// segments are kept, shifted or cleared as specified, so that the split times
+/// keep increasing. Returns `false` if the split time couldn't be corrected.
+#[no_mangle]
+pub extern "C" fn Timer_set_split_time(
+    this: &mut Timer,
+    segment_index: usize,
+    timing_method: TimingMethod,
+    time: &TimeSpan,
+    later_split_times: LaterSplitTimes,
+) -> bool {
+    this.set_split_time(segment_index, timing_method, *time, later_split_times)
+        .is_ok()
+}
+
 /// Accesses the loading times. Loading times are defined as Game Time - Real Time.
 #[no_mangle]
 pub extern "C" fn Timer_loading_times(this: &Timer) -> *const TimeSpan {
@@ -271,12 +313,63 @@ pub extern "C" fn Timer_current_phase(this: &Timer) -> TimerPhase {
     this.current_phase()
 }
 
+/// Returns whether the Timer is in practice mode. Attempts done in practice
+/// mode don't count as attempts and are never stored in the Run's history.
+#[no_mangle]
+pub extern "C" fn Timer_is_in_practice_mode(this: &Timer) -> bool {
+    this.is_in_practice_mode()
+}
+
+/// Enables or disables practice mode. In practice mode starting an attempt
+/// doesn't increment the attempt count and resetting discards the attempt
+/// entirely. Practice mode can only be changed while there is no attempt in
+/// progress. If that's not the case, nothing happens.
+#[no_mangle]
+pub extern "C" fn Timer_set_practice_mode(this: &mut Timer, practice_mode: bool) {
+    this.set_practice_mode(practice_mode);
+}
+
+/// Toggles practice mode if there is no attempt in progress. If that's not
+/// the case, nothing happens.
+#[no_mangle]
+pub extern "C" fn Timer_toggle_practice_mode(this: &mut Timer) {
+    this.toggle_practice_mode();
+}
+
+/// Sets the runtime value with the name provided. Runtime values are named
+/// values that describe the current state of the game, like the amount of
+/// collectibles, and are usually provided by an auto splitter. They are never
+/// stored in the Run.
+#[no_mangle]
+pub unsafe extern "C" fn Timer_set_runtime_value(
+    this: &mut Timer,
+    name: *const c_char,
+    value: *const c_char,
+) {
+    this.set_runtime_value(str(name), str(value));
+}
+
+/// Removes the runtime value with the name provided. If there's no runtime
+/// value with that name, nothing happens.
+#[no_mangle]
+pub unsafe extern "C" fn Timer_remove_runtime_value(this: &mut Timer, name: *const c_char) {
+    this.remove_runtime_value(str(name));
+}
+
 /// Accesses the Run in use by the Timer.
 #[no_mangle]
 pub extern "C" fn Timer_get_run(this: &Timer) -> &Run {
     this.run()
 }
 
+/// Accesses the Run that the current attempt is timed against. While an
+/// individual level attempt is in progress, this is the Run consisting of only
+/// the segments of the individual level.
+#[no_mangle]
+pub extern "C" fn Timer_get_timed_run(this: &Timer) -> &Run {
+    this.timed_run()
+}
+
 /// Saves the Run in use by the Timer as a LiveSplit splits file (*.lss).
 #[no_mangle]
 pub extern "C" fn Timer_save_as_lss(this: &Timer) -> *const c_char {
@@ -9,12 +9,12 @@ use crate::{analysis, TimeSpan, Timer, TimerPhase};
 /// returned instead.
 pub fn calculate(timer: &Timer, comparison: &str) -> Option<TimeSpan> {
     let timing_method = timer.current_timing_method();
-    let last_segment = timer.run().segments().last().unwrap();
+    let last_segment = timer.timed_run().segments().last().unwrap();
 
     match timer.current_phase() {
         TimerPhase::Running | TimerPhase::Paused => {
             let mut delta = analysis::last_delta(
-                timer.run(),
+                timer.timed_run(),
                 timer.current_split_index().unwrap(),
                 comparison,
                 timing_method,
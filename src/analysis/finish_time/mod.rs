@@ -0,0 +1,195 @@
+//! Provides functionality to predict the distribution of the final time for
+//! either a Run or a Timer. For a Run the distribution describes the final time
+//! of a general attempt. For a Timer the distribution is predicted in terms of
+//! the current attempt. If there is no attempt in progress it yields the same
+//! result as the distribution for the Run.
+//!
+//! The distribution is based on the same skill curve as the PB chance, which
+//! is the curve the Balanced PB algorithm is based on. So the chance to beat
+//! the Personal Best's final time is the same as the PB chance.
+
+use super::pb_chance;
+use crate::{comparison::goal, Run, Segment, TimeSpan, Timer, TimingMethod};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod tests;
+
+const RESOLUTION: usize = 100;
+const TRIES: usize = 50;
+
+/// Describes the predicted distribution of the final time of an attempt. All
+/// the probabilities are values in the range from 0 (0%) to 1 (100%).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Distribution {
+    quantiles: Vec<TimeSpan>,
+}
+
+/// A single bucket of the histogram of a Distribution, describing how likely
+/// it is that the final time is within its range.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    /// The start of the range of final times.
+    pub start: TimeSpan,
+    /// The end of the range of final times.
+    pub end: TimeSpan,
+    /// The probability of the final time being within the range.
+    pub probability: f64,
+}
+
+impl Distribution {
+    /// Returns the final time that is beaten or tied with the probability
+    /// provided. So a probability of 0.5 yields the median final time.
+    pub fn quantile(&self, probability: f64) -> TimeSpan {
+        let probability = if probability > 1.0 {
+            1.0
+        } else if probability >= 0.0 {
+            probability
+        } else {
+            0.0
+        };
+        let position = probability * RESOLUTION as f64;
+
+        let lower = position.floor() as usize;
+        let upper = position.ceil() as usize;
+        let fraction = position - lower as f64;
+        let (lower, upper) = (self.quantiles[lower], self.quantiles[upper]);
+        lower + TimeSpan::from_seconds((upper - lower).total_seconds() * fraction)
+    }
+
+    /// Returns the fastest final time that is considered possible.
+    pub fn min(&self) -> TimeSpan {
+        self.quantiles[0]
+    }
+
+    /// Returns the slowest final time that is considered possible.
+    pub fn max(&self) -> TimeSpan {
+        self.quantiles[RESOLUTION]
+    }
+
+    /// Calculates the chance to beat the target time provided with the final
+    /// time.
+    pub fn chance_to_beat(&self, target: TimeSpan) -> f64 {
+        if target < self.min() {
+            return 0.0;
+        } else if target > self.max() {
+            return 1.0;
+        }
+
+        let (mut perc_min, mut perc_max) = (0.0, 1.0);
+
+        for _ in 0..TRIES {
+            let percentile = (perc_max + perc_min) / 2.0;
+            let time = self.quantile(percentile);
+
+            if time == target {
+                return percentile;
+            } else if time < target {
+                perc_min = percentile;
+            } else {
+                perc_max = percentile;
+            }
+        }
+
+        (perc_max + perc_min) / 2.0
+    }
+
+    /// Splits the range of possible final times into the amount of equally
+    /// sized buckets provided and calculates how likely it is for the final
+    /// time to end up in each of them. If all the possible final times are the
+    /// same, there is only a single bucket.
+    pub fn histogram(&self, bucket_count: usize) -> Vec<HistogramBucket> {
+        let (min, max) = (self.min(), self.max());
+        if min == max || bucket_count <= 1 {
+            return vec![HistogramBucket {
+                start: min,
+                end: max,
+                probability: 1.0,
+            }];
+        }
+
+        let width = (max - min).total_seconds() / bucket_count as f64;
+        let mut previous_chance = 0.0;
+
+        (1..=bucket_count)
+            .map(|i| {
+                let start = min + TimeSpan::from_seconds(width * (i - 1) as f64);
+                let (end, chance) = if i == bucket_count {
+                    (max, 1.0)
+                } else {
+                    let end = min + TimeSpan::from_seconds(width * i as f64);
+                    (end, self.chance_to_beat(end))
+                };
+                let probability = chance - previous_chance;
+                previous_chance = chance;
+                HistogramBucket {
+                    start,
+                    end,
+                    probability,
+                }
+            })
+            .collect()
+    }
+}
+
+fn calculate(
+    segments: &[Segment],
+    allowed_attempts: Option<&HashSet<i32>>,
+    method: TimingMethod,
+    offset: TimeSpan,
+) -> Option<Distribution> {
+    let mut all_weighted_segment_times = vec![Vec::new(); segments.len()];
+    let mut time_span_buf = Vec::with_capacity(segments.len());
+
+    let len = goal::collect_weighted_segment_times(
+        segments,
+        allowed_attempts,
+        method,
+        &mut all_weighted_segment_times,
+    );
+
+    // Without any segment times for a segment, there's no way to know how
+    // long the remainder of the attempt would take.
+    if len < segments.len() {
+        return None;
+    }
+
+    let quantiles = (0..=RESOLUTION)
+        .map(|i| {
+            goal::split_times_at_percentile(
+                offset,
+                i as f64 / RESOLUTION as f64,
+                &all_weighted_segment_times,
+                &mut time_span_buf,
+            )
+        })
+        .collect();
+
+    Some(Distribution { quantiles })
+}
+
+/// Predicts the distribution of the final time for a run. No information about
+/// an active attempt is used. If there are segments without any segment times
+/// in their history, no prediction is possible and `None` is returned.
+pub fn for_run(run: &Run, method: TimingMethod) -> Option<Distribution> {
+    let allowed_attempts = run.allowed_attempts();
+    calculate(
+        run.segments(),
+        allowed_attempts.as_ref(),
+        method,
+        TimeSpan::zero(),
+    )
+}
+
+/// Predicts the distribution of the final time for a timer. The distribution
+/// is predicted in terms of the current attempt. If there is no attempt in
+/// progress it yields the same result as the distribution for the run. If
+/// there are remaining segments without any segment times in their history,
+/// no prediction is possible and `None` is returned.
+pub fn for_timer(timer: &Timer) -> Option<Distribution> {
+    let method = timer.current_timing_method();
+    let run = timer.timed_run();
+    let (segments, current_time) = pb_chance::remaining_segments(timer, run.segments(), method);
+    let allowed_attempts = run.allowed_attempts();
+    calculate(segments, allowed_attempts.as_ref(), method, current_time)
+}
@@ -0,0 +1,91 @@
+use super::{for_run, for_timer};
+use crate::analysis::pb_chance;
+use crate::tests_helper::{
+    create_timer, make_progress_run_with_splits_opt, run_with_splits, span, start_run,
+};
+use crate::TimingMethod;
+
+#[test]
+fn is_none_without_any_times() {
+    let timer = create_timer(&["A"]);
+    assert!(for_timer(&timer).is_none());
+}
+
+#[test]
+fn is_none_if_a_segment_has_never_been_reached() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0]);
+    assert!(for_timer(&timer).is_none());
+}
+
+#[test]
+fn single_run_is_a_single_point() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    let distribution = for_timer(&timer).unwrap();
+    assert_eq!(distribution.quantile(0.0), span(10.0));
+    assert_eq!(distribution.quantile(1.0), span(10.0));
+    assert_eq!(distribution.histogram(10).len(), 1);
+}
+
+#[test]
+fn quantiles_are_within_the_history() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[8.0]);
+    run_with_splits(&mut timer, &[12.0]);
+    let distribution = for_timer(&timer).unwrap();
+
+    assert_eq!(distribution.min(), span(8.0));
+    assert_eq!(distribution.max(), span(12.0));
+    assert!(distribution.quantile(0.5) > distribution.quantile(0.25));
+    assert!(distribution.quantile(0.9) > distribution.quantile(0.5));
+    assert_eq!(distribution.chance_to_beat(span(7.0)), 0.0);
+    assert_eq!(distribution.chance_to_beat(span(13.0)), 1.0);
+}
+
+#[test]
+fn chance_to_beat_pb_matches_pb_chance() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 19.0]);
+    run_with_splits(&mut timer, &[12.0, 25.0]);
+    run_with_splits(&mut timer, &[9.0, 21.0]);
+
+    let distribution = for_run(timer.run(), TimingMethod::GameTime).unwrap();
+    let pb = timer.run().segments()[1]
+        .personal_best_split_time()
+        .game_time
+        .unwrap();
+    let chance = distribution.chance_to_beat(pb);
+    let pb_chance = pb_chance::for_run(timer.run(), TimingMethod::GameTime);
+    assert!((chance - pb_chance).abs() < 0.01);
+}
+
+#[test]
+fn histogram_sums_up_to_one() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[8.0]);
+    run_with_splits(&mut timer, &[12.0]);
+    run_with_splits(&mut timer, &[11.0]);
+    let histogram = for_timer(&timer).unwrap().histogram(4);
+
+    assert_eq!(histogram.len(), 4);
+    assert_eq!(histogram[0].start, span(8.0));
+    assert_eq!(histogram[3].end, span(12.0));
+    let sum: f64 = histogram.iter().map(|b| b.probability).sum();
+    assert!((sum - 1.0).abs() < 1e-9);
+    assert!(histogram.iter().all(|b| b.probability >= 0.0));
+}
+
+#[test]
+fn current_attempt_is_taken_into_account() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[8.0, 19.0]);
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(30.0)]);
+    let distribution = for_timer(&timer).unwrap();
+    assert!(distribution.min() >= span(40.0));
+}
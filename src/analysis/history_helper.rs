@@ -0,0 +1,40 @@
+//! Provides helper functions for the analysis of the Segment History.
+
+use crate::{Run, TimeSpan, TimingMethod};
+use std::collections::HashSet;
+
+/// Iterates over the segment times of the segment specified in the order they
+/// were achieved in, along with the index of the attempt they belong to. If a
+/// set of allowed attempts is provided, only the segment times of those
+/// attempts are considered. Combined segment times are skipped.
+pub(crate) fn segment_times<'a>(
+    run: &'a Run,
+    allowed_attempts: Option<&'a HashSet<i32>>,
+    segment_index: usize,
+    method: TimingMethod,
+) -> impl Iterator<Item = (i32, TimeSpan)> + 'a {
+    let previous_segment = segment_index.checked_sub(1).map(|i| run.segment(i));
+
+    run.segment(segment_index)
+        .segment_history()
+        .iter_actual_runs()
+        .filter(move |&&(id, _)| allowed_attempts.map_or(true, |a| a.contains(&id)))
+        .filter(move |&&(id, _)| {
+            // Skip all the combined segments
+            !catch! { previous_segment?.segment_history().get(id)?[method].is_none() }
+                .unwrap_or(false)
+        })
+        .filter_map(move |&(id, time)| Some((id, time[method]?)))
+}
+
+/// Calculates the median of the values provided. The values get sorted in the
+/// process. There needs to be at least one value.
+pub(crate) fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let middle = values.len() / 2;
+    if values.len() % 2 == 1 {
+        values[middle]
+    } else {
+        0.5 * (values[middle - 1] + values[middle])
+    }
+}
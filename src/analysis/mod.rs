@@ -3,11 +3,17 @@
 
 pub mod current_pace;
 pub mod delta;
+pub mod finish_time;
+pub(crate) mod history_helper;
 pub mod pb_chance;
 pub mod possible_time_save;
+pub mod reset_points;
+pub mod segment_potential;
+pub mod sessions;
 pub mod state_helper;
 pub mod sum_of_segments;
 pub mod total_playtime;
+pub mod trend;
 
 pub use self::state_helper::*;
 
@@ -7,13 +7,21 @@
 //!
 //! The PB chance is currently calculated through the Balanced PB algorithm. The
 //! PB chance is the percentile at which the Balanced PB algorithm finds the PB.
+//! Only the attempts considered by the Run's History Filter are taken into
+//! account.
 
 use crate::{comparison, Run, Segment, TimeSpan, Timer, TimingMethod};
+use std::collections::HashSet;
 
 #[cfg(test)]
 mod tests;
 
-fn calculate(segments: &[Segment], method: TimingMethod, offset: TimeSpan) -> f64 {
+fn calculate(
+    segments: &[Segment],
+    allowed_attempts: Option<&HashSet<i32>>,
+    method: TimingMethod,
+    offset: TimeSpan,
+) -> f64 {
     if segments
         .last()
         .and_then(|s| s.personal_best_split_time()[method])
@@ -29,6 +37,7 @@ fn calculate(segments: &[Segment], method: TimingMethod, offset: TimeSpan) -> f6
     comparison::goal::determine_percentile(
         offset,
         segments,
+        allowed_attempts,
         method,
         None,
         &mut time_span_buf,
@@ -41,20 +50,26 @@ fn calculate(segments: &[Segment], method: TimingMethod, offset: TimeSpan) -> f6
 /// The value is being reported as a floating point number in the range from 0
 /// (0%) to 1 (100%).
 pub fn for_run(run: &Run, method: TimingMethod) -> f64 {
-    calculate(run.segments(), method, TimeSpan::zero())
+    let allowed_attempts = run.allowed_attempts();
+    calculate(
+        run.segments(),
+        allowed_attempts.as_ref(),
+        method,
+        TimeSpan::zero(),
+    )
 }
 
-/// Calculates the PB chance for a timer. The chance is calculated in terms of
-/// the current attempt. If there is no attempt in progress it yields the same
-/// result as the PB chance for the run. The value is being reported as a
-/// floating point number in the range from 0 (0%) to 1 (100%).
-pub fn for_timer(timer: &Timer) -> f64 {
-    let method = timer.current_timing_method();
-    let all_segments = timer.run().segments();
-
+/// Determines the segments that are still remaining in the current attempt and
+/// the time at which they start. If there is a live delta, the attempt is
+/// treated as if the current segment was just split.
+pub(super) fn remaining_segments<'a>(
+    timer: &Timer,
+    all_segments: &'a [Segment],
+    method: TimingMethod,
+) -> (&'a [Segment], TimeSpan) {
     let live_delta = super::check_live_delta(timer, false, comparison::personal_best::NAME, method);
 
-    let (segments, current_time) = if live_delta.is_some() {
+    if live_delta.is_some() {
         // If there is a live delta, act as if we did just split.
         (
             &all_segments[timer.current_split_index().unwrap() + 1..],
@@ -72,7 +87,18 @@ pub fn for_timer(timer: &Timer) -> f64 {
         // Otherwise fall back to all segments with a timer that didn't really
         // start.
         (all_segments, TimeSpan::zero())
-    };
+    }
+}
+
+/// Calculates the PB chance for a timer. The chance is calculated in terms of
+/// the current attempt. If there is no attempt in progress it yields the same
+/// result as the PB chance for the run. The value is being reported as a
+/// floating point number in the range from 0 (0%) to 1 (100%).
+pub fn for_timer(timer: &Timer) -> f64 {
+    let method = timer.current_timing_method();
+    let all_segments = timer.timed_run().segments();
+
+    let (segments, current_time) = remaining_segments(timer, all_segments, method);
 
     // If there are no more segments, which can be because either there is a
     // live delta and we are on the final split, or if we actually did split the
@@ -90,6 +116,7 @@ pub fn for_timer(timer: &Timer) -> f64 {
             0.0
         }
     } else {
-        calculate(segments, method, current_time)
+        let allowed_attempts = timer.timed_run().allowed_attempts();
+        calculate(segments, allowed_attempts.as_ref(), method, current_time)
     }
 }
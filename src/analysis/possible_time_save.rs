@@ -21,10 +21,10 @@ pub fn calculate(
     comparison: &str,
     live: bool,
 ) -> Option<TimeSpan> {
-    let segments = timer.run().segments();
+    let segments = timer.timed_run().segments();
     let method = timer.current_timing_method();
     let mut prev_time = TimeSpan::zero();
-    let segment = timer.run().segment(segment_index);
+    let segment = timer.timed_run().segment(segment_index);
     let mut best_segments = segment.best_segment_time()[method];
 
     for segment in segments[..segment_index].iter().rev() {
@@ -76,7 +76,7 @@ pub fn calculate(
 pub fn calculate_total(timer: &Timer, segment_index: usize, comparison: &str) -> TimeSpan {
     let mut total = TimeSpan::zero();
 
-    for index in segment_index..timer.run().len() {
+    for index in segment_index..timer.timed_run().len() {
         if let Some(time_save) = calculate(timer, index, comparison, true) {
             total += time_save;
         }
@@ -0,0 +1,94 @@
+//! Provides functionality to analyze at which segments attempts get reset. For
+//! each segment it is determined how many attempts reached it, how many of them
+//! got reset during it and how many of all the attempts survived it. This is
+//! based on the Attempt History and the Segment Histories, so only attempts
+//! that are finished or got reset are considered. Only the attempts considered
+//! by the Run's History Filter are taken into account.
+
+use crate::{Run, TimingMethod};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Describes how the attempts progressed through a single segment.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SegmentResets {
+    /// The amount of attempts that reached the segment.
+    pub reached: u32,
+    /// The amount of attempts that got reset during the segment.
+    pub resets: u32,
+    /// The share of all the attempts that completed the segment, as a value
+    /// from 0 (0%) to 1 (100%).
+    pub survival: f64,
+}
+
+/// Describes where the attempts of a Run got reset.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResetPoints {
+    /// The amount of attempts that are considered.
+    pub attempts: u32,
+    /// The amount of attempts that completed every segment.
+    pub finished: u32,
+    /// How the attempts progressed through each of the segments.
+    pub segments: Vec<SegmentResets>,
+}
+
+/// Calculates where the attempts of the Run got reset. The progress of an
+/// attempt is determined by the last segment that has a segment time for the
+/// timing method specified.
+pub fn calculate(run: &Run, method: TimingMethod) -> ResetPoints {
+    let len = run.len();
+
+    let mut progress = HashMap::new();
+    for (segment_index, segment) in run.segments().iter().enumerate() {
+        for &(id, time) in segment.segment_history().iter_actual_runs() {
+            if time[method].is_some() {
+                progress.insert(id, segment_index + 1);
+            }
+        }
+    }
+
+    let allowed = run.allowed_attempts();
+
+    let mut resets = vec![0; len];
+    let mut attempts = 0;
+    let mut finished = 0;
+
+    for attempt in run.attempt_history() {
+        let index = attempt.index();
+        if allowed.as_ref().map_or(false, |a| !a.contains(&index)) {
+            continue;
+        }
+        attempts += 1;
+
+        let completed = progress.get(&index).cloned().unwrap_or(0);
+        if let Some(resets) = resets.get_mut(completed) {
+            *resets += 1;
+        } else {
+            finished += 1;
+        }
+    }
+
+    let mut reached = attempts;
+    let segments = resets
+        .into_iter()
+        .map(|resets| {
+            let segment = SegmentResets {
+                reached,
+                resets,
+                survival: if attempts == 0 {
+                    0.0
+                } else {
+                    (reached - resets) as f64 / attempts as f64
+                },
+            };
+            reached -= resets;
+            segment
+        })
+        .collect();
+
+    ResetPoints {
+        attempts,
+        finished,
+        segments,
+    }
+}
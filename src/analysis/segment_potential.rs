@@ -0,0 +1,104 @@
+//! Provides functions for calculating how much potential there is on
+//! individual segments, based on the segment times in the Segment History.
+//! This consists of the empirical probability of getting a new best segment
+//! and how much time is expected to be saved compared to a comparison. Both can
+//! be used to decide which segments to focus on when practicing. The average
+//! segment time can be calculated as well. If a set of allowed attempts is
+//! provided, which is usually the set of attempts considered by the Run's
+//! History Filter, only the segment times of those attempts are taken into
+//! account.
+
+use crate::analysis::{self, history_helper};
+use crate::{Run, TimeSpan, TimingMethod};
+use std::collections::HashSet;
+
+/// Collects the segment times of the segment in the order they were achieved
+/// in. Combined segment times are skipped.
+fn segment_times(
+    run: &Run,
+    allowed_attempts: Option<&HashSet<i32>>,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Vec<TimeSpan> {
+    history_helper::segment_times(run, allowed_attempts, segment_index, method)
+        .map(|(_, time)| time)
+        .collect()
+}
+
+/// Calculates the empirical probability of getting a new best segment on the
+/// segment specified. This is the share of the segment times that were faster
+/// than all the segment times before them when they were achieved. The very
+/// first segment time is not considered, as it is a best segment by
+/// definition. The probability is reported as a floating point number in the
+/// range from 0 (0%) to 1 (100%). If there are less than two segment times,
+/// `None` is returned.
+pub fn gold_probability(
+    run: &Run,
+    allowed_attempts: Option<&HashSet<i32>>,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<f64> {
+    let times = segment_times(run, allowed_attempts, segment_index, method);
+    let (&first, rest) = times.split_first()?;
+    if rest.is_empty() {
+        return None;
+    }
+
+    let mut best = first;
+    let mut golds = 0;
+    for &time in rest {
+        if time < best {
+            best = time;
+            golds += 1;
+        }
+    }
+
+    Some(golds as f64 / rest.len() as f64)
+}
+
+/// Calculates the average of the segment times of the segment specified. If
+/// there are no segment times, `None` is returned.
+pub fn average_segment_time(
+    run: &Run,
+    allowed_attempts: Option<&HashSet<i32>>,
+    segment_index: usize,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let times = segment_times(run, allowed_attempts, segment_index, method);
+    if times.is_empty() {
+        return None;
+    }
+
+    let total_seconds: f64 = times.iter().map(TimeSpan::total_seconds).sum();
+
+    Some(TimeSpan::from_seconds(total_seconds / times.len() as f64))
+}
+
+/// Calculates how much time is expected to be saved on the segment specified
+/// compared to the comparison's segment time. This is the average of how much
+/// faster each of the segment times is than the comparison's segment time,
+/// where slower segment times count as no time saved. If there are no segment
+/// times or the comparison doesn't have a segment time for the segment,
+/// `None` is returned.
+pub fn expected_time_save(
+    run: &Run,
+    allowed_attempts: Option<&HashSet<i32>>,
+    segment_index: usize,
+    comparison: &str,
+    method: TimingMethod,
+) -> Option<TimeSpan> {
+    let comparison_time =
+        analysis::comparison_segment_time(run, segment_index, comparison, method)?;
+    let times = segment_times(run, allowed_attempts, segment_index, method);
+    if times.is_empty() {
+        return None;
+    }
+
+    let total_seconds: f64 = times
+        .iter()
+        .filter(|&&time| time < comparison_time)
+        .map(|&time| (comparison_time - time).total_seconds())
+        .sum();
+
+    Some(TimeSpan::from_seconds(total_seconds / times.len() as f64))
+}
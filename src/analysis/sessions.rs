@@ -0,0 +1,148 @@
+//! Provides functionality to group the attempts of a Run into sessions and to
+//! calculate statistics for each of them. A session is a sequence of attempts
+//! where no attempt started more than a certain idle gap after the previous
+//! attempt ended. Attempts where it is unknown when they started or ended are
+//! considered to be part of the session of the attempt before them.
+
+use super::total_playtime;
+use crate::{AtomicDateTime, Attempt, Run, Segment, TimeSpan, TimingMethod};
+use std::collections::HashSet;
+
+/// The idle gap that is used by default to separate sessions. This is 30
+/// minutes.
+pub fn default_idle_gap() -> TimeSpan {
+    TimeSpan::from_seconds(30.0 * 60.0)
+}
+
+/// Describes the statistics of a single session.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Session {
+    /// The index of the first attempt of the session.
+    pub first_attempt: i32,
+    /// The index of the last attempt of the session.
+    pub last_attempt: i32,
+    /// The point in time the session started at, if known.
+    pub started: Option<AtomicDateTime>,
+    /// The point in time the session ended at, if known.
+    pub ended: Option<AtomicDateTime>,
+    /// The amount of attempts in the session.
+    pub attempts: u32,
+    /// The amount of attempts in the session that got finished.
+    pub completed_runs: u32,
+    /// The total playtime of all the attempts in the session.
+    pub playtime: TimeSpan,
+    /// The amount of segment times in the session that were faster than all
+    /// the segment times achieved before them.
+    pub golds: u32,
+    /// The fastest final time of all the attempts in the session.
+    pub best_time: Option<TimeSpan>,
+}
+
+/// Groups the attempts provided into sessions. The attempts need to be in the
+/// order they were played in, just like they are stored in the Attempt
+/// History. A new session starts whenever an attempt started more than the idle
+/// gap provided after the previous attempt ended.
+pub fn group(attempts: &[Attempt], idle_gap: TimeSpan) -> Vec<&[Attempt]> {
+    let mut sessions = Vec::new();
+    let mut session_start = 0;
+    let mut last_seen = None::<AtomicDateTime>;
+
+    for (i, attempt) in attempts.iter().enumerate() {
+        if let (Some(last_seen), Some(started)) = (last_seen, attempt.started()) {
+            if started - last_seen > idle_gap {
+                sessions.push(&attempts[session_start..i]);
+                session_start = i;
+            }
+        }
+        if let Some(seen) = attempt.ended().or_else(|| attempt.started()) {
+            last_seen = Some(seen);
+        }
+    }
+
+    if session_start < attempts.len() {
+        sessions.push(&attempts[session_start..]);
+    }
+
+    sessions
+}
+
+/// Returns the attempts of the session that is currently going on. This is the
+/// most recent session of the attempts provided, so the result only depends on
+/// the Attempt History and not on the current point in time. If there are no
+/// attempts, `None` is returned.
+pub fn current(attempts: &[Attempt], idle_gap: TimeSpan) -> Option<&[Attempt]> {
+    group(attempts, idle_gap).pop()
+}
+
+/// Calculates the statistics of the session provided. The attempts need to be
+/// a session of the Run provided. The final times, the segment times and the
+/// golds are based on the timing method provided.
+pub fn statistics(run: &Run, session: &[Attempt], method: TimingMethod) -> Session {
+    let indices = session.iter().map(Attempt::index).collect::<HashSet<_>>();
+
+    let finished_times = session.iter().filter_map(|a| a.time()[method]);
+
+    Session {
+        first_attempt: session.first().map_or(0, Attempt::index),
+        last_attempt: session.last().map_or(0, Attempt::index),
+        started: session.iter().filter_map(Attempt::started).next(),
+        ended: session
+            .iter()
+            .rev()
+            .filter_map(|a| a.ended().or_else(|| a.started()))
+            .next(),
+        attempts: session.len() as u32,
+        completed_runs: finished_times.clone().count() as u32,
+        playtime: total_playtime::for_attempts(run, session),
+        golds: count_golds(run, &indices, method),
+        best_time: finished_times.min(),
+    }
+}
+
+/// Groups the Attempt History of the Run into sessions and calculates the
+/// statistics for each of them. A new session starts whenever an attempt
+/// started more than the idle gap provided after the previous attempt ended.
+pub fn calculate(run: &Run, idle_gap: TimeSpan, method: TimingMethod) -> Vec<Session> {
+    group(run.attempt_history(), idle_gap)
+        .into_iter()
+        .map(|session| statistics(run, session, method))
+        .collect()
+}
+
+/// Counts the segment times of the attempts provided that were faster than all
+/// the segment times achieved before them. The very first segment time of a
+/// segment doesn't count as a gold, and combined segment times are skipped.
+fn count_golds(run: &Run, indices: &HashSet<i32>, method: TimingMethod) -> u32 {
+    let mut golds = 0;
+    let mut previous_segment = None::<&Segment>;
+
+    for segment in run.segments() {
+        let mut best = None;
+        for &(id, time) in segment.segment_history().iter_actual_runs() {
+            // Skip all the combined segments
+            let combined = catch! {
+                previous_segment?.segment_history().get(id)?[method].is_none()
+            }
+            .unwrap_or(false);
+            if combined {
+                continue;
+            }
+
+            if let Some(time) = time[method] {
+                if let Some(best_time) = best {
+                    if time < best_time {
+                        best = Some(time);
+                        if indices.contains(&id) {
+                            golds += 1;
+                        }
+                    }
+                } else {
+                    best = Some(time);
+                }
+            }
+        }
+        previous_segment = Some(segment);
+    }
+
+    golds
+}
@@ -129,9 +129,9 @@ pub fn previous_segment_time(
     method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_time(
-        timer.run(),
+        timer.timed_run(),
         segment_index,
-        timer.run().segment(segment_index).split_time()[method]?,
+        timer.timed_run().segment(segment_index).split_time()[method]?,
         method,
     )
     .into()
@@ -153,7 +153,7 @@ pub fn live_segment_time(
     method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_time(
-        timer.run(),
+        timer.timed_run(),
         segment_index,
         timer.current_time()[method]?,
         method,
@@ -177,9 +177,9 @@ pub fn previous_segment_delta(
     method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_delta(
-        timer.run(),
+        timer.timed_run(),
         segment_index,
-        timer.run().segment(segment_index).split_time()[method]?,
+        timer.timed_run().segment(segment_index).split_time()[method]?,
         comparison,
         method,
     )
@@ -202,7 +202,7 @@ pub fn live_segment_delta(
     method: TimingMethod,
 ) -> Option<TimeSpan> {
     segment_delta(
-        timer.run(),
+        timer.timed_run(),
         segment_index,
         timer.current_time()[method]?,
         comparison,
@@ -235,7 +235,7 @@ pub fn check_live_delta(
         let current_time = timer.current_time()[method];
         let segment_index = timer.current_split_index().unwrap();
         let current_segment = live_segment_time(timer, segment_index, method);
-        let best_segment = timer.run().segment(segment_index).best_segment_time()[method];
+        let best_segment = timer.timed_run().segment(segment_index).best_segment_time()[method];
         let best_segment_delta =
             live_segment_delta(timer, segment_index, best_segments::NAME, method);
         let comparison_delta = live_segment_delta(timer, segment_index, comparison, method);
@@ -284,7 +284,7 @@ pub fn split_color(
     } else if let Some(time_difference) = time_difference {
         let last_delta = segment_index
             .checked_sub(1)
-            .and_then(|n| last_delta(timer.run(), n, comparison, method));
+            .and_then(|n| last_delta(timer.timed_run(), n, comparison, method));
         if time_difference < TimeSpan::zero() {
             if show_segment_deltas && last_delta.map_or(false, |d| time_difference > d) {
                 SemanticColor::AheadLosingTime
@@ -310,13 +310,13 @@ pub fn split_color(
 ///
 /// Returns whether or not the indicated split is a Best Segment.
 pub fn check_best_segment(timer: &Timer, segment_index: usize, method: TimingMethod) -> bool {
-    if timer.run().segment(segment_index).split_time()[method].is_none() {
+    if timer.timed_run().segment(segment_index).split_time()[method].is_none() {
         return false;
     }
 
     let delta = previous_segment_delta(timer, segment_index, best_segments::NAME, method);
     let current_segment = previous_segment_time(timer, segment_index, method);
-    let best_segment = timer.run().segment(segment_index).best_segment_time()[method];
+    let best_segment = timer.timed_run().segment(segment_index).best_segment_time()[method];
     best_segment.map_or(true, |b| {
         current_segment.map_or(false, |c| c < b) || delta.map_or(false, |d| d < TimeSpan::zero())
     })
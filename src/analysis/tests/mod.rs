@@ -1 +1,5 @@
 mod empty_run;
+mod reset_points;
+mod segment_potential;
+mod sessions;
+mod trend;
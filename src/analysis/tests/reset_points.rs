@@ -0,0 +1,65 @@
+use crate::analysis::reset_points::{calculate, SegmentResets};
+use crate::run::HistoryFilter;
+use crate::tests_helper::{create_timer, run_with_splits, run_with_splits_opt};
+use crate::TimingMethod;
+
+#[test]
+fn empty_run() {
+    let timer = create_timer(&["A", "B"]);
+    let reset_points = calculate(timer.run(), TimingMethod::GameTime);
+    assert_eq!(reset_points.attempts, 0);
+    assert_eq!(reset_points.finished, 0);
+    assert_eq!(reset_points.segments.len(), 2);
+    assert_eq!(reset_points.segments[0].reached, 0);
+}
+
+#[test]
+fn counts_where_attempts_got_reset() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0]);
+    // Skipped segments still count as reached if a later one got completed.
+    run_with_splits_opt(&mut timer, &[None, Some(20.0), Some(30.0)]);
+
+    let reset_points = calculate(timer.run(), TimingMethod::GameTime);
+    assert_eq!(reset_points.attempts, 4);
+    assert_eq!(reset_points.finished, 2);
+    assert_eq!(
+        reset_points.segments,
+        [
+            SegmentResets {
+                reached: 4,
+                resets: 1,
+                survival: 0.75,
+            },
+            SegmentResets {
+                reached: 3,
+                resets: 1,
+                survival: 0.5,
+            },
+            SegmentResets {
+                reached: 2,
+                resets: 0,
+                survival: 0.5,
+            },
+        ]
+    );
+}
+
+#[test]
+fn honors_the_history_filter() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+
+    let mut run = timer.into_run(true);
+    let mut filter = HistoryFilter::new();
+    filter.set_last_attempts(Some(1));
+    run.set_history_filter(filter);
+
+    let reset_points = calculate(&run, TimingMethod::GameTime);
+    assert_eq!(reset_points.attempts, 1);
+    assert_eq!(reset_points.finished, 1);
+    assert_eq!(reset_points.segments[0].resets, 0);
+}
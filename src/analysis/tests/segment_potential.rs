@@ -0,0 +1,74 @@
+use crate::analysis::segment_potential::{
+    average_segment_time, expected_time_save, gold_probability,
+};
+use crate::comparison::personal_best;
+use crate::tests_helper::{create_timer, run_with_splits, span};
+use crate::TimingMethod;
+
+#[test]
+fn gold_probability_needs_two_segment_times() {
+    let mut timer = create_timer(&["A"]);
+    assert_eq!(
+        gold_probability(timer.run(), None, 0, TimingMethod::GameTime),
+        None
+    );
+    run_with_splits(&mut timer, &[10.0]);
+    assert_eq!(
+        gold_probability(timer.run(), None, 0, TimingMethod::GameTime),
+        None
+    );
+}
+
+#[test]
+fn gold_probability_counts_improvements() {
+    let mut timer = create_timer(&["A"]);
+    for &time in &[10.0, 11.0, 9.0, 12.0, 8.0] {
+        run_with_splits(&mut timer, &[time]);
+    }
+    assert_eq!(
+        gold_probability(timer.run(), None, 0, TimingMethod::GameTime),
+        Some(0.5)
+    );
+}
+
+#[test]
+fn expected_time_save_averages_the_savings() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0]);
+    run_with_splits(&mut timer, &[11.0, 25.0]);
+
+    // The Personal Best's segment times are 10 and 10. Only the second attempt
+    // is faster on the second segment, the others save nothing.
+    let second = expected_time_save(
+        timer.run(),
+        None,
+        1,
+        personal_best::NAME,
+        TimingMethod::GameTime,
+    );
+    assert_eq!(second, Some(span(2.0 / 3.0)));
+
+    let first = expected_time_save(
+        timer.run(),
+        None,
+        0,
+        personal_best::NAME,
+        TimingMethod::GameTime,
+    );
+    assert_eq!(first, Some(span(0.0)));
+}
+
+#[test]
+fn average_segment_time_averages_the_segment_times() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[12.0, 20.0]);
+    run_with_splits(&mut timer, &[11.0]);
+
+    let first = average_segment_time(timer.run(), None, 0, TimingMethod::GameTime);
+    assert_eq!(first, Some(span(11.0)));
+
+    let second = average_segment_time(timer.run(), None, 1, TimingMethod::GameTime);
+    assert_eq!(second, Some(span(9.0)));
+}
@@ -0,0 +1,109 @@
+use crate::analysis::sessions::{calculate, current, group};
+use crate::tests_helper::{create_run, span};
+use crate::{AtomicDateTime, Run, Time, TimeSpan, TimingMethod};
+use chrono::{DateTime, Duration, Utc};
+
+fn at(base: DateTime<Utc>, minutes: i64) -> Option<AtomicDateTime> {
+    Some(AtomicDateTime::new(
+        base + Duration::minutes(minutes),
+        false,
+    ))
+}
+
+fn time(seconds: Option<f64>) -> Time {
+    Time::new().with_real_time(seconds.map(span))
+}
+
+/// Adds an attempt that started at the given minute and took a minute, along
+/// with its segment times.
+fn add_attempt(run: &mut Run, base: DateTime<Utc>, minute: i64, segments: &[Option<f64>]) {
+    let index = run.attempt_history().len() as i32 + 1;
+    let finished = segments.len() == run.len();
+    let final_time = if finished {
+        segments.iter().map(|s| s.unwrap_or(0.0)).sum::<f64>()
+    } else {
+        0.0
+    };
+    run.add_attempt_with_index(
+        time(if finished { Some(final_time) } else { None }),
+        index,
+        at(base, minute),
+        at(base, minute + 1),
+        None,
+    );
+    for (segment, &segment_time) in run.segments_mut().iter_mut().zip(segments) {
+        segment
+            .segment_history_mut()
+            .insert(index, time(segment_time));
+    }
+}
+
+fn run(base: DateTime<Utc>) -> Run {
+    let mut run = create_run(&["A", "B"]);
+    add_attempt(&mut run, base, 0, &[Some(10.0), Some(20.0)]);
+    add_attempt(&mut run, base, 5, &[Some(12.0)]);
+    add_attempt(&mut run, base, 120, &[Some(9.0), Some(21.0)]);
+    add_attempt(&mut run, base, 125, &[Some(11.0), Some(18.0)]);
+    add_attempt(&mut run, base, 130, &[Some(8.0)]);
+    run
+}
+
+fn base() -> DateTime<Utc> {
+    "2019-01-01T12:00:00Z".parse().unwrap()
+}
+
+#[test]
+fn separates_sessions_by_the_idle_gap() {
+    let run = run(base());
+
+    let sessions = group(run.attempt_history(), TimeSpan::from_seconds(30.0 * 60.0));
+    assert_eq!(sessions.len(), 2);
+    assert_eq!(sessions[0].len(), 2);
+    assert_eq!(sessions[1].len(), 3);
+
+    let sessions = group(
+        run.attempt_history(),
+        TimeSpan::from_seconds(3.0 * 60.0 * 60.0),
+    );
+    assert_eq!(sessions.len(), 1);
+}
+
+#[test]
+fn calculates_the_statistics_of_each_session() {
+    let run = run(base());
+    let sessions = calculate(
+        &run,
+        TimeSpan::from_seconds(30.0 * 60.0),
+        TimingMethod::RealTime,
+    );
+
+    let first = &sessions[0];
+    assert_eq!((first.first_attempt, first.last_attempt), (1, 2));
+    assert_eq!(first.attempts, 2);
+    assert_eq!(first.completed_runs, 1);
+    assert_eq!(first.playtime, TimeSpan::from_seconds(2.0 * 60.0));
+    assert_eq!(first.golds, 0);
+    assert_eq!(first.best_time, Some(span(30.0)));
+    assert_eq!(first.started, at(base(), 0));
+    assert_eq!(first.ended, at(base(), 6));
+
+    let second = &sessions[1];
+    assert_eq!((second.first_attempt, second.last_attempt), (3, 5));
+    assert_eq!(second.attempts, 3);
+    assert_eq!(second.completed_runs, 2);
+    // 9.0 and 8.0 on the first segment and 18.0 on the second one
+    assert_eq!(second.golds, 3);
+    assert_eq!(second.best_time, Some(span(29.0)));
+}
+
+#[test]
+fn the_latest_session_is_current() {
+    let idle_gap = TimeSpan::from_seconds(30.0 * 60.0);
+    let run = run(base());
+
+    let session = current(run.attempt_history(), idle_gap).unwrap();
+    assert_eq!(session.len(), 3);
+    assert_eq!(session[0].index(), 3);
+
+    assert!(current(&[], idle_gap).is_none());
+}
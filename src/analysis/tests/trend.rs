@@ -0,0 +1,64 @@
+use crate::analysis::trend::{calculate, TrendAxis};
+use crate::tests_helper::{create_timer, run_with_splits, span};
+use crate::TimingMethod;
+
+#[test]
+fn calculates_rolling_statistics() {
+    let mut timer = create_timer(&["A"]);
+    for &time in &[10.0, 12.0, 8.0, 11.0] {
+        run_with_splits(&mut timer, &[time]);
+    }
+
+    let trend = calculate(
+        timer.run(),
+        0,
+        TimingMethod::GameTime,
+        TrendAxis::Attempt,
+        3,
+    );
+    let attempts = trend.points.iter().map(|p| p.attempt).collect::<Vec<_>>();
+    assert_eq!(attempts, [1, 2, 3, 4]);
+
+    let last = trend.points[3];
+    assert_eq!(last.time, span(11.0));
+    assert_eq!(last.rolling_mean, span(31.0 / 3.0));
+    assert_eq!(last.rolling_median, span(11.0));
+    assert_eq!(last.best, span(8.0));
+    assert_eq!(trend.points[1].best, span(10.0));
+}
+
+#[test]
+fn fits_trends_to_improving_times() {
+    let mut timer = create_timer(&["A"]);
+    for &time in &[16.0, 14.0, 12.0, 10.0] {
+        run_with_splits(&mut timer, &[time]);
+    }
+
+    let trend = calculate(
+        timer.run(),
+        0,
+        TimingMethod::GameTime,
+        TrendAxis::Attempt,
+        0,
+    );
+
+    let linear = trend.linear.unwrap();
+    assert!((linear.slope + 2.0).abs() < 1e-9);
+    assert!((linear.intercept - 18.0).abs() < 1e-9);
+
+    let exponential = trend.exponential.unwrap();
+    assert!(exponential.factor < 1.0);
+    assert!((exponential.at(2.5) - span(13.0)).total_seconds().abs() < 0.5);
+}
+
+#[test]
+fn needs_two_segment_times_for_a_trend() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+
+    let trend = calculate(timer.run(), 0, TimingMethod::GameTime, TrendAxis::Date, 0);
+    assert_eq!(trend.points.len(), 1);
+    assert_eq!(trend.points[0].x, 0.0);
+    assert!(trend.linear.is_none());
+    assert!(trend.exponential.is_none());
+}
@@ -2,7 +2,7 @@
 //! Timer. For a Run, all the durations stored in the Attempt History are summed
 //! together. For a Timer, the current attempt's duration is also factored in.
 
-use crate::{Run, TimeSpan, Timer, TimingMethod};
+use crate::{Attempt, Run, TimeSpan, Timer, TimingMethod};
 
 /// Allows calculating the total playtime.
 pub trait TotalPlaytime {
@@ -12,32 +12,39 @@ pub trait TotalPlaytime {
 
 impl TotalPlaytime for Run {
     fn total_playtime(&self) -> TimeSpan {
-        let mut total_playtime = TimeSpan::zero();
-
-        for attempt in self.attempt_history() {
-            if let Some(duration) = attempt.duration() {
-                // Either >= 1.6.0 or a finished run
-                total_playtime += duration;
-                if let Some(pause_time) = attempt.pause_time() {
-                    total_playtime -= pause_time;
-                }
-            } else {
-                // Must be < 1.6.0 and a reset
-                // Calculate the sum of the segments for that run
-                for segment in self.segments() {
-                    if let Some(segment_time) = segment
-                        .segment_history()
-                        .get(attempt.index())
-                        .and_then(|s| s[TimingMethod::RealTime])
-                    {
-                        total_playtime += segment_time;
-                    }
+        for_attempts(self, self.attempt_history())
+    }
+}
+
+/// Calculates the playtime of the attempts provided, which need to be part of
+/// the Attempt History of the Run provided. This is useful for only summing up
+/// the playtime of a subset of the attempts, like a single session.
+pub fn for_attempts(run: &Run, attempts: &[Attempt]) -> TimeSpan {
+    let mut total_playtime = TimeSpan::zero();
+
+    for attempt in attempts {
+        if let Some(duration) = attempt.duration() {
+            // Either >= 1.6.0 or a finished run
+            total_playtime += duration;
+            if let Some(pause_time) = attempt.pause_time() {
+                total_playtime -= pause_time;
+            }
+        } else {
+            // Must be < 1.6.0 and a reset
+            // Calculate the sum of the segments for that run
+            for segment in run.segments() {
+                if let Some(segment_time) = segment
+                    .segment_history()
+                    .get(attempt.index())
+                    .and_then(|s| s[TimingMethod::RealTime])
+                {
+                    total_playtime += segment_time;
                 }
             }
         }
-
-        total_playtime
     }
+
+    total_playtime
 }
 
 impl TotalPlaytime for Timer {
@@ -0,0 +1,213 @@
+//! Provides functionality to analyze how the segment times of a segment evolve
+//! over time. This can be used to see whether practicing a segment is paying
+//! off. For each segment time, the rolling mean, the rolling median and the
+//! best segment time up to that point are calculated. Additionally, a linear
+//! and an exponential trend are fitted to the segment times. The segment times
+//! can either be placed by their attempt index or by the date of the attempt.
+//! Only the attempts considered by the Run's History Filter are taken into
+//! account.
+
+use super::history_helper;
+use crate::{Run, TimeSpan, TimingMethod};
+use std::collections::HashMap;
+
+/// Describes what the segment times are placed by.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TrendAxis {
+    /// The segment times are placed by the index of their attempt.
+    Attempt,
+    /// The segment times are placed by the date their attempt started at, in
+    /// days since the first attempt that is considered. Segment times of
+    /// attempts where it is unknown when they started are skipped.
+    Date,
+}
+
+/// Describes a single segment time and the statistics of the segment at that
+/// point in time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrendPoint {
+    /// The index of the attempt the segment time belongs to.
+    pub attempt: i32,
+    /// The position of the segment time on the axis chosen.
+    pub x: f64,
+    /// The segment time itself.
+    pub time: TimeSpan,
+    /// The mean of the segment times within the rolling window ending at this
+    /// segment time.
+    pub rolling_mean: TimeSpan,
+    /// The median of the segment times within the rolling window ending at this
+    /// segment time.
+    pub rolling_median: TimeSpan,
+    /// The best segment time up to and including this segment time.
+    pub best: TimeSpan,
+}
+
+/// A linear trend of the segment times, where the segment time changes by the
+/// same amount per unit on the axis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LinearTrend {
+    /// The estimated segment time in seconds at the position 0.
+    pub intercept: f64,
+    /// The change of the segment time in seconds per unit on the axis. A
+    /// negative slope means that the segment is getting faster.
+    pub slope: f64,
+}
+
+impl LinearTrend {
+    /// Estimates the segment time at the position on the axis provided.
+    pub fn at(&self, x: f64) -> TimeSpan {
+        TimeSpan::from_seconds(self.intercept + self.slope * x)
+    }
+}
+
+/// An exponential trend of the segment times, where the segment time changes
+/// by the same factor per unit on the axis.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExponentialTrend {
+    /// The estimated segment time in seconds at the position 0.
+    pub initial: f64,
+    /// The factor the segment time changes by per unit on the axis. A factor
+    /// below 1 means that the segment is getting faster.
+    pub factor: f64,
+}
+
+impl ExponentialTrend {
+    /// Estimates the segment time at the position on the axis provided.
+    pub fn at(&self, x: f64) -> TimeSpan {
+        TimeSpan::from_seconds(self.initial * self.factor.powf(x))
+    }
+}
+
+/// Describes how the segment times of a segment evolve over time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SegmentTrend {
+    /// All the segment times, ordered by their position on the axis.
+    pub points: Vec<TrendPoint>,
+    /// The linear trend of the segment times. This is `None` if there are less
+    /// than two different positions on the axis.
+    pub linear: Option<LinearTrend>,
+    /// The exponential trend of the segment times. This is `None` if there are
+    /// less than two different positions on the axis.
+    pub exponential: Option<ExponentialTrend>,
+}
+
+/// Calculates how the segment times of the segment specified evolve over time.
+/// The rolling statistics consider the amount of most recent segment times
+/// specified by the window. A window of 0 means that all the segment times up
+/// to that point are considered. Combined segment times are skipped.
+pub fn calculate(
+    run: &Run,
+    segment_index: usize,
+    method: TimingMethod,
+    axis: TrendAxis,
+    window: usize,
+) -> SegmentTrend {
+    let allowed = run.allowed_attempts();
+
+    let started = run
+        .attempt_history()
+        .iter()
+        .filter_map(|a| Some((a.index(), a.started()?.time)))
+        .collect::<HashMap<_, _>>();
+
+    let mut times = history_helper::segment_times(run, allowed.as_ref(), segment_index, method)
+        .filter_map(|(id, time)| {
+            let x = match axis {
+                TrendAxis::Attempt => id as f64,
+                TrendAxis::Date => started.get(&id)?.timestamp() as f64 / (24.0 * 60.0 * 60.0),
+            };
+            Some((id, x, time))
+        })
+        .collect::<Vec<_>>();
+
+    times.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+
+    if axis == TrendAxis::Date {
+        if let Some(&(_, first, _)) = times.first() {
+            for (_, x, _) in &mut times {
+                *x -= first;
+            }
+        }
+    }
+
+    let mut points = Vec::with_capacity(times.len());
+    let mut best = None::<TimeSpan>;
+    let mut window_buf = Vec::new();
+
+    for (i, &(attempt, x, time)) in times.iter().enumerate() {
+        let start = if window == 0 {
+            0
+        } else {
+            (i + 1).saturating_sub(window)
+        };
+
+        window_buf.clear();
+        window_buf.extend(times[start..=i].iter().map(|t| t.2.total_seconds()));
+
+        let rolling_mean = window_buf.iter().sum::<f64>() / window_buf.len() as f64;
+
+        let rolling_median = history_helper::median(&mut window_buf);
+
+        let current_best = match best {
+            Some(best) if best <= time => best,
+            _ => time,
+        };
+        best = Some(current_best);
+
+        points.push(TrendPoint {
+            attempt,
+            x,
+            time,
+            rolling_mean: TimeSpan::from_seconds(rolling_mean),
+            rolling_median: TimeSpan::from_seconds(rolling_median),
+            best: current_best,
+        });
+    }
+
+    let linear = fit_line(points.iter().map(|p| (p.x, p.time.total_seconds())))
+        .map(|(intercept, slope)| LinearTrend { intercept, slope });
+
+    let exponential = fit_line(
+        points
+            .iter()
+            .filter(|p| p.time > TimeSpan::zero())
+            .map(|p| (p.x, p.time.total_seconds().ln())),
+    )
+    .map(|(intercept, slope)| ExponentialTrend {
+        initial: intercept.exp(),
+        factor: slope.exp(),
+    });
+
+    SegmentTrend {
+        points,
+        linear,
+        exponential,
+    }
+}
+
+/// Fits a line through the points provided by using the method of least
+/// squares. The intercept and the slope of the line are returned. If there are
+/// less than two different x coordinates, no line can be fitted.
+fn fit_line(points: impl Iterator<Item = (f64, f64)> + Clone) -> Option<(f64, f64)> {
+    let count = points.clone().count() as f64;
+    if count < 2.0 {
+        return None;
+    }
+
+    let (sum_x, sum_y) = points
+        .clone()
+        .fold((0.0, 0.0), |(sx, sy), (x, y)| (sx + x, sy + y));
+    let (mean_x, mean_y) = (sum_x / count, sum_y / count);
+
+    let (covariance, variance) = points.fold((0.0, 0.0), |(c, v), (x, y)| {
+        let dx = x - mean_x;
+        (c + dx * (y - mean_y), v + dx * dx)
+    });
+
+    if variance <= 0.0 {
+        return None;
+    }
+
+    let slope = covariance / variance;
+    Some((mean_y - slope * mean_x, slope))
+}
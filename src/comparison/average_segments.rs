@@ -5,6 +5,7 @@
 //! runner.
 
 use super::ComparisonGenerator;
+use crate::settings::{Field, SettingsDescription, Value};
 use crate::{Attempt, Segment, TimeSpan, TimingMethod};
 
 /// The Comparison Generator for calculating the Average Segments of a Run. The
@@ -12,7 +13,9 @@ use crate::{Attempt, Segment, TimeSpan, TimingMethod};
 /// gives more recent segments a larger weight so that the Average Segments are
 /// more suited to represent the current performance of a runner.
 #[derive(Copy, Clone, Debug)]
-pub struct AverageSegments;
+pub struct AverageSegments {
+    weight: f64,
+}
 
 /// The short name of this comparison. Suitable for situations where not a lot
 /// of space for text is available.
@@ -20,9 +23,47 @@ pub const SHORT_NAME: &str = "Average";
 /// The name of this comparison.
 pub const NAME: &str = "Average Segments";
 
-const WEIGHT: f64 = 0.75;
+const DEFAULT_WEIGHT: f64 = 0.75;
+
+impl Default for AverageSegments {
+    fn default() -> Self {
+        Self {
+            weight: DEFAULT_WEIGHT,
+        }
+    }
+}
+
+impl AverageSegments {
+    /// Creates a new Average Segments Comparison Generator that uses the weight
+    /// provided, which is clamped to be within 0 and 1. Each segment time gets
+    /// weighted by this factor compared to the segment time that came after
+    /// it. So a weight of 1 weighs all the segment times equally, while lower
+    /// weights favor the more recent segment times.
+    pub fn new(weight: f64) -> Self {
+        let mut generator = Self::default();
+        generator.set_weight(weight);
+        generator
+    }
 
-fn generate(segments: &mut [Segment], method: TimingMethod) {
+    /// Accesses the weight that is used for weighing the segment times.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Sets the weight that is used for weighing the segment times. The weight
+    /// is clamped to be within 0 and 1.
+    pub fn set_weight(&mut self, weight: f64) {
+        self.weight = if weight > 1.0 {
+            1.0
+        } else if weight >= 0.0 {
+            weight
+        } else {
+            0.0
+        };
+    }
+}
+
+fn generate(segments: &mut [Segment], weight: f64, method: TimingMethod) {
     let mut accumulated = Some(TimeSpan::zero());
 
     let mut previous_segment: Option<&Segment> = None;
@@ -42,7 +83,7 @@ fn generate(segments: &mut [Segment], method: TimingMethod) {
                     if !skip {
                         total_weights += current_weight;
                         total_time += current_weight * time.total_seconds();
-                        current_weight *= WEIGHT;
+                        current_weight *= weight;
                     }
                 }
             }
@@ -64,7 +105,22 @@ impl ComparisonGenerator for AverageSegments {
     }
 
     fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
-        generate(segments, TimingMethod::RealTime);
-        generate(segments, TimingMethod::GameTime);
+        generate(segments, self.weight, TimingMethod::RealTime);
+        generate(segments, self.weight, TimingMethod::GameTime);
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new("Weight".into(), self.weight.into())])
+    }
+
+    fn setting_keys(&self) -> &'static [&'static str] {
+        &["Weight"]
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.set_weight(value.into_float().unwrap()),
+            _ => panic!("Unsupported Setting Index"),
+        }
     }
 }
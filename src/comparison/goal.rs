@@ -7,6 +7,7 @@
 
 use crate::{Segment, Time, TimeSpan, TimingMethod};
 use ordered_float::OrderedFloat;
+use std::collections::HashSet;
 
 /// The default name of the goal comparison.
 pub const NAME: &str = "Goal";
@@ -26,15 +27,17 @@ fn interpolate(
     TimeSpan::from_milliseconds(perc_up + perc_down)
 }
 
-// FIXME: Possibly move this into the analysis module.
-pub(crate) fn determine_percentile(
-    offset: TimeSpan,
+/// Collects the weighted segment times of each segment, sorted by the segment
+/// times and with the weights accumulated to be in the range 0..1. If a set of
+/// allowed attempts is provided, only the segment times of those attempts are
+/// collected. Returns the amount of segments, starting from the first one, that
+/// have segment times.
+pub(crate) fn collect_weighted_segment_times(
     segments: &[Segment],
+    allowed_attempts: Option<&HashSet<i32>>,
     method: TimingMethod,
-    goal_time: Option<TimeSpan>,
-    time_span_buf: &mut Vec<TimeSpan>,
     all_weighted_segment_times: &mut [Vec<(f64, TimeSpan)>],
-) -> f64 {
+) -> usize {
     let mut len = segments.len();
 
     for ((i, segment), weighted_segment_times) in segments
@@ -47,6 +50,9 @@ pub(crate) fn determine_percentile(
         // Collect initial weighted segments
         let mut current_weight = 1.0;
         for &(id, time) in segment.segment_history().iter_actual_runs().rev() {
+            if allowed_attempts.map_or(false, |allowed| !allowed.contains(&id)) {
+                continue;
+            }
             if let Some(time) = time[method] {
                 // Skip all the combined segments
                 let skip = catch! {
@@ -96,6 +102,74 @@ pub(crate) fn determine_percentile(
         }
     }
 
+    len
+}
+
+/// Calculates the split times where each segment time is at the percentile
+/// provided of the segment's weighted segment times. The split times are
+/// stored in the buffer and the final split time is returned.
+pub(crate) fn split_times_at_percentile(
+    offset: TimeSpan,
+    percentile: f64,
+    all_weighted_segment_times: &[Vec<(f64, TimeSpan)>],
+    time_span_buf: &mut Vec<TimeSpan>,
+) -> TimeSpan {
+    let mut sum = offset;
+
+    time_span_buf.clear();
+    time_span_buf.extend(
+        all_weighted_segment_times
+            .iter()
+            .map(|weighted_segment_times| {
+                // Binary search the percentile in the segment's segment times
+                let percentile_segment_time = if weighted_segment_times.len() == 1 {
+                    // Shortcut for a single segment time
+                    weighted_segment_times[0].1
+                } else {
+                    let found_index = weighted_segment_times
+                        .binary_search_by(|&(w, _)| w.partial_cmp(&percentile).unwrap());
+
+                    match found_index {
+                        // The percentile perfectly matched a segment time
+                        Ok(index) => weighted_segment_times[index].1,
+                        // The percentile didn't perfectly match, interpolate instead
+                        Err(right_index) => {
+                            let right = weighted_segment_times[right_index];
+                            let left = right_index
+                                .checked_sub(1)
+                                .map(|left_index| weighted_segment_times[left_index])
+                                .unwrap_or_default();
+
+                            interpolate(percentile, left, right)
+                        }
+                    }
+                };
+
+                sum += percentile_segment_time;
+                sum
+            }),
+    );
+
+    sum
+}
+
+// FIXME: Possibly move this into the analysis module.
+pub(crate) fn determine_percentile(
+    offset: TimeSpan,
+    segments: &[Segment],
+    allowed_attempts: Option<&HashSet<i32>>,
+    method: TimingMethod,
+    goal_time: Option<TimeSpan>,
+    time_span_buf: &mut Vec<TimeSpan>,
+    all_weighted_segment_times: &mut [Vec<(f64, TimeSpan)>],
+) -> f64 {
+    let len = collect_weighted_segment_times(
+        segments,
+        allowed_attempts,
+        method,
+        all_weighted_segment_times,
+    );
+
     // Limit the slice to only the segments that have segment times.
     let mut all_weighted_segment_times = &mut all_weighted_segment_times[..len];
 
@@ -121,40 +195,11 @@ pub(crate) fn determine_percentile(
     // Try to find the correct percentile
     for _ in 0..TRIES {
         let percentile = (perc_max + perc_min) / 2.0;
-        let mut sum = offset;
-
-        time_span_buf.clear();
-        time_span_buf.extend(
-            all_weighted_segment_times
-                .iter()
-                .map(|weighted_segment_times| {
-                    // Binary search the percentile in the segment's segment times
-                    let percentile_segment_time = if weighted_segment_times.len() == 1 {
-                        // Shortcut for a single segment time
-                        weighted_segment_times[0].1
-                    } else {
-                        let found_index = weighted_segment_times
-                            .binary_search_by(|&(w, _)| w.partial_cmp(&percentile).unwrap());
-
-                        match found_index {
-                            // The percentile perfectly matched a segment time
-                            Ok(index) => weighted_segment_times[index].1,
-                            // The percentile didn't perfectly match, interpolate instead
-                            Err(right_index) => {
-                                let right = weighted_segment_times[right_index];
-                                let left = right_index
-                                    .checked_sub(1)
-                                    .map(|left_index| weighted_segment_times[left_index])
-                                    .unwrap_or_default();
-
-                                interpolate(percentile, left, right)
-                            }
-                        }
-                    };
-
-                    sum += percentile_segment_time;
-                    sum
-                }),
+        let sum = split_times_at_percentile(
+            offset,
+            percentile,
+            all_weighted_segment_times,
+            time_span_buf,
         );
 
         // Binary search the correct percentile
@@ -181,6 +226,7 @@ pub(super) fn generate_for_timing_method_with_buf(
     let _percentile = determine_percentile(
         TimeSpan::zero(),
         segments,
+        None,
         method,
         goal_time,
         time_span_buf,
@@ -195,6 +241,37 @@ pub(super) fn generate_for_timing_method_with_buf(
     }
 }
 
+/// Populates the segments with a comparison for the timing method specified,
+/// where each segment time is at the percentile provided of the segment's
+/// weighted segment history. The percentile is a value from 0 to 1. Since the
+/// PB chance is the percentile at which the Personal Best is found, the final
+/// time of this comparison is beaten with a probability of roughly the
+/// percentile provided.
+pub(super) fn generate_for_percentile_with_buf(
+    segments: &mut [Segment],
+    method: TimingMethod,
+    percentile: f64,
+    comparison: &str,
+    time_span_buf: &mut Vec<TimeSpan>,
+    all_weighted_segment_times: &mut [Vec<(f64, TimeSpan)>],
+) {
+    let len = collect_weighted_segment_times(segments, None, method, all_weighted_segment_times);
+
+    split_times_at_percentile(
+        TimeSpan::zero(),
+        percentile,
+        &all_weighted_segment_times[..len],
+        time_span_buf,
+    );
+
+    for (segment, &val) in segments.iter_mut().zip(time_span_buf.iter()) {
+        segment.comparison_mut(comparison)[method] = Some(val);
+    }
+    for segment in &mut segments[time_span_buf.len()..] {
+        segment.comparison_mut(comparison)[method] = None;
+    }
+}
+
 /// Populates the segments with a goal comparison for the timing method
 /// specified. Every other timing method is left untouched. The segment history
 /// is used to generate comparison times such that they end up with the goal
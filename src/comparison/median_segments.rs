@@ -4,6 +4,7 @@
 //! suited to represent the current performance of a runner.
 
 use super::ComparisonGenerator;
+use crate::settings::{Field, SettingsDescription, Value};
 use crate::{Attempt, Segment, TimeSpan, TimingMethod};
 use ordered_float::OrderedFloat;
 
@@ -12,7 +13,9 @@ use ordered_float::OrderedFloat;
 /// recent segments a larger weight so that the Median Segments are more suited
 /// to represent the current performance of a runner.
 #[derive(Copy, Clone, Debug)]
-pub struct MedianSegments;
+pub struct MedianSegments {
+    weight: f64,
+}
 
 /// The short name of this comparison. Suitable for situations where not a lot
 /// of space for text is available.
@@ -20,9 +23,52 @@ pub const SHORT_NAME: &str = "Median";
 /// The name of this comparison.
 pub const NAME: &str = "Median Segments";
 
-const WEIGHT: f64 = 0.75;
+const DEFAULT_WEIGHT: f64 = 0.75;
+
+impl Default for MedianSegments {
+    fn default() -> Self {
+        Self {
+            weight: DEFAULT_WEIGHT,
+        }
+    }
+}
+
+impl MedianSegments {
+    /// Creates a new Median Segments Comparison Generator that uses the weight
+    /// provided, which is clamped to be within 0 and 1. Each segment time gets
+    /// weighted by this factor compared to the segment time that came after
+    /// it. So a weight of 1 weighs all the segment times equally, while lower
+    /// weights favor the more recent segment times.
+    pub fn new(weight: f64) -> Self {
+        let mut generator = Self::default();
+        generator.set_weight(weight);
+        generator
+    }
 
-fn generate(segments: &mut [Segment], medians: &mut Vec<(f64, f64)>, method: TimingMethod) {
+    /// Accesses the weight that is used for weighing the segment times.
+    pub fn weight(&self) -> f64 {
+        self.weight
+    }
+
+    /// Sets the weight that is used for weighing the segment times. The weight
+    /// is clamped to be within 0 and 1.
+    pub fn set_weight(&mut self, weight: f64) {
+        self.weight = if weight > 1.0 {
+            1.0
+        } else if weight >= 0.0 {
+            weight
+        } else {
+            0.0
+        };
+    }
+}
+
+fn generate(
+    segments: &mut [Segment],
+    weight: f64,
+    medians: &mut Vec<(f64, f64)>,
+    method: TimingMethod,
+) {
     let mut accumulated = Some(TimeSpan::zero());
 
     let mut previous_segment: Option<&Segment> = None;
@@ -42,7 +88,7 @@ fn generate(segments: &mut [Segment], medians: &mut Vec<(f64, f64)>, method: Tim
 
                     if !skip {
                         medians.push((current_weight, time.total_seconds()));
-                        current_weight *= WEIGHT;
+                        current_weight *= weight;
                     }
                 }
             }
@@ -79,7 +125,22 @@ impl ComparisonGenerator for MedianSegments {
 
     fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
         let medians = &mut Vec::new();
-        generate(segments, medians, TimingMethod::RealTime);
-        generate(segments, medians, TimingMethod::GameTime);
+        generate(segments, self.weight, medians, TimingMethod::RealTime);
+        generate(segments, self.weight, medians, TimingMethod::GameTime);
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new("Weight".into(), self.weight.into())])
+    }
+
+    fn setting_keys(&self) -> &'static [&'static str] {
+        &["Weight"]
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.set_weight(value.into_float().unwrap()),
+            _ => panic!("Unsupported Setting Index"),
+        }
     }
 }
@@ -14,6 +14,9 @@ pub mod goal;
 pub mod latest_run;
 pub mod median_segments;
 pub mod none;
+pub mod percentile_segments;
+pub mod probability_goal;
+pub mod session_best;
 pub mod worst_segments;
 
 pub use self::average_segments::AverageSegments;
@@ -23,8 +26,12 @@ pub use self::best_split_times::BestSplitTimes;
 pub use self::latest_run::LatestRun;
 pub use self::median_segments::MedianSegments;
 pub use self::none::None;
+pub use self::percentile_segments::PercentileSegments;
+pub use self::probability_goal::ProbabilityGoal;
+pub use self::session_best::SessionBest;
 pub use self::worst_segments::WorstSegments;
 
+use crate::settings::{SettingsDescription, Value};
 use crate::{Attempt, Segment, Timer};
 use std::fmt::Debug;
 
@@ -61,6 +68,31 @@ pub trait ComparisonGenerator: Debug + Sync + Send + ComparisonGeneratorClone {
     /// provided, in case the comparison generator requires information from the
     /// previous attempts.
     fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]);
+
+    /// Accesses a generic description of the settings available for this
+    /// Comparison Generator and their current values. Most Comparison
+    /// Generators don't have any settings.
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::default()
+    }
+
+    /// Accesses the keys identifying the settings of this Comparison Generator
+    /// when they are stored, in the same order as the fields of the settings
+    /// description. Unlike the names of the fields, the keys never change.
+    fn setting_keys(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    fn set_value(&mut self, _index: usize, _value: Value) {
+        panic!("Unsupported Setting Index")
+    }
 }
 
 /// Provides the ability to clone a Comparison Generator, even when it is stored
@@ -88,18 +120,58 @@ impl Clone for Box<dyn ComparisonGenerator> {
 /// Creates a list of all the Comparison Generators that are active by default.
 /// Which comparison generators are in this list may change in future versions.
 pub fn default_generators() -> Vec<Box<dyn ComparisonGenerator>> {
+    all_generators()
+        .into_iter()
+        .filter(|g| {
+            let name = g.name();
+            name != percentile_segments::NAME
+                && name != probability_goal::NAME
+                && name != session_best::NAME
+        })
+        .collect()
+}
+
+/// Checks whether the Comparison Generators provided are the ones that are
+/// active by default, in the same order and with their default settings.
+pub(crate) fn are_default_generators(generators: &[Box<dyn ComparisonGenerator>]) -> bool {
+    let defaults = default_generators();
+    generators.len() == defaults.len()
+        && generators
+            .iter()
+            .zip(&defaults)
+            .all(|(generator, default)| {
+                generator.name() == default.name()
+                    && serde_json::to_value(generator.settings_description()).ok()
+                        == serde_json::to_value(default.settings_description()).ok()
+            })
+}
+
+/// Creates a list of all the Comparison Generators that are available, each
+/// with its default settings. A Run can enable and disable each of them
+/// individually. The order of this list is the order in which the comparisons
+/// of enabled Comparison Generators are listed.
+pub fn all_generators() -> Vec<Box<dyn ComparisonGenerator>> {
     vec![
         Box::new(BestSegments),
         Box::new(BestSplitTimes),
-        Box::new(AverageSegments),
-        Box::new(MedianSegments),
+        Box::new(AverageSegments::default()),
+        Box::new(MedianSegments::default()),
+        Box::new(PercentileSegments::default()),
         Box::new(WorstSegments),
         Box::new(BalancedPB),
+        Box::new(ProbabilityGoal::default()),
         Box::new(LatestRun),
+        Box::new(SessionBest::default()),
         Box::new(None),
     ]
 }
 
+/// Creates the Comparison Generator with the name provided, using its default
+/// settings. If there's no such Comparison Generator, `None` is returned.
+pub fn generator_by_name(name: &str) -> Option<Box<dyn ComparisonGenerator>> {
+    all_generators().into_iter().find(|g| g.name() == name)
+}
+
 /// Shortens a comparison name. If the name of the comparison matches one of the
 /// comparison generators, the short name of that comparison generator is
 /// returned. Otherwise the comparison name is returned without being shortened.
@@ -116,6 +188,9 @@ pub fn shorten(comparison: &str) -> &str {
         best_split_times::NAME => best_split_times::SHORT_NAME,
         latest_run::NAME => latest_run::SHORT_NAME,
         none::NAME => none::SHORT_NAME,
+        percentile_segments::NAME => percentile_segments::SHORT_NAME,
+        probability_goal::NAME => probability_goal::SHORT_NAME,
+        session_best::NAME => session_best::SHORT_NAME,
         worst_segments::NAME => worst_segments::SHORT_NAME,
         c => c,
     }
@@ -132,5 +207,5 @@ pub fn or_current<'a>(comparison: Option<&'a str>, timer: &'a Timer) -> &'a str
 /// Otherwise the comparison name stored in the Timer is returned by reference.
 pub fn resolve<'a>(comparison: &Option<String>, timer: &'a Timer) -> Option<&'a str> {
     let comparison = comparison.as_ref()?;
-    timer.run().comparisons().find(|&rc| comparison == rc)
+    timer.timed_run().comparisons().find(|&rc| comparison == rc)
 }
@@ -0,0 +1,171 @@
+//! Defines the Comparison Generator for calculating the Percentile Segments of
+//! a Run. The Percentile Segments use a configurable percentile of each
+//! segment's most recent segment times. A low percentile represents a good
+//! segment that is still achieved regularly, while a high percentile
+//! represents a segment that is usually beaten.
+
+use super::ComparisonGenerator;
+use crate::settings::{Field, SettingsDescription, Value};
+use crate::{Attempt, Segment, TimeSpan, TimingMethod};
+use ordered_float::OrderedFloat;
+use std::convert::TryFrom;
+
+/// The Comparison Generator for calculating the Percentile Segments of a Run.
+/// The Percentile Segments use a configurable percentile of each segment's
+/// most recent segment times. A low percentile represents a good segment that
+/// is still achieved regularly, while a high percentile represents a segment
+/// that is usually beaten.
+#[derive(Copy, Clone, Debug)]
+pub struct PercentileSegments {
+    percentile: f64,
+    window: u32,
+}
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Percentile";
+/// The name of this comparison.
+pub const NAME: &str = "Percentile Segments";
+
+impl Default for PercentileSegments {
+    fn default() -> Self {
+        Self {
+            percentile: 25.0,
+            window: 20,
+        }
+    }
+}
+
+impl PercentileSegments {
+    /// Creates a new Percentile Segments Comparison Generator that uses the
+    /// percentile provided, which is clamped to be within 0 and 100. Only the
+    /// most recent segment times within the window are considered for each
+    /// segment. A window of 0 means that all the segment times are considered.
+    pub fn new(percentile: f64, window: u32) -> Self {
+        let mut generator = Self::default();
+        generator.set_percentile(percentile);
+        generator.window = window;
+        generator
+    }
+
+    /// Accesses the percentile of the segment times that is used for the
+    /// comparison.
+    pub fn percentile(&self) -> f64 {
+        self.percentile
+    }
+
+    /// Sets the percentile of the segment times that is used for the
+    /// comparison. The percentile is clamped to be within 0 and 100.
+    pub fn set_percentile(&mut self, percentile: f64) {
+        self.percentile = if percentile > 100.0 {
+            100.0
+        } else if percentile >= 0.0 {
+            percentile
+        } else {
+            0.0
+        };
+    }
+
+    /// Accesses the amount of most recent segment times that are considered
+    /// for each segment. A window of 0 means that all the segment times are
+    /// considered.
+    pub fn window(&self) -> u32 {
+        self.window
+    }
+
+    /// Sets the amount of most recent segment times that are considered for
+    /// each segment. A window of 0 means that all the segment times are
+    /// considered.
+    pub fn set_window(&mut self, window: u32) {
+        self.window = window;
+    }
+
+    fn generate_for_method(
+        &self,
+        segments: &mut [Segment],
+        times: &mut Vec<f64>,
+        method: TimingMethod,
+    ) {
+        let window = Some(self.window as usize).filter(|&window| window != 0);
+
+        let mut accumulated = Some(TimeSpan::zero());
+
+        let mut previous_segment: Option<&Segment> = None;
+        for segment in segments {
+            if let Some(accumulated_val) = &mut accumulated {
+                times.clear();
+
+                for &(id, time) in segment.segment_history().iter_actual_runs().rev() {
+                    if window.map_or(false, |window| times.len() >= window) {
+                        break;
+                    }
+                    if let Some(time) = time[method] {
+                        // Skip all the combined segments
+                        let skip = catch! {
+                            previous_segment?.segment_history().get(id)?[method].is_none()
+                        }
+                        .unwrap_or(false);
+
+                        if !skip {
+                            times.push(time.total_seconds());
+                        }
+                    }
+                }
+
+                if times.is_empty() {
+                    accumulated = None;
+                } else {
+                    times.sort_unstable_by_key(|&time| OrderedFloat(time));
+                    *accumulated_val += TimeSpan::from_seconds(percentile(times, self.percentile));
+                }
+            }
+            segment.comparison_mut(NAME)[method] = accumulated;
+            previous_segment = Some(&*segment);
+        }
+    }
+}
+
+/// Linearly interpolates between the closest ranks of the sorted times.
+fn percentile(sorted_times: &[f64], percentile: f64) -> f64 {
+    let rank = percentile / 100.0 * (sorted_times.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    let fraction = rank - lower as f64;
+    sorted_times[lower] + (sorted_times[upper] - sorted_times[lower]) * fraction
+}
+
+impl ComparisonGenerator for PercentileSegments {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let times = &mut Vec::new();
+        self.generate_for_method(segments, times, TimingMethod::RealTime);
+        self.generate_for_method(segments, times, TimingMethod::GameTime);
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Percentile".into(), self.percentile.into()),
+            Field::new("Window".into(), u64::from(self.window).into()),
+        ])
+    }
+
+    fn setting_keys(&self) -> &'static [&'static str] {
+        &["Percentile", "Window"]
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.set_percentile(value.into_float().unwrap()),
+            1 => {
+                // A window that doesn't fit includes all the segment times
+                // anyway, so it's saturated.
+                let window = value.into_uint().unwrap();
+                self.set_window(u32::try_from(window).unwrap_or(u32::MAX));
+            }
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
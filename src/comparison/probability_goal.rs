@@ -0,0 +1,108 @@
+//! Defines the Comparison Generator for calculating a goal comparison that is
+//! beaten with a certain probability. Instead of distributing a fixed goal time,
+//! every segment time is chosen at the same percentile of the segment's
+//! history, which is the same skill curve that the Balanced PB and the PB
+//! chance are based on. A probability of 10% results in a good, but still
+//! realistic run that the runner beats roughly every tenth attempt.
+
+use super::{goal, ComparisonGenerator};
+use crate::settings::{Field, SettingsDescription, Value};
+use crate::{Attempt, Segment, TimingMethod};
+
+/// The Comparison Generator for calculating a goal comparison that is beaten
+/// with a certain probability. Instead of distributing a fixed goal time, every
+/// segment time is chosen at the same percentile of the segment's history,
+/// which is the same skill curve that the Balanced PB and the PB chance are
+/// based on. A probability of 10% results in a good, but still realistic run
+/// that the runner beats roughly every tenth attempt.
+#[derive(Copy, Clone, Debug)]
+pub struct ProbabilityGoal {
+    probability: f64,
+}
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Prob. Goal";
+/// The name of this comparison.
+pub const NAME: &str = "Probability Goal";
+
+impl Default for ProbabilityGoal {
+    fn default() -> Self {
+        Self { probability: 0.1 }
+    }
+}
+
+impl ProbabilityGoal {
+    /// Creates a new Probability Goal Comparison Generator for the probability
+    /// provided. The probability is a value from 0 (0%) to 1 (100%), just like
+    /// the PB chance, and is clamped to be within that range.
+    pub fn new(probability: f64) -> Self {
+        let mut generator = Self::default();
+        generator.set_probability(probability);
+        generator
+    }
+
+    /// Accesses the probability with which the comparison is supposed to be
+    /// beaten, as a value from 0 (0%) to 1 (100%).
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    /// Sets the probability with which the comparison is supposed to be
+    /// beaten. The probability is clamped to be within 0 (0%) and 1 (100%).
+    pub fn set_probability(&mut self, probability: f64) {
+        self.probability = if probability > 1.0 {
+            1.0
+        } else if probability >= 0.0 {
+            probability
+        } else {
+            0.0
+        };
+    }
+}
+
+impl ComparisonGenerator for ProbabilityGoal {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], _: &[Attempt]) {
+        let mut all_weighted_segment_times = vec![Vec::new(); segments.len()];
+        let mut time_span_buf = Vec::with_capacity(segments.len());
+
+        goal::generate_for_percentile_with_buf(
+            segments,
+            TimingMethod::RealTime,
+            self.probability,
+            NAME,
+            &mut time_span_buf,
+            &mut all_weighted_segment_times,
+        );
+        goal::generate_for_percentile_with_buf(
+            segments,
+            TimingMethod::GameTime,
+            self.probability,
+            NAME,
+            &mut time_span_buf,
+            &mut all_weighted_segment_times,
+        );
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new(
+            "Probability".into(),
+            self.probability.into(),
+        )])
+    }
+
+    fn setting_keys(&self) -> &'static [&'static str] {
+        &["Probability"]
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.set_probability(value.into_float().unwrap()),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
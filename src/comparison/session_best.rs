@@ -0,0 +1,122 @@
+//! Defines the Comparison Generator for calculating the Session Best. Using the
+//! Segment History, this comparison reconstructs the splits of the fastest
+//! finished attempt of the most recent session in the Attempt History. A session
+//! ends once no attempt has been started for longer than a configurable idle
+//! gap. If no attempt has been finished in that session, this comparison
+//! is empty.
+
+use super::ComparisonGenerator;
+use crate::analysis::sessions;
+use crate::settings::{Field, SettingsDescription, Value};
+use crate::{Attempt, Segment, TimeSpan, TimingMethod};
+
+/// The Comparison Generator for calculating the Session Best. Using the
+/// Segment History, this comparison reconstructs the splits of the fastest
+/// finished attempt of the most recent session in the Attempt History. A session
+/// ends once no attempt has been started for longer than a configurable idle
+/// gap. If no attempt has been finished in that session, this comparison
+/// is empty.
+#[derive(Copy, Clone, Debug)]
+pub struct SessionBest {
+    idle_gap: TimeSpan,
+}
+
+/// The short name of this comparison. Suitable for situations where not a lot
+/// of space for text is available.
+pub const SHORT_NAME: &str = "Session";
+/// The name of this comparison.
+pub const NAME: &str = "Session Best";
+
+impl Default for SessionBest {
+    fn default() -> Self {
+        Self {
+            idle_gap: sessions::default_idle_gap(),
+        }
+    }
+}
+
+impl SessionBest {
+    /// Creates a new Session Best Comparison Generator that separates sessions
+    /// by the idle gap provided.
+    pub fn new(idle_gap: TimeSpan) -> Self {
+        Self { idle_gap }
+    }
+
+    /// Accesses the idle gap that separates the sessions.
+    pub fn idle_gap(&self) -> TimeSpan {
+        self.idle_gap
+    }
+
+    /// Sets the idle gap that separates the sessions.
+    pub fn set_idle_gap(&mut self, idle_gap: TimeSpan) {
+        self.idle_gap = idle_gap;
+    }
+}
+
+fn generate(segments: &mut [Segment], session: &[Attempt], method: TimingMethod) {
+    let attempt_id = session
+        .iter()
+        .filter_map(|a| Some((a.time()[method]?, a.index())))
+        .min()
+        .map(|(_, index)| index);
+
+    let mut remaining_segments = segments.iter_mut();
+
+    if let Some(attempt_id) = attempt_id {
+        let mut total_time = TimeSpan::zero();
+        for segment in remaining_segments.by_ref() {
+            let segment_time = segment.segment_history().get(attempt_id).map(|t| t[method]);
+
+            let split_time = match segment_time {
+                Some(Some(segment_time)) => {
+                    total_time += segment_time;
+                    Some(total_time)
+                }
+                Some(None) => None,
+                None => {
+                    segment.comparison_mut(NAME)[method] = None;
+                    break;
+                }
+            };
+
+            segment.comparison_mut(NAME)[method] = split_time;
+        }
+    }
+
+    for segment in remaining_segments {
+        segment.comparison_mut(NAME)[method] = None;
+    }
+}
+
+impl ComparisonGenerator for SessionBest {
+    fn name(&self) -> &str {
+        NAME
+    }
+
+    fn generate(&mut self, segments: &mut [Segment], attempts: &[Attempt]) {
+        let session = sessions::current(attempts, self.idle_gap).unwrap_or(&[]);
+        generate(segments, session, TimingMethod::RealTime);
+        generate(segments, session, TimingMethod::GameTime);
+    }
+
+    fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![Field::new(
+            "Idle Gap (Minutes)".into(),
+            ((self.idle_gap.total_seconds() / 60.0) as u64).into(),
+        )])
+    }
+
+    fn setting_keys(&self) -> &'static [&'static str] {
+        &["IdleGapMinutes"]
+    }
+
+    fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => {
+                let minutes = value.into_uint().unwrap();
+                self.idle_gap = TimeSpan::from_seconds(minutes as f64 * 60.0);
+            }
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -12,7 +12,7 @@ fn test() {
 
     run.comparison_generators_mut().clear();
     run.comparison_generators_mut()
-        .push(Box::new(AverageSegments));
+        .push(Box::new(AverageSegments::default()));
 
     let mut timer = Timer::new(run).unwrap();
 
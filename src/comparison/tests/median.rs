@@ -12,7 +12,7 @@ fn test() {
 
     run.comparison_generators_mut().clear();
     run.comparison_generators_mut()
-        .push(Box::new(MedianSegments));
+        .push(Box::new(MedianSegments::default()));
 
     let mut timer = Timer::new(run).unwrap();
 
@@ -2,3 +2,7 @@ mod average;
 mod balanced_pb;
 mod empty;
 mod median;
+mod percentile;
+mod probability_goal;
+mod session_best;
+mod settings;
@@ -0,0 +1,82 @@
+use crate::comparison::percentile_segments::{PercentileSegments, NAME};
+use crate::comparison::ComparisonGenerator;
+use crate::run::{parser, saver};
+use crate::settings::Value;
+use crate::tests_helper::run_with_splits;
+use crate::{Run, Segment, TimeSpan, Timer};
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run
+}
+
+#[test]
+fn test() {
+    let s = TimeSpan::from_seconds;
+
+    let mut run = run();
+    run.comparison_generators_mut().clear();
+    run.comparison_generators_mut()
+        .push(Box::new(PercentileSegments::new(25.0, 4)));
+
+    let mut timer = Timer::new(run).unwrap();
+
+    assert_eq!(timer.run().segment(0).comparison(NAME).game_time, None);
+
+    run_with_splits(&mut timer, &[3.0]);
+    assert_eq!(
+        timer.run().segment(0).comparison(NAME).game_time,
+        Some(s(3.0))
+    );
+
+    for &split in &[1.0, 4.0, 2.0, 5.0] {
+        run_with_splits(&mut timer, &[split]);
+    }
+
+    // Only the 4 most recent times are considered: 1, 2, 4 and 5. The 25th
+    // percentile lies between 1 and 2.
+    let time = timer.run().segment(0).comparison(NAME).game_time.unwrap();
+    assert!(time > s(1.74) && time < s(1.76));
+}
+
+#[test]
+fn settings_survive_saving_and_parsing() {
+    let mut run = run();
+    let mut generator = PercentileSegments::default();
+    generator.set_value(0, Value::Float(75.0));
+    generator.set_value(1, Value::UInt(10));
+    run.comparison_generators_mut().push(Box::new(generator));
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(buf.as_slice(), None).unwrap();
+
+    let mut fields = parsed
+        .comparison_generators()
+        .iter()
+        .find(|g| g.name() == NAME)
+        .unwrap()
+        .settings_description()
+        .fields
+        .into_iter()
+        .map(|field| field.value);
+
+    assert_eq!(fields.next().unwrap().into_float().unwrap(), 75.0);
+    assert_eq!(fields.next().unwrap().into_uint().unwrap(), 10);
+}
+
+#[test]
+fn is_not_active_by_default() {
+    assert!(run()
+        .comparison_generators()
+        .iter()
+        .all(|g| g.name() != NAME));
+}
+
+#[test]
+fn large_windows_are_saturated() {
+    let mut generator = PercentileSegments::default();
+    generator.set_value(1, Value::UInt(u64::from(u32::MAX) + 1));
+    assert_eq!(generator.window(), u32::MAX);
+}
@@ -0,0 +1,55 @@
+use crate::analysis::pb_chance;
+use crate::comparison::probability_goal::{ProbabilityGoal, NAME};
+use crate::comparison::{balanced_pb, ComparisonGenerator};
+use crate::tests_helper::run_with_splits;
+use crate::{Run, Segment, TimeSpan, Timer, TimingMethod};
+
+fn timer() -> Timer {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    run.push_segment(Segment::new("Second"));
+
+    let mut timer = Timer::new(run).unwrap();
+    for splits in &[[1.0, 3.0], [2.0, 3.5], [1.5, 4.0], [1.2, 2.8]] {
+        run_with_splits(&mut timer, splits);
+    }
+    timer
+}
+
+#[test]
+fn is_not_enabled_by_default() {
+    assert!(!Run::new().is_comparison_generator_enabled(NAME));
+}
+
+#[test]
+fn extremes_are_the_best_and_worst_segments() {
+    let mut run = timer().into_run(true);
+
+    ProbabilityGoal::new(0.0).generate(run.segments_mut(), &[]);
+    assert_eq!(
+        run.segment(1).comparison(NAME).game_time,
+        Some(TimeSpan::from_seconds(2.5))
+    );
+
+    ProbabilityGoal::new(1.0).generate(run.segments_mut(), &[]);
+    assert_eq!(
+        run.segment(1).comparison(NAME).game_time,
+        Some(TimeSpan::from_seconds(4.5))
+    );
+}
+
+#[test]
+fn pb_chance_results_in_the_balanced_pb() {
+    let mut run = timer().into_run(true);
+
+    let chance = pb_chance::for_run(&run, TimingMethod::GameTime);
+    ProbabilityGoal::new(chance).generate(run.segments_mut(), &[]);
+
+    let goal = run.segment(1).comparison(NAME).game_time.unwrap();
+    let balanced_pb = run
+        .segment(1)
+        .comparison(balanced_pb::NAME)
+        .game_time
+        .unwrap();
+    assert!((goal - balanced_pb).total_seconds().abs() < 0.01);
+}
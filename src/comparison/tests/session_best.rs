@@ -0,0 +1,82 @@
+use crate::comparison::session_best::{SessionBest, NAME};
+use crate::comparison::ComparisonGenerator;
+use crate::tests_helper::{create_run, span};
+use crate::{AtomicDateTime, Run, Time};
+use chrono::{DateTime, Duration, Utc};
+
+fn time(seconds: Option<f64>) -> Time {
+    Time::new().with_real_time(seconds.map(span))
+}
+
+/// Creates a Run with two sessions, where the second one ended at the point in
+/// time provided.
+fn run(session_end: DateTime<Utc>) -> Run {
+    let mut run = create_run(&["A", "B"]);
+    let attempts: &[(i64, [Option<f64>; 2])] = &[
+        (-120, [Some(5.0), Some(10.0)]),
+        (-10, [Some(9.0), Some(21.0)]),
+        (-5, [Some(11.0), Some(18.0)]),
+        (-1, [Some(8.0), None]),
+    ];
+    for (i, &(minutes, segments)) in attempts.iter().enumerate() {
+        let index = i as i32 + 1;
+        let started = session_end + Duration::minutes(minutes);
+        let final_time = catch! { segments[0]? + segments[1]? };
+        run.add_attempt_with_index(
+            time(final_time),
+            index,
+            Some(AtomicDateTime::new(started, false)),
+            Some(AtomicDateTime::new(started + Duration::minutes(1), false)),
+            None,
+        );
+        for (segment, &segment_time) in run.segments_mut().iter_mut().zip(&segments) {
+            segment
+                .segment_history_mut()
+                .insert(index, time(segment_time));
+        }
+    }
+    run
+}
+
+fn generate(run: &mut Run) {
+    let attempts = run.attempt_history().to_vec();
+    SessionBest::default().generate(run.segments_mut(), &attempts);
+}
+
+#[test]
+fn is_not_enabled_by_default() {
+    assert!(!Run::new().is_comparison_generator_enabled(NAME));
+}
+
+fn session_end() -> DateTime<Utc> {
+    "2019-01-01T12:00:00Z".parse().unwrap()
+}
+
+#[test]
+fn shows_the_best_run_of_the_most_recent_session() {
+    let mut run = run(session_end());
+    generate(&mut run);
+
+    assert_eq!(run.segment(0).comparison(NAME).real_time, Some(span(11.0)));
+    assert_eq!(run.segment(1).comparison(NAME).real_time, Some(span(29.0)));
+}
+
+#[test]
+fn is_empty_if_no_attempt_of_the_session_got_finished() {
+    let mut run = run(session_end());
+    let started = session_end() + Duration::hours(1);
+    run.add_attempt_with_index(
+        Time::new(),
+        5,
+        Some(AtomicDateTime::new(started, false)),
+        Some(AtomicDateTime::new(started + Duration::minutes(1), false)),
+        None,
+    );
+    run.segment_mut(0)
+        .segment_history_mut()
+        .insert(5, time(Some(7.0)));
+    generate(&mut run);
+
+    assert_eq!(run.segment(0).comparison(NAME).real_time, None);
+    assert_eq!(run.segment(1).comparison(NAME).real_time, None);
+}
@@ -0,0 +1,46 @@
+use crate::comparison::{all_generators, session_best, ComparisonGenerator, SessionBest};
+use crate::run::{parser, saver};
+use crate::settings::Value;
+use crate::{Run, Segment};
+
+#[test]
+fn every_setting_has_a_key() {
+    for generator in all_generators() {
+        let keys = generator.setting_keys();
+        assert_eq!(
+            keys.len(),
+            generator.settings_description().fields.len(),
+            "{}",
+            generator.name()
+        );
+        for (index, key) in keys.iter().enumerate() {
+            assert!(!keys[..index].contains(key), "{}", generator.name());
+        }
+    }
+}
+
+#[test]
+fn settings_are_stored_by_their_keys() {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("First"));
+    let mut generator = SessionBest::default();
+    generator.set_value(0, Value::UInt(45));
+    run.comparison_generators_mut().push(Box::new(generator));
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let saved = String::from_utf8(buf).unwrap();
+    assert!(saved.contains(r#"<Setting name="IdleGapMinutes">45</Setting>"#));
+
+    let parsed = parser::livesplit::parse(saved.as_bytes(), None).unwrap();
+    let value = parsed
+        .comparison_generators()
+        .iter()
+        .find(|g| g.name() == session_best::NAME)
+        .unwrap()
+        .settings_description()
+        .fields
+        .remove(0)
+        .value;
+    assert_eq!(value.into_uint().unwrap(), 45);
+}
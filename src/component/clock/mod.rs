@@ -0,0 +1,307 @@
+//! Provides the Clock Component and relevant types for using it. The Clock
+//! Component shows the current local time, how long the current session has
+//! been going on since the first attempt of the day and how much time has been
+//! spent playing today.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::analysis::total_playtime;
+use crate::platform::local_utc_offset;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, Regular, TimeFormatter, DASH};
+use crate::{AtomicDateTime, Timer};
+use chrono::format::{Item, StrftimeItems};
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::convert::TryFrom;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Clock Component shows the current local time, how long the current
+/// session has been going on since the first attempt of the day and how much
+/// time has been spent playing today.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// Specifies whether the current local time is shown.
+    pub show_wall_clock: bool,
+    /// Specifies whether the time since the first attempt of the day started
+    /// is shown.
+    pub show_session_time: bool,
+    /// Specifies whether the time spent playing today is shown.
+    pub show_playtime_today: bool,
+    /// The format of the current local time, as a `strftime` format string.
+    pub clock_format: String,
+    /// Specifies whether the time zone of the system is used. Otherwise the
+    /// UTC offset provided is used.
+    pub use_local_time_zone: bool,
+    /// The offset from UTC in minutes that is used if the time zone of the
+    /// system is not used.
+    pub utc_offset: i64,
+    /// The accuracy of the session time and the playtime shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            label_color: None,
+            value_color: None,
+            show_wall_clock: true,
+            show_session_time: true,
+            show_playtime_today: true,
+            clock_format: String::from("%H:%M:%S"),
+            use_local_time_zone: true,
+            utc_offset: 0,
+            accuracy: Accuracy::Seconds,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The rows to show, one for each of the values that are enabled.
+    pub rows: Vec<RowState>,
+}
+
+/// The state object of a single row of the component.
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    /// The label of the row.
+    pub label: String,
+    /// The value of the row.
+    pub value: String,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Clock Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Clock Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Clock".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        self.state_at(timer, AtomicDateTime::now())
+    }
+
+    fn state_at(&self, timer: &Timer, now: AtomicDateTime) -> State {
+        let offset = self.utc_offset(now.time);
+        let today_started = start_of_day(now.time, offset);
+
+        let mut rows = Vec::new();
+
+        if self.settings.show_wall_clock {
+            let items = StrftimeItems::new(&self.settings.clock_format);
+            let value = if items.clone().any(|item| item == Item::Error) {
+                DASH.to_string()
+            } else {
+                now.time
+                    .with_timezone(&offset)
+                    .format_with_items(items)
+                    .to_string()
+            };
+            rows.push(RowState {
+                label: String::from("Time"),
+                value,
+            });
+        }
+
+        let formatter = Regular::with_accuracy(self.settings.accuracy);
+
+        // Only the attempts that started today are considered. The Attempt
+        // History is ordered, so these are all the attempts from the first one
+        // that started today onwards.
+        let attempts = timer.run().attempt_history();
+        let first_today = attempts
+            .iter()
+            .position(|a| a.started().map_or(false, |s| s.time >= today_started))
+            .unwrap_or(attempts.len());
+        let attempts_today = &attempts[first_today..];
+
+        let current_attempt_started = timer
+            .attempt_started()
+            .filter(|started| started.time >= today_started);
+
+        if self.settings.show_session_time {
+            let session_started = attempts_today
+                .iter()
+                .filter_map(|a| a.started())
+                .next()
+                .or(current_attempt_started);
+
+            rows.push(RowState {
+                label: String::from("Session"),
+                value: formatter
+                    .format(session_started.map(|started| now - started))
+                    .to_string(),
+            });
+        }
+
+        if self.settings.show_playtime_today {
+            let mut playtime = total_playtime::for_attempts(timer.run(), attempts_today);
+            if current_attempt_started.is_some() {
+                playtime +=
+                    timer.current_attempt_duration() - timer.get_pause_time().unwrap_or_default();
+            }
+
+            rows.push(RowState {
+                label: String::from("Today"),
+                value: formatter.format(playtime).to_string(),
+            });
+        }
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            rows,
+        }
+    }
+
+    fn utc_offset(&self, now: DateTime<Utc>) -> FixedOffset {
+        if self.settings.use_local_time_zone {
+            local_utc_offset(now)
+        } else {
+            // Offsets beyond a day are invalid, so they are treated as UTC.
+            self.settings
+                .utc_offset
+                .checked_mul(60)
+                .and_then(|seconds| i32::try_from(seconds).ok())
+                .and_then(FixedOffset::east_opt)
+                .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap())
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new(
+                "Show Wall Clock".into(),
+                self.settings.show_wall_clock.into(),
+            ),
+            Field::new(
+                "Show Session Time".into(),
+                self.settings.show_session_time.into(),
+            ),
+            Field::new(
+                "Show Playtime Today".into(),
+                self.settings.show_playtime_today.into(),
+            ),
+            Field::new(
+                "Clock Format".into(),
+                self.settings.clock_format.clone().into(),
+            ),
+            Field::new(
+                "Use Local Time Zone".into(),
+                self.settings.use_local_time_zone.into(),
+            ),
+            Field::new(
+                "UTC Offset (Minutes)".into(),
+                self.settings.utc_offset.into(),
+            ),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.value_color = value.into(),
+            3 => self.settings.show_wall_clock = value.into(),
+            4 => self.settings.show_session_time = value.into(),
+            5 => self.settings.show_playtime_today = value.into(),
+            6 => self.settings.clock_format = value.into(),
+            7 => self.settings.use_local_time_zone = value.into(),
+            8 => self.settings.utc_offset = value.into(),
+            9 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// Determines the point in time the day of the point in time provided started
+/// at in the time zone described by the UTC offset.
+fn start_of_day(time: DateTime<Utc>, offset: FixedOffset) -> DateTime<Utc> {
+    let midnight = time
+        .with_timezone(&offset)
+        .naive_local()
+        .date()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    // With a fixed offset every local time maps to exactly one point in time.
+    offset
+        .from_local_datetime(&midnight)
+        .single()
+        .unwrap()
+        .with_timezone(&Utc)
+}
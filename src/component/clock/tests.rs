@@ -0,0 +1,82 @@
+use super::{Component, Settings};
+use crate::tests_helper::create_run;
+use crate::{AtomicDateTime, Time, Timer};
+use chrono::{TimeZone, Utc};
+
+fn date_time(h: u32, m: u32) -> AtomicDateTime {
+    AtomicDateTime::new(
+        Utc.from_utc_datetime(
+            &chrono::NaiveDate::from_ymd_opt(2019, 5, 2)
+                .unwrap()
+                .and_hms_opt(h, m, 0)
+                .unwrap(),
+        ),
+        false,
+    )
+}
+
+fn component() -> Component {
+    Component::with_settings(Settings {
+        use_local_time_zone: false,
+        utc_offset: 120,
+        ..Default::default()
+    })
+}
+
+fn values(component: &Component, timer: &Timer, now: AtomicDateTime) -> Vec<String> {
+    component
+        .state_at(timer, now)
+        .rows
+        .into_iter()
+        .map(|row| row.value)
+        .collect()
+}
+
+#[test]
+fn shows_the_time_in_the_time_zone_chosen() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+    let mut component = component();
+
+    let state = component.state_at(&timer, date_time(21, 30));
+    assert_eq!(state.rows.len(), 3);
+    assert_eq!(state.rows[0].label, "Time");
+    assert_eq!(state.rows[0].value, "23:30:00");
+    assert_eq!(state.rows[1].value, "—");
+    assert_eq!(state.rows[2].value, "0:00");
+
+    component.settings_mut().clock_format = String::from("%H:%M %Q");
+    assert_eq!(values(&component, &timer, date_time(21, 30))[0], "—");
+}
+
+#[test]
+fn only_considers_the_attempts_of_today() {
+    let mut run = create_run(&["A"]);
+    let attempts = [(20, 0, 21, 0), (22, 30, 23, 0), (23, 0, 23, 30)];
+    for &(start_h, start_m, end_h, end_m) in &attempts {
+        let (started, ended) = (date_time(start_h, start_m), date_time(end_h, end_m));
+        run.add_attempt(Time::default(), Some(started), Some(ended), None);
+    }
+    let timer = Timer::new(run).unwrap();
+
+    // In UTC+2, the day started at 22:00 UTC, so the first attempt is from the
+    // day before.
+    let values = values(&component(), &timer, date_time(23, 45));
+    assert_eq!(values, ["01:45:00", "1:15:00", "1:00:00"]);
+
+    let mut component = component();
+    component.settings_mut().utc_offset = 0;
+    let state = component.state_at(&timer, date_time(23, 45));
+    assert_eq!(state.rows[1].value, "3:45:00");
+    assert_eq!(state.rows[2].value, "2:00:00");
+}
+
+#[test]
+fn treats_invalid_offsets_as_utc() {
+    let timer = Timer::new(create_run(&["A"])).unwrap();
+    let mut component = component();
+
+    for &offset in &[24 * 60, i64::from(i32::MAX) / 60 + 1, i64::MAX, i64::MIN] {
+        component.settings_mut().utc_offset = offset;
+        assert_eq!(values(&component, &timer, date_time(21, 30))[0], "21:30:00");
+    }
+}
@@ -0,0 +1,345 @@
+//! Provides the Comparison Table Component and relevant types for using it. The
+//! Comparison Table Component is a component that shows a small table with the
+//! split time, the segment time and the delta of several comparisons at once
+//! for a single split. This way multiple Delta or Previous Segment Components
+//! don't need to be stacked in order to compare against multiple comparisons.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::comparison::{self, average_segments, balanced_pb, best_segments, personal_best};
+use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, Delta, Regular, TimeFormatter};
+use crate::{analysis, GeneralLayoutSettings, Timer, TimerPhase};
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+const SETTINGS_BEFORE_COMPARISONS: usize = 10;
+
+/// The Comparison Table Component is a component that shows a small table with
+/// the split time, the segment time and the delta of several comparisons at
+/// once for a single split. This way multiple Delta or Previous Segment
+/// Components don't need to be stacked in order to compare against multiple
+/// comparisons.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// Specifies whether the split that got completed last is shown instead of
+    /// the split that is currently being run.
+    pub show_previous_split: bool,
+    /// Specifies whether the comparisons' split times are shown.
+    pub show_split_time: bool,
+    /// Specifies whether the comparisons' segment times are shown.
+    pub show_segment_time: bool,
+    /// Specifies whether the deltas of the current attempt are shown.
+    pub show_delta: bool,
+    /// The accuracy of the split times and the segment times shown.
+    pub accuracy: Accuracy,
+    /// Specifies if the decimals should not be shown anymore when a delta is
+    /// above one minute.
+    pub drop_decimals: bool,
+    /// The accuracy of the deltas shown.
+    pub delta_accuracy: Accuracy,
+    /// The comparisons to show. Comparisons that don't exist for the current
+    /// Run are skipped.
+    pub comparisons: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            label_color: None,
+            show_previous_split: false,
+            show_split_time: true,
+            show_segment_time: true,
+            show_delta: true,
+            accuracy: Accuracy::Tenths,
+            drop_decimals: true,
+            delta_accuracy: Accuracy::Tenths,
+            comparisons: vec![
+                personal_best::NAME.to_string(),
+                best_segments::NAME.to_string(),
+                average_segments::NAME.to_string(),
+                balanced_pb::NAME.to_string(),
+            ],
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The name of the split the table is about. This is meant to be shown
+    /// above the names of the comparisons.
+    pub split_name: String,
+    /// The labels of the columns of the table. They are meant to be shown
+    /// above the cells of the rows.
+    pub column_labels: Vec<String>,
+    /// The rows of the table, one for each comparison.
+    pub rows: Vec<RowState>,
+}
+
+/// The state object of a single row of the table.
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    /// The shortened name of the comparison.
+    pub name: String,
+    /// The cells of the row. There is one cell for each column label.
+    pub cells: Vec<CellState>,
+}
+
+/// The state object of a single cell of the table.
+#[derive(Serialize, Deserialize)]
+pub struct CellState {
+    /// The value shown in the cell.
+    pub value: String,
+    /// The semantic coloring information the value carries.
+    pub semantic_color: SemanticColor,
+    /// The visual color of the value.
+    pub visual_color: Color,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Comparison Table Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Comparison Table Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Comparison Table".into()
+    }
+
+    /// Calculates the component's state based on the timer and the layout
+    /// settings provided.
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let mut column_labels = Vec::new();
+        if self.settings.show_split_time {
+            column_labels.push(String::from("Split"));
+        }
+        if self.settings.show_segment_time {
+            column_labels.push(String::from("Segment"));
+        }
+        if self.settings.show_delta {
+            column_labels.push(String::from("+/−"));
+        }
+
+        let mut state = State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            split_name: String::new(),
+            column_labels,
+            rows: Vec::new(),
+        };
+
+        let run = timer.timed_run();
+        if run.is_empty() {
+            return state;
+        }
+
+        let phase = timer.current_phase();
+        let current_split_index = timer.current_split_index().unwrap_or(0);
+        let segment_index = if self.settings.show_previous_split {
+            match current_split_index.checked_sub(1) {
+                Some(index) => index,
+                None => return state,
+            }
+        } else {
+            current_split_index.min(run.len() - 1)
+        };
+
+        let segment = run.segment(segment_index);
+        state.split_name = segment.name().to_owned();
+
+        let method = timer.current_timing_method();
+        let is_completed = segment_index < current_split_index;
+        let is_live =
+            !is_completed && (phase == TimerPhase::Running || phase == TimerPhase::Paused);
+
+        let formatter = Regular::with_accuracy(self.settings.accuracy);
+        let delta_formatter =
+            Delta::custom(self.settings.drop_decimals, self.settings.delta_accuracy);
+
+        let default_cell = |value: String| CellState {
+            value,
+            semantic_color: SemanticColor::Default,
+            visual_color: SemanticColor::Default.visualize(layout_settings),
+        };
+
+        for comparison in &self.settings.comparisons {
+            if !run.comparisons().any(|c| c == comparison) {
+                continue;
+            }
+
+            let mut cells = Vec::new();
+
+            if self.settings.show_split_time {
+                let split_time = segment.comparison(comparison)[method];
+                cells.push(default_cell(formatter.format(split_time).to_string()));
+            }
+
+            if self.settings.show_segment_time {
+                let segment_time =
+                    analysis::comparison_segment_time(run, segment_index, comparison, method);
+                cells.push(default_cell(formatter.format(segment_time).to_string()));
+            }
+
+            if self.settings.show_delta {
+                let delta = if is_completed {
+                    catch! {
+                        segment.split_time()[method]? - segment.comparison(comparison)[method]?
+                    }
+                } else if is_live {
+                    analysis::check_live_delta(timer, true, comparison, method)
+                } else {
+                    None
+                };
+
+                let semantic_color = if is_completed || delta.is_some() {
+                    analysis::split_color(
+                        timer,
+                        delta,
+                        segment_index,
+                        true,
+                        is_completed,
+                        comparison,
+                        method,
+                    )
+                } else {
+                    SemanticColor::Default
+                };
+
+                cells.push(CellState {
+                    value: delta_formatter.format(delta).to_string(),
+                    semantic_color,
+                    visual_color: semantic_color.visualize(layout_settings),
+                });
+            }
+
+            state.rows.push(RowState {
+                name: comparison::shorten(comparison).to_owned(),
+                cells,
+            });
+        }
+
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut settings = SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new(
+                "Show Previous Split".into(),
+                self.settings.show_previous_split.into(),
+            ),
+            Field::new(
+                "Show Split Time".into(),
+                self.settings.show_split_time.into(),
+            ),
+            Field::new(
+                "Show Segment Time".into(),
+                self.settings.show_segment_time.into(),
+            ),
+            Field::new("Show Delta".into(), self.settings.show_delta.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new("Drop Decimals".into(), self.settings.drop_decimals.into()),
+            Field::new("Delta Accuracy".into(), self.settings.delta_accuracy.into()),
+            Field::new(
+                "Comparisons".into(),
+                Value::UInt(self.settings.comparisons.len() as _),
+            ),
+        ]);
+
+        settings.fields.extend(
+            self.settings
+                .comparisons
+                .iter()
+                .map(|comparison| Field::new("Comparison".into(), comparison.clone().into())),
+        );
+
+        settings
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.label_color = value.into(),
+            2 => self.settings.show_previous_split = value.into(),
+            3 => self.settings.show_split_time = value.into(),
+            4 => self.settings.show_segment_time = value.into(),
+            5 => self.settings.show_delta = value.into(),
+            6 => self.settings.accuracy = value.into(),
+            7 => self.settings.drop_decimals = value.into(),
+            8 => self.settings.delta_accuracy = value.into(),
+            9 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings
+                    .comparisons
+                    .resize(new_len, personal_best::NAME.to_string());
+            }
+            index => {
+                let index = index - SETTINGS_BEFORE_COMPARISONS;
+                if let Some(comparison) = self.settings.comparisons.get_mut(index) {
+                    *comparison = value.into();
+                } else {
+                    panic!("Unsupported Setting Index")
+                }
+            }
+        }
+    }
+}
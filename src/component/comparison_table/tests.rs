@@ -0,0 +1,71 @@
+use super::{Component, Settings};
+use crate::comparison::personal_best;
+use crate::settings::SemanticColor;
+use crate::tests_helper::{create_timer, run_with_splits, span, start_run};
+use crate::GeneralLayoutSettings;
+
+#[test]
+fn shows_each_comparison_for_the_current_split() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+
+    let mut component = Component::new();
+    component
+        .settings_mut()
+        .comparisons
+        .push(String::from("Doesn't Exist"));
+    let layout_settings = GeneralLayoutSettings::default();
+
+    start_run(&mut timer);
+    timer.set_game_time(span(12.0));
+
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.split_name, "A");
+    assert_eq!(state.column_labels, ["Split", "Segment", "+/−"]);
+    assert_eq!(state.rows.len(), 4);
+
+    let row = &state.rows[0];
+    assert_eq!(row.name, personal_best::SHORT_NAME);
+    assert_eq!(row.cells[0].value, "0:10.0");
+    assert_eq!(row.cells[1].value, "0:10.0");
+    assert_eq!(row.cells[2].value, "+2.0");
+    assert_eq!(row.cells[2].semantic_color, SemanticColor::BehindLosingTime);
+
+    timer.split();
+    timer.set_game_time(span(14.0));
+
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.split_name, "B");
+    assert_eq!(state.rows[0].cells[0].value, "0:25.0");
+    assert_eq!(state.rows[0].cells[1].value, "0:15.0");
+    assert_eq!(state.rows[0].cells[2].value, "—");
+}
+
+#[test]
+fn shows_the_delta_of_the_previous_split() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+
+    let component = Component::with_settings(Settings {
+        show_previous_split: true,
+        show_split_time: false,
+        show_segment_time: false,
+        ..Default::default()
+    });
+    let layout_settings = GeneralLayoutSettings::default();
+
+    start_run(&mut timer);
+    assert!(component.state(&timer, &layout_settings).rows.is_empty());
+
+    timer.set_game_time(span(9.0));
+    timer.split();
+
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.split_name, "A");
+    assert_eq!(state.column_labels, ["+/−"]);
+    assert_eq!(state.rows[0].cells[0].value, "−1.0");
+    assert_eq!(
+        state.rows[0].cells[0].semantic_color,
+        SemanticColor::BestSegment
+    );
+}
@@ -0,0 +1,335 @@
+//! Provides the Counter Component and relevant types for using it. The Counter
+//! Component is a component that shows a number that can be incremented,
+//! decremented and reset, like the amount of deaths or collectibles. The
+//! counter can be modified by the hotkeys configured in its settings through a
+//! Hotkey System that the counter is connected to.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::hotkey::KeyCode;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+use std::sync::Arc;
+
+#[cfg(test)]
+mod tests;
+
+/// The Counter Component is a component that shows a number that can be
+/// incremented, decremented and reset, like the amount of deaths or
+/// collectibles. The counter can be modified by the hotkeys configured in its
+/// settings through a Hotkey System that the counter is connected to.
+pub struct Component {
+    counter: Counter,
+    settings: Settings,
+}
+
+/// A handle to the value of a Counter Component. The handle can be cloned and
+/// shared with other parts of the application, like a Hotkey System, so that
+/// they can modify the counter. All the clones refer to the same counter.
+#[derive(Default, Clone, Debug)]
+pub struct Counter {
+    inner: Arc<RwLock<CounterInner>>,
+}
+
+#[derive(Default, Debug)]
+struct CounterInner {
+    value: i64,
+    start_value: i64,
+    step: i64,
+    increment_key: Option<KeyCode>,
+    decrement_key: Option<KeyCode>,
+    reset_key: Option<KeyCode>,
+}
+
+impl Counter {
+    fn configure(&self, settings: &Settings) {
+        let mut inner = self.inner.write();
+        inner.start_value = settings.start_value;
+        inner.step = settings.step;
+        inner.increment_key = settings.increment_key;
+        inner.decrement_key = settings.decrement_key;
+        inner.reset_key = settings.reset_key;
+    }
+
+    pub(crate) fn is_same(&self, other: &Counter) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+
+    /// Returns all the hotkeys that are configured for modifying the counter.
+    pub(crate) fn hotkeys(&self) -> Vec<KeyCode> {
+        let inner = self.inner.read();
+        [inner.increment_key, inner.decrement_key, inner.reset_key]
+            .iter()
+            .filter_map(|&k| k)
+            .collect()
+    }
+
+    /// Modifies the counter according to the action the hotkey provided is
+    /// configured for. If it isn't one of the counter's hotkeys, nothing
+    /// happens.
+    pub(crate) fn handle_hotkey(&self, hotkey: KeyCode) {
+        let hotkey = Some(hotkey);
+        let (increment, decrement, reset) = {
+            let inner = self.inner.read();
+            (
+                inner.increment_key == hotkey,
+                inner.decrement_key == hotkey,
+                inner.reset_key == hotkey,
+            )
+        };
+
+        if increment {
+            self.increment();
+        } else if decrement {
+            self.decrement();
+        } else if reset {
+            self.reset();
+        }
+    }
+
+    /// Accesses the current value of the counter.
+    pub fn value(&self) -> i64 {
+        self.inner.read().value
+    }
+
+    /// Sets the current value of the counter.
+    pub fn set_value(&self, value: i64) {
+        self.inner.write().value = value;
+    }
+
+    /// Increments the counter by its step.
+    pub fn increment(&self) {
+        let mut inner = self.inner.write();
+        inner.value = inner.value.saturating_add(inner.step);
+    }
+
+    /// Decrements the counter by its step.
+    pub fn decrement(&self) {
+        let mut inner = self.inner.write();
+        inner.value = inner.value.saturating_sub(inner.step);
+    }
+
+    /// Resets the counter to its start value.
+    pub fn reset(&self) {
+        let mut inner = self.inner.write();
+        inner.value = inner.start_value;
+    }
+}
+
+impl Default for Component {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clone for Component {
+    fn clone(&self) -> Self {
+        // The clone gets its own counter, so that modifying one of them
+        // doesn't affect the other.
+        let component = Self::with_settings(self.settings.clone());
+        component.counter.set_value(self.counter.value());
+        component
+    }
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label's text.
+    pub text: String,
+    /// The value the counter starts at and gets reset to.
+    pub start_value: i64,
+    /// The amount the counter is incremented or decremented by.
+    pub step: i64,
+    /// The key to use for incrementing the counter.
+    pub increment_key: Option<KeyCode>,
+    /// The key to use for decrementing the counter.
+    pub decrement_key: Option<KeyCode>,
+    /// The key to use for resetting the counter to its start value.
+    pub reset_key: Option<KeyCode>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            text: String::from("Counter"),
+            start_value: 0,
+            step: 1,
+            increment_key: None,
+            decrement_key: None,
+            reset_key: None,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label's text.
+    pub text: String,
+    /// The current value of the counter.
+    pub value: String,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Counter Component.
+    pub fn new() -> Self {
+        Self::with_settings(Default::default())
+    }
+
+    /// Creates a new Counter Component with the given settings. The counter
+    /// starts at the start value of the settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        let component = Self {
+            counter: Counter::default(),
+            settings,
+        };
+        component.sync_counter();
+        component.counter.reset();
+        component
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Counter".into()
+    }
+
+    /// Accesses the counter of the component. The counter can be cloned and
+    /// connected to a Hotkey System, so that the counter can be modified by
+    /// the hotkeys configured in the settings.
+    pub fn counter(&self) -> &Counter {
+        &self.counter
+    }
+
+    /// Increments the counter by the step configured.
+    pub fn increment(&mut self) {
+        self.sync_counter();
+        self.counter.increment();
+    }
+
+    /// Decrements the counter by the step configured.
+    pub fn decrement(&mut self) {
+        self.sync_counter();
+        self.counter.decrement();
+    }
+
+    /// Resets the counter to the start value configured.
+    pub fn reset(&mut self) {
+        self.sync_counter();
+        self.counter.reset();
+    }
+
+    fn sync_counter(&self) {
+        self.counter.configure(&self.settings);
+    }
+
+    /// Calculates the component's state.
+    pub fn state(&self) -> State {
+        // The settings may have been modified directly, so the counter needs
+        // to know about the current start value, step and hotkeys.
+        self.sync_counter();
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            text: self.settings.text.clone(),
+            value: self.counter.value().to_string(),
+            display_two_rows: self.settings.display_two_rows,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Text".into(), self.settings.text.clone().into()),
+            Field::new("Start Value".into(), self.settings.start_value.into()),
+            Field::new("Step".into(), self.settings.step.into()),
+            Field::new("Increment Key".into(), self.settings.increment_key.into()),
+            Field::new("Decrement Key".into(), self.settings.decrement_key.into()),
+            Field::new("Reset Key".into(), self.settings.reset_key.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.text = value.into(),
+            5 => self.settings.start_value = value.into(),
+            6 => self.settings.step = value.into(),
+            7 => self.settings.increment_key = value.into(),
+            8 => self.settings.decrement_key = value.into(),
+            9 => self.settings.reset_key = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+        self.sync_counter();
+    }
+}
@@ -0,0 +1,80 @@
+use super::{Component, Settings};
+use crate::settings::Value;
+use crate::HotkeyConfig;
+
+#[test]
+fn increments_decrements_and_resets() {
+    let mut component = Component::with_settings(Settings {
+        start_value: 5,
+        step: 2,
+        ..Default::default()
+    });
+    assert_eq!(component.state().value, "5");
+
+    component.increment();
+    component.increment();
+    assert_eq!(component.state().value, "9");
+
+    component.decrement();
+    assert_eq!(component.state().value, "7");
+
+    component.reset();
+    assert_eq!(component.state().value, "5");
+}
+
+#[test]
+fn shared_counter_uses_the_current_settings() {
+    let mut component = Component::new();
+    let counter = component.counter().clone();
+
+    counter.increment();
+    assert_eq!(component.state().value, "1");
+
+    component.set_value(6, Value::Int(10));
+    counter.decrement();
+    assert_eq!(component.state().value, "-9");
+
+    component.settings_mut().start_value = 3;
+    component.state();
+    counter.reset();
+    assert_eq!(component.state().value, "3");
+}
+
+#[test]
+fn clones_have_their_own_counter() {
+    let mut component = Component::new();
+    component.increment();
+
+    let clone = component.clone();
+    component.increment();
+
+    assert_eq!(clone.state().value, "1");
+    assert_eq!(component.state().value, "2");
+}
+
+#[test]
+fn hotkeys_only_modify_the_counters_using_them() {
+    let config = HotkeyConfig::default();
+    let (first_key, second_key) = (config.split.unwrap(), config.reset.unwrap());
+
+    let deaths = Component::with_settings(Settings {
+        increment_key: Some(first_key),
+        ..Default::default()
+    });
+    let mut collectibles = Component::with_settings(Settings {
+        increment_key: Some(second_key),
+        ..Default::default()
+    });
+    assert_eq!(collectibles.counter().hotkeys(), [second_key]);
+
+    deaths.counter().handle_hotkey(first_key);
+    collectibles.counter().handle_hotkey(first_key);
+    assert_eq!(deaths.state().value, "1");
+    assert_eq!(collectibles.state().value, "0");
+
+    collectibles.set_value(9, Value::Hotkey(Some(first_key)));
+    collectibles.counter().handle_hotkey(first_key);
+    assert_eq!(collectibles.state().value, "0");
+    collectibles.counter().handle_hotkey(second_key);
+    assert_eq!(collectibles.state().value, "1");
+}
@@ -7,7 +7,7 @@
 use super::timer;
 use crate::comparison::{self, best_segments, none};
 use crate::settings::{
-    CachedImageId, Field, Gradient, ImageData, SemanticColor, SettingsDescription, Value,
+    CachedImageId, Color, Field, Gradient, ImageData, SemanticColor, SettingsDescription, Value,
 };
 use crate::timing::formatter::{
     none_wrapper::DashWrapper, timer as formatter, Accuracy, DigitsFormat, Short, TimeFormatter,
@@ -164,7 +164,7 @@ impl Component {
             .unwrap_or_else(|| timer.current_timing_method());
 
         let last_split_index = if current_phase == TimerPhase::Ended {
-            timer.run().len() - 1
+            timer.timed_run().len() - 1
         } else {
             timer.current_split_index().unwrap_or(0)
         };
@@ -187,15 +187,16 @@ impl Component {
             let mut hide_comparison = self.settings.hide_second_comparison;
 
             if hide_comparison
-                || !timer.run().comparisons().any(|c| c == comparison2)
+                || !timer.timed_run().comparisons().any(|c| c == comparison2)
                 || comparison2 == none::NAME
             {
                 hide_comparison = true;
-                if !timer.run().comparisons().any(|c| c == comparison1) || comparison1 == none::NAME
+                if !timer.timed_run().comparisons().any(|c| c == comparison1)
+                    || comparison1 == none::NAME
                 {
                     comparison1 = timer.current_comparison();
                 }
-            } else if !timer.run().comparisons().any(|c| c == comparison1)
+            } else if !timer.timed_run().comparisons().any(|c| c == comparison1)
                 || comparison1 == none::NAME
             {
                 hide_comparison = true;
@@ -230,8 +231,20 @@ impl Component {
             segment_time = calculate_segment_time(timer, TimingMethod::RealTime, last_split_index);
         }
 
-        let (top_color, bottom_color) =
-            timer::top_and_bottom_color((170.0 / 255.0, 170.0 / 255.0, 170.0 / 255.0, 1.0).into());
+        let is_counting_down = segment_time.map_or(false, |t| t < TimeSpan::zero());
+
+        if is_counting_down && !self.settings.timer.show_countdown_sign {
+            segment_time = segment_time.map(|t| -t);
+        }
+
+        let segment_timer_color = self
+            .settings
+            .timer
+            .countdown_color
+            .filter(|_| is_counting_down)
+            .unwrap_or_else(|| (170.0 / 255.0, 170.0 / 255.0, 170.0 / 255.0, 1.0).into());
+
+        let (top_color, bottom_color) = timer::top_and_bottom_color(segment_timer_color);
 
         let background = Gradient::Transparent;
 
@@ -358,6 +371,14 @@ impl Component {
                 self.settings.show_segment_name.into(),
             ),
             Field::new("Display Icon".into(), self.settings.display_icon.into()),
+            Field::new(
+                "Countdown Color".into(),
+                self.settings.timer.countdown_color.into(),
+            ),
+            Field::new(
+                "Show Minus Sign During Countdown".into(),
+                self.settings.timer.show_countdown_sign.into(),
+            ),
         ])
     }
 
@@ -395,6 +416,16 @@ impl Component {
             10 => self.settings.segment_timer.accuracy = value.into(),
             11 => self.settings.show_segment_name = value.into(),
             12 => self.settings.display_icon = value.into(),
+            13 => {
+                let value: Option<Color> = value.into();
+                self.settings.timer.countdown_color = value;
+                self.timer.settings_mut().countdown_color = value;
+            }
+            14 => {
+                let value: bool = value.into();
+                self.settings.timer.show_countdown_sign = value;
+                self.timer.settings_mut().show_countdown_sign = value;
+            }
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -407,20 +438,23 @@ fn calculate_comparison_time(
     last_split_index: usize,
 ) -> Option<TimeSpan> {
     if comparison == best_segments::NAME {
-        timer.run().segment(last_split_index).best_segment_time()[timing_method]
+        timer
+            .timed_run()
+            .segment(last_split_index)
+            .best_segment_time()[timing_method]
     } else if last_split_index == 0 {
         timer
-            .run()
+            .timed_run()
             .segment(0)
             .comparison_timing_method(comparison, timing_method)
     } else if timer.current_split_index() > Some(0) {
         Some(
             timer
-                .run()
+                .timed_run()
                 .segment(last_split_index)
                 .comparison_timing_method(comparison, timing_method)?
                 - timer
-                    .run()
+                    .timed_run()
                     .segment(last_split_index - 1)
                     .comparison_timing_method(comparison, timing_method)?,
         )
@@ -435,13 +469,13 @@ fn calculate_segment_time(
     last_split_index: usize,
 ) -> Option<TimeSpan> {
     let last_split = if last_split_index > 0 {
-        timer.run().segment(last_split_index - 1).split_time()[timing_method]
+        timer.timed_run().segment(last_split_index - 1).split_time()[timing_method]
     } else {
         Some(TimeSpan::zero())
     };
 
     if timer.current_phase() == TimerPhase::NotRunning {
-        Some(timer.run().offset())
+        Some(timer.timed_run().offset())
     } else {
         Some(timer.current_time()[timing_method]? - last_split?)
     }
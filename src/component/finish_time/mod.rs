@@ -0,0 +1,210 @@
+//! Provides the Finish Time Component and relevant types for using it. The
+//! Finish Time Component is a component that shows the predicted final times
+//! of the current attempt for two probabilities, such as `50% ≤ 1:23:45, 90% ≤
+//! 1:25:10`. If there is no active attempt it shows the predicted final times
+//! of a general attempt.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::analysis::finish_time;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, Regular, TimeFormatter};
+use crate::Timer;
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Finish Time Component is a component that shows the predicted final
+/// times of the current attempt for two probabilities, such as `50% ≤ 1:23:45,
+/// 90% ≤ 1:25:10`. If there is no active attempt it shows the predicted final
+/// times of a general attempt.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the times shown.
+    pub accuracy: Accuracy,
+    /// The probability of the first final time shown, as a value from 0 (0%)
+    /// to 1 (100%).
+    pub first_probability: f64,
+    /// The probability of the second final time shown, as a value from 0 (0%)
+    /// to 1 (100%).
+    pub second_probability: f64,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Seconds,
+            first_probability: 0.5,
+            second_probability: 0.9,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label's text.
+    pub text: String,
+    /// The predicted final times.
+    pub finish_time: String,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Finish Time Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Finish Time Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Finish Time".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        let distribution = finish_time::for_timer(timer);
+        let formatter = Regular::with_accuracy(self.settings.accuracy);
+
+        let finish_time = [
+            self.settings.first_probability,
+            self.settings.second_probability,
+        ]
+        .iter()
+        .map(|&probability| {
+            let probability = clamp_probability(probability);
+            format!(
+                "{:.0}% ≤ {}",
+                100.0 * probability,
+                formatter.format(distribution.as_ref().map(|d| d.quantile(probability))),
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            text: self.name().into_owned(),
+            finish_time,
+            display_two_rows: self.settings.display_two_rows,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new(
+                "First Probability".into(),
+                self.settings.first_probability.into(),
+            ),
+            Field::new(
+                "Second Probability".into(),
+                self.settings.second_probability.into(),
+            ),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.accuracy = value.into(),
+            5 => self.settings.first_probability = clamp_probability(value.into()),
+            6 => self.settings.second_probability = clamp_probability(value.into()),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
+
+/// Restricts the probability to be a value from 0 (0%) to 1 (100%). Settings
+/// that are modified directly may contain any value, so this also needs to be
+/// done before the probability is shown.
+fn clamp_probability(probability: f64) -> f64 {
+    if probability > 1.0 {
+        1.0
+    } else if probability >= 0.0 {
+        probability
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,42 @@
+use super::{Component, Settings};
+use crate::settings::Value;
+use crate::tests_helper::{create_timer, run_with_splits};
+
+#[test]
+fn probabilities_are_clamped_when_set() {
+    let mut component = Component::new();
+
+    component.set_value(5, Value::Float(-0.5));
+    component.set_value(6, Value::Float(1.5));
+    assert_eq!(component.settings().first_probability, 0.0);
+    assert_eq!(component.settings().second_probability, 1.0);
+
+    component.set_value(5, Value::Float(0.0));
+    component.set_value(6, Value::Float(1.0));
+    assert_eq!(component.settings().first_probability, 0.0);
+    assert_eq!(component.settings().second_probability, 1.0);
+}
+
+#[test]
+fn probabilities_out_of_range_are_shown_clamped() {
+    let mut timer = create_timer(&["A"]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[12.0]);
+
+    let clamped = Component::with_settings(Settings {
+        first_probability: -0.5,
+        second_probability: 1.5,
+        ..Default::default()
+    })
+    .state(&timer);
+    let edges = Component::with_settings(Settings {
+        first_probability: 0.0,
+        second_probability: 1.0,
+        ..Default::default()
+    })
+    .state(&timer);
+
+    assert!(clamped.finish_time.starts_with("0% ≤ "));
+    assert!(clamped.finish_time.contains(", 100% ≤ "));
+    assert_eq!(clamped.finish_time, edges.finish_time);
+}
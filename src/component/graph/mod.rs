@@ -1,8 +1,11 @@
 //! Provides the Graph Component and relevant types for using it. The Graph
 //! Component visualizes how far the current attempt has been ahead or behind
 //! the chosen comparison throughout the whole attempt. All the individual
-//! deltas are shown as points in a graph.
+//! deltas are shown as points in a graph. Alternatively the graph can show the
+//! segment deltas as bars, the cumulative possible time save or the times of
+//! all the finished attempts.
 
+use crate::analysis::possible_time_save;
 use crate::settings::{Color, Field, SettingsDescription, Value};
 use crate::{analysis, comparison, GeneralLayoutSettings, TimeSpan, Timer, TimerPhase};
 use serde::{Deserialize, Serialize};
@@ -10,6 +13,9 @@ use serde_json::{to_writer, Result};
 use std::borrow::Cow;
 use std::io::Write;
 
+#[cfg(test)]
+mod tests;
+
 const GRAPH_EDGE_VALUE: f32 = 200.0;
 const GRAPH_EDGE_MIN: f32 = 5.0;
 
@@ -24,6 +30,30 @@ pub struct Component {
     settings: Settings,
 }
 
+/// Describes what the Graph Component visualizes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphMode {
+    /// The graph shows how far the current attempt has been ahead or behind
+    /// the comparison throughout the whole attempt.
+    Delta,
+    /// The graph shows a bar for each segment of the current attempt, showing
+    /// how much time got saved or lost on that segment compared to the
+    /// comparison.
+    SegmentDelta,
+    /// The graph shows how much time could be saved on the comparison compared
+    /// to the sum of best segments, accumulated over all the segments.
+    PossibleTimeSave,
+    /// The graph shows the final times of all the finished attempts, relative
+    /// to the final time of the comparison.
+    History,
+}
+
+impl Default for GraphMode {
+    fn default() -> Self {
+        GraphMode::Delta
+    }
+}
+
 /// The Settings for this component.
 #[derive(Clone, Serialize, Deserialize)]
 #[serde(default)]
@@ -59,6 +89,8 @@ pub struct Settings {
     pub complete_fill_color: Color,
     /// The height of the graph.
     pub height: u32,
+    /// Specifies what the graph visualizes.
+    pub mode: GraphMode,
 }
 
 /// The state object describes the information to visualize for this component.
@@ -105,6 +137,10 @@ pub struct State {
     pub best_segment_color: Color,
     /// The height of the graph.
     pub height: u32,
+    /// Describes whether the graph is a bar graph. In that case every two
+    /// consecutive points describe the top edge of a bar. The points are not
+    /// meant to be visualized as circles then.
+    pub is_bar_graph: bool,
 }
 
 /// Describes a point on the graph to visualize.
@@ -133,6 +169,7 @@ impl Default for Settings {
             partial_fill_color: (1.0, 1.0, 1.0, 0.25).into(),
             complete_fill_color: (1.0, 1.0, 1.0, 0.4).into(),
             height: 80,
+            mode: GraphMode::default(),
         }
     }
 }
@@ -156,6 +193,29 @@ struct DrawInfo {
     is_live_delta_active: bool,
 }
 
+/// The values visualized by the graph modes other than the delta mode. The x
+/// coordinates are already final, while the values still need to be mapped to
+/// y coordinates.
+#[derive(Default)]
+struct SeriesInfo {
+    values: Vec<(f32, TimeSpan, bool)>,
+    max_value: TimeSpan,
+    min_value: TimeSpan,
+    vertical_grid_lines: Vec<f32>,
+    is_live_value_active: bool,
+}
+
+impl SeriesInfo {
+    fn push(&mut self, x: f32, value: TimeSpan, is_best_segment: bool) {
+        if value > self.max_value {
+            self.max_value = value;
+        } else if value < self.min_value {
+            self.min_value = value;
+        }
+        self.values.push((x, value, is_best_segment));
+    }
+}
+
 impl Component {
     /// Creates a new Graph Component.
     pub fn new() -> Self {
@@ -188,10 +248,16 @@ impl Component {
     }
 
     fn text(&self, comparison: Option<&str>) -> Cow<'_, str> {
+        let name = match self.settings.mode {
+            GraphMode::Delta => "Graph",
+            GraphMode::SegmentDelta => "Segment Graph",
+            GraphMode::PossibleTimeSave => "Possible Time Save Graph",
+            GraphMode::History => "History Graph",
+        };
         if let Some(comparison) = comparison {
-            format!("Graph ({})", comparison::shorten(comparison)).into()
+            format!("{} ({})", name, comparison::shorten(comparison)).into()
         } else {
-            "Graph".into()
+            name.into()
         }
     }
 
@@ -201,16 +267,32 @@ impl Component {
         let comparison = comparison::resolve(&self.settings.comparison_override, timer);
         let comparison = comparison::or_current(comparison, timer);
 
-        let mut draw_info = DrawInfo {
-            deltas: Vec::with_capacity(timer.run().len() + 1),
-            ..Default::default()
-        };
+        let mut state = match self.settings.mode {
+            GraphMode::Delta => {
+                let mut draw_info = DrawInfo {
+                    deltas: Vec::with_capacity(timer.timed_run().len() + 1),
+                    ..Default::default()
+                };
 
-        self.calculate_final_split(timer, &mut draw_info);
-        self.calculate_deltas(timer, comparison, &mut draw_info);
-        self.check_live_segment_delta(timer, comparison, &mut draw_info);
+                self.calculate_final_split(timer, &mut draw_info);
+                self.calculate_deltas(timer, comparison, &mut draw_info);
+                self.check_live_segment_delta(timer, comparison, &mut draw_info);
 
-        let mut state = self.calculate_points(timer, &draw_info, layout_settings);
+                self.calculate_points(timer, &draw_info, layout_settings)
+            }
+            GraphMode::SegmentDelta => {
+                let series = self.calculate_segment_deltas(timer, comparison);
+                self.calculate_series_points(&series, true, layout_settings)
+            }
+            GraphMode::PossibleTimeSave => {
+                let series = self.calculate_possible_time_saves(timer, comparison);
+                self.calculate_series_points(&series, false, layout_settings)
+            }
+            GraphMode::History => {
+                let series = self.calculate_history(timer, comparison);
+                self.calculate_series_points(&series, false, layout_settings)
+            }
+        };
 
         self.make_uniform(&mut state);
         self.flip(&mut state);
@@ -257,6 +339,7 @@ impl Component {
                 "Complete Fill Color".into(),
                 self.settings.complete_fill_color.into(),
             ),
+            Field::new("Mode".into(), self.settings.mode.into()),
         ])
     }
 
@@ -280,6 +363,7 @@ impl Component {
             8 => self.settings.graph_lines_color = value.into(),
             9 => self.settings.partial_fill_color = value.into(),
             10 => self.settings.complete_fill_color = value.into(),
+            11 => self.settings.mode = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -334,6 +418,25 @@ impl Component {
 
         let points = self.make_points_list(draw_info, timer, total_delta, graph_edge, graph_height);
 
+        self.make_state(
+            points,
+            horizontal_grid_lines,
+            vertical_grid_lines,
+            middle,
+            draw_info.is_live_delta_active,
+            layout_settings,
+        )
+    }
+
+    fn make_state(
+        &self,
+        points: Vec<Point>,
+        horizontal_grid_lines: Vec<f32>,
+        vertical_grid_lines: Vec<f32>,
+        middle: f32,
+        is_live_delta_active: bool,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> State {
         let (top_background_color, bottom_background_color) = if self.settings.flip_graph {
             (
                 self.settings.ahead_background_color,
@@ -351,7 +454,7 @@ impl Component {
             horizontal_grid_lines,
             vertical_grid_lines,
             middle,
-            is_live_delta_active: draw_info.is_live_delta_active,
+            is_live_delta_active,
             is_flipped: self.settings.flip_graph,
             top_background_color,
             bottom_background_color,
@@ -361,6 +464,7 @@ impl Component {
             complete_fill_color: self.settings.complete_fill_color,
             best_segment_color: layout_settings.best_segment_color,
             height: self.settings.height,
+            is_bar_graph: self.settings.mode == GraphMode::SegmentDelta,
         }
     }
 
@@ -432,7 +536,7 @@ impl Component {
 
     fn check_best_segment(&self, timer: &Timer, split_number: usize) -> bool {
         self.settings.show_best_segments
-            && split_number < timer.run().len()
+            && split_number < timer.timed_run().len()
             && analysis::check_best_segment(timer, split_number, timer.current_timing_method())
     }
 
@@ -461,7 +565,7 @@ impl Component {
 
         if y + 1 != draw_info.deltas.len() {
             if let Some(split_time) =
-                timer.run().segment(y).split_time()[timer.current_timing_method()]
+                timer.timed_run().segment(y).split_time()[timer.current_timing_method()]
             {
                 *width_one = (split_time.total_milliseconds() as f32
                     / draw_info.final_split.total_milliseconds() as f32)
@@ -485,7 +589,7 @@ impl Component {
         if y + 1 == draw_info.deltas.len() && draw_info.is_live_delta_active {
             *width_two = WIDTH;
         } else if let Some(split_time) =
-            timer.run().segment(y).split_time()[timer.current_timing_method()]
+            timer.timed_run().segment(y).split_time()[timer.current_timing_method()]
         {
             *width_two = (split_time.total_milliseconds() as f32
                 / draw_info.final_split.total_milliseconds() as f32)
@@ -550,34 +654,47 @@ impl Component {
         graph_height: f32,
         draw_info: &DrawInfo,
     ) -> (f32, f32) {
-        let (mut grid_value_x, mut grid_value_y);
+        if timer.current_phase() != TimerPhase::NotRunning {
+            (
+                self.calculate_grid_value_x(draw_info.final_split),
+                self.calculate_grid_value_y(total_delta, graph_edge, graph_height),
+            )
+        } else {
+            (-1.0, -1.0)
+        }
+    }
 
-        let current_phase = timer.current_phase();
-        if current_phase != TimerPhase::NotRunning && draw_info.final_split > TimeSpan::zero() {
-            grid_value_x = 1000.0;
-            while draw_info.final_split.total_milliseconds() as f32 / grid_value_x > WIDTH / 20.0 {
+    fn calculate_grid_value_x(&self, final_split: TimeSpan) -> f32 {
+        if final_split > TimeSpan::zero() {
+            let mut grid_value_x = 1000.0;
+            while final_split.total_milliseconds() as f32 / grid_value_x > WIDTH / 20.0 {
                 grid_value_x *= 6.0;
             }
-            grid_value_x =
-                (grid_value_x / draw_info.final_split.total_milliseconds() as f32) * WIDTH;
+            (grid_value_x / final_split.total_milliseconds() as f32) * WIDTH
         } else {
-            grid_value_x = -1.0;
+            -1.0
         }
-        if current_phase != TimerPhase::NotRunning && total_delta < TimeSpan::zero() {
-            grid_value_y = 1000.0;
+    }
+
+    fn calculate_grid_value_y(
+        &self,
+        total_delta: TimeSpan,
+        graph_edge: f32,
+        graph_height: f32,
+    ) -> f32 {
+        if total_delta < TimeSpan::zero() {
+            let mut grid_value_y = 1000.0;
             while (-total_delta.total_milliseconds() as f32) / grid_value_y
                 > (graph_height - graph_edge) * 2.0 / 20.0
             {
                 grid_value_y *= 6.0;
             }
-            grid_value_y = (grid_value_y / (-total_delta.total_milliseconds() as f32))
+            (grid_value_y / (-total_delta.total_milliseconds() as f32))
                 * (graph_height - graph_edge)
-                * 2.0;
+                * 2.0
         } else {
-            grid_value_y = -1.0;
+            -1.0
         }
-
-        (grid_value_x, grid_value_y)
     }
 
     fn calculate_middle_and_graph_edge(
@@ -613,7 +730,7 @@ impl Component {
                     .unwrap_or_else(TimeSpan::zero);
             } else {
                 let timing_method = timer.current_timing_method();
-                for segment in timer.run().segments()[..timer.current_split_index().unwrap()]
+                for segment in timer.timed_run().segments()[..timer.current_split_index().unwrap()]
                     .iter()
                     .rev()
                 {
@@ -628,7 +745,7 @@ impl Component {
 
     fn calculate_deltas(&self, timer: &Timer, comparison: &str, draw_info: &mut DrawInfo) {
         let timing_method = timer.current_timing_method();
-        for segment in timer.run().segments() {
+        for segment in timer.timed_run().segments() {
             let time = catch! {
                 let time = segment.split_time()[timing_method]?
                     - segment.comparison(comparison)[timing_method]?;
@@ -677,4 +794,195 @@ impl Component {
             }
         }
     }
+
+    fn calculate_series_points(
+        &self,
+        series: &SeriesInfo,
+        is_bar_graph: bool,
+        layout_settings: &GeneralLayoutSettings,
+    ) -> State {
+        let draw_info = DrawInfo {
+            max_delta: series.max_value,
+            min_delta: series.min_value,
+            ..Default::default()
+        };
+        let total_delta = draw_info.min_delta - draw_info.max_delta;
+
+        let (graph_edge, graph_height, middle) =
+            self.calculate_middle_and_graph_edge(total_delta, &draw_info);
+
+        let grid_value_y = self.calculate_grid_value_y(total_delta, graph_edge, graph_height);
+        let (horizontal_grid_lines, _) =
+            self.make_grid_lines_list(graph_height, middle, -1.0, grid_value_y);
+
+        let calculate_y = |value: TimeSpan| {
+            if total_delta != TimeSpan::zero() {
+                (value.total_milliseconds() as f32
+                    - draw_info.max_delta.total_milliseconds() as f32)
+                    / total_delta.total_milliseconds() as f32
+                    * (graph_height - graph_edge)
+                    * 2.0
+                    + graph_edge
+            } else {
+                graph_height
+            }
+        };
+
+        let mut points = Vec::with_capacity(series.values.len() + 1);
+        if !is_bar_graph && !series.values.is_empty() {
+            points.push(Point {
+                x: 0.0,
+                y: calculate_y(TimeSpan::zero()),
+                is_best_segment: false,
+            });
+        }
+        points.extend(
+            series
+                .values
+                .iter()
+                .map(|&(x, value, is_best_segment)| Point {
+                    x,
+                    y: calculate_y(value),
+                    is_best_segment,
+                }),
+        );
+
+        self.make_state(
+            points,
+            horizontal_grid_lines,
+            series.vertical_grid_lines.clone(),
+            middle,
+            series.is_live_value_active,
+            layout_settings,
+        )
+    }
+
+    fn calculate_segment_deltas(&self, timer: &Timer, comparison: &str) -> SeriesInfo {
+        let mut series = SeriesInfo::default();
+        let run = timer.timed_run();
+        if run.is_empty() {
+            return series;
+        }
+
+        let timing_method = timer.current_timing_method();
+        let bar_width = WIDTH / run.len() as f32;
+        series
+            .vertical_grid_lines
+            .extend((1..run.len()).map(|i| i as f32 * bar_width));
+
+        let current_split_index = timer.current_split_index().unwrap_or(0);
+        for index in 0..current_split_index.min(run.len()) {
+            let delta = analysis::previous_segment_delta(timer, index, comparison, timing_method)
+                .unwrap_or_default();
+            let is_best_segment = self.check_best_segment(timer, index);
+            let left = index as f32 * bar_width;
+            series.push(left, delta, is_best_segment);
+            series.push(left + bar_width, delta, is_best_segment);
+        }
+
+        let current_phase = timer.current_phase();
+        if self.settings.live_graph
+            && (current_phase == TimerPhase::Running || current_phase == TimerPhase::Paused)
+        {
+            // Similar to the deltas, the live segment delta is only shown once
+            // time is being lost on the segment.
+            if let Some(delta) =
+                analysis::live_segment_delta(timer, current_split_index, comparison, timing_method)
+            {
+                if delta > TimeSpan::zero() {
+                    let left = current_split_index as f32 * bar_width;
+                    series.push(left, delta, false);
+                    series.push(left + bar_width, delta, false);
+                    series.is_live_value_active = true;
+                }
+            }
+        }
+
+        series
+    }
+
+    fn calculate_possible_time_saves(&self, timer: &Timer, comparison: &str) -> SeriesInfo {
+        let mut series = SeriesInfo::default();
+        let timing_method = timer.current_timing_method();
+        let segments = timer.timed_run().segments();
+
+        let final_time = match segments
+            .last()
+            .and_then(|s| s.comparison(comparison)[timing_method])
+        {
+            Some(final_time) if final_time > TimeSpan::zero() => final_time,
+            _ => return series,
+        };
+
+        let grid_value_x = self.calculate_grid_value_x(final_time);
+        let mut x = grid_value_x;
+        while x < WIDTH {
+            series.vertical_grid_lines.push(x);
+            x += grid_value_x;
+        }
+
+        let mut total_time_save = TimeSpan::zero();
+        for (index, segment) in segments.iter().enumerate() {
+            // Segments without a comparison time don't have a possible time
+            // save of their own. It gets accounted for by the next segment
+            // that has a comparison time instead.
+            if let Some(split_time) = segment.comparison(comparison)[timing_method] {
+                if let Some(time_save) = possible_time_save::calculate(
+                    timer,
+                    index,
+                    comparison,
+                    self.settings.live_graph,
+                ) {
+                    total_time_save += time_save;
+                }
+                let x = split_time.total_milliseconds() as f32
+                    / final_time.total_milliseconds() as f32
+                    * WIDTH;
+                series.push(x, total_time_save, false);
+            }
+        }
+
+        series
+    }
+
+    fn calculate_history(&self, timer: &Timer, comparison: &str) -> SeriesInfo {
+        let mut series = SeriesInfo::default();
+        let run = timer.timed_run();
+        let timing_method = timer.current_timing_method();
+
+        let final_times = run
+            .attempt_history()
+            .iter()
+            .filter_map(|attempt| attempt.time()[timing_method])
+            .collect::<Vec<_>>();
+
+        // The times are shown relative to the final time of the comparison.
+        // If there is none, the best final time is used instead.
+        let reference = run
+            .segments()
+            .last()
+            .and_then(|s| s.comparison(comparison)[timing_method])
+            .or_else(|| final_times.iter().cloned().min());
+
+        let reference = match reference {
+            Some(reference) => reference,
+            None => return series,
+        };
+
+        let mut best_time = None;
+        for (index, &time) in final_times.iter().enumerate() {
+            let is_best_time = best_time.map_or(true, |best| time < best);
+            if is_best_time {
+                best_time = Some(time);
+            }
+            let x = (index + 1) as f32 / final_times.len() as f32 * WIDTH;
+            series.push(
+                x,
+                time - reference,
+                self.settings.show_best_segments && is_best_time,
+            );
+        }
+
+        series
+    }
 }
@@ -0,0 +1,105 @@
+use super::{Component, GraphMode, Settings};
+use crate::tests_helper::{create_timer, run_with_splits, span, start_run};
+use crate::GeneralLayoutSettings;
+
+fn component(mode: GraphMode) -> Component {
+    Component::with_settings(Settings {
+        mode,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn segment_delta_mode_shows_a_bar_per_completed_segment() {
+    let mut timer = create_timer(&["A", "B", "C", "D"]);
+    run_with_splits(&mut timer, &[10.0, 20.0, 30.0, 40.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(8.0));
+    timer.split();
+    timer.set_game_time(span(21.0));
+    timer.split();
+
+    let component = component(GraphMode::SegmentDelta);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    assert!(state.is_bar_graph);
+    assert_eq!(state.points.len(), 4);
+    assert_eq!(state.vertical_grid_lines, [0.25, 0.5, 0.75]);
+
+    let xs = state.points.iter().map(|p| p.x).collect::<Vec<_>>();
+    assert_eq!(xs, [0.0, 0.25, 0.25, 0.5]);
+
+    // The first segment was faster than the comparison, the second one was
+    // slower.
+    assert!(state.points[0].y > state.middle);
+    assert_eq!(state.points[0].y, state.points[1].y);
+    assert!(state.points[2].y < state.middle);
+    assert!(!state.is_live_delta_active);
+
+    timer.set_game_time(span(35.0));
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+    assert_eq!(state.points.len(), 6);
+    assert!(state.is_live_delta_active);
+}
+
+#[test]
+fn possible_time_save_mode_accumulates_the_time_save() {
+    let mut timer = create_timer(&["A", "B", "C"]);
+    run_with_splits(&mut timer, &[10.0, 18.0, 30.0]);
+    run_with_splits(&mut timer, &[12.0, 22.0, 28.0]);
+
+    let component = component(GraphMode::PossibleTimeSave);
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    assert!(!state.is_bar_graph);
+    assert_eq!(state.points.len(), 4);
+    assert_eq!(state.points[3].x, 1.0);
+
+    // The Personal Best can be improved by 2 seconds on the first and on the
+    // second segment, so the time save only grows until the last split.
+    assert_eq!(state.points[0].y, state.middle);
+    assert!(state.points[1].y < state.points[0].y);
+    assert!(state.points[2].y < state.points[1].y);
+    assert_eq!(state.points[3].y, state.points[2].y);
+}
+
+#[test]
+fn history_mode_shows_all_finished_attempts() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[12.0, 25.0]);
+    run_with_splits(&mut timer, &[10.0, 27.0]);
+
+    let component = Component::with_settings(Settings {
+        mode: GraphMode::History,
+        show_best_segments: true,
+        ..Default::default()
+    });
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    assert_eq!(state.points.len(), 4);
+    let xs = state.points.iter().map(|p| p.x).collect::<Vec<_>>();
+    assert_eq!(xs, [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+
+    let bests = state
+        .points
+        .iter()
+        .map(|p| p.is_best_segment)
+        .collect::<Vec<_>>();
+    assert_eq!(bests, [false, true, true, false]);
+
+    // The times are relative to the Personal Best, which is the second
+    // finished attempt.
+    assert!(state.points[1].y < state.middle);
+    assert_eq!(state.points[2].y, state.middle);
+    assert!(state.points[3].y < state.middle);
+}
+
+#[test]
+fn history_mode_without_attempts_is_empty() {
+    let timer = create_timer(&["A"]);
+    let state = component(GraphMode::History).state(&timer, &GeneralLayoutSettings::default());
+    assert!(state.points.is_empty());
+}
@@ -0,0 +1,198 @@
+//! Provides the Image Component and relevant types for using it. The Image
+//! Component is a component that shows an arbitrary image, like a logo, a
+//! route map or a sponsor.
+
+use crate::settings::{
+    Alignment, CachedImageId, Field, Gradient, Image, ImageData, SettingsDescription, Value,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The maximum width and height of the images shown by the Image Component.
+/// Larger images get shrunk when they are set as the image of the component.
+pub const MAX_IMAGE_SIZE: u32 = 1024;
+
+/// The Image Component is a component that shows an arbitrary image, like a
+/// logo, a route map or a sponsor.
+#[derive(Default, Clone)]
+pub struct Component {
+    image_id: CachedImageId,
+    settings: Settings,
+}
+
+/// Describes how the image is scaled to the size of the component.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImageScaling {
+    /// The image is scaled to fit into the component, while preserving its
+    /// aspect ratio.
+    Fit,
+    /// The image is stretched to fill the whole component, ignoring its aspect
+    /// ratio.
+    Stretch,
+}
+
+impl Default for ImageScaling {
+    fn default() -> Self {
+        ImageScaling::Fit
+    }
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The image to show.
+    pub image: Image,
+    /// Specifies how the image is scaled to the size of the component.
+    pub scaling: ImageScaling,
+    /// Specifies the horizontal alignment of the image, if it doesn't fill the
+    /// whole width of the component.
+    pub alignment: Alignment,
+    /// The height of the component.
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: Gradient::Transparent,
+            image: Image::default(),
+            scaling: ImageScaling::default(),
+            alignment: Alignment::Auto,
+            height: 80,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The image encoded as the raw file bytes. This value is only specified
+    /// whenever the image changes. If you explicitly want to query this value,
+    /// remount the component. The buffer itself may be empty. This indicates
+    /// that there is no image.
+    pub image_change: Option<ImageData>,
+    /// The unique ID of the image. Since the image itself is only provided
+    /// whenever it changes, this can be used to associate a cached version of
+    /// the image with the component, even if the components of the layout get
+    /// reordered.
+    pub image_id: usize,
+    /// Specifies how the image is scaled to the size of the component.
+    pub scaling: ImageScaling,
+    /// Specifies whether the image should be centered or aligned to the left
+    /// instead, if it doesn't fill the whole width of the component.
+    pub is_centered: bool,
+    /// The height of the component.
+    pub height: u32,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Image Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Image Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Image".into()
+    }
+
+    /// Sets the image to show. The image is shrunk if its width or height
+    /// exceed the maximum image size.
+    pub fn set_image(&mut self, data: &[u8]) {
+        self.settings.image = Image::with_max_size(data, MAX_IMAGE_SIZE);
+    }
+
+    /// Calculates the component's state.
+    pub fn state(&mut self) -> State {
+        let image_change = self
+            .image_id
+            .update_with(Some(&self.settings.image))
+            .map(Into::into);
+
+        State {
+            background: self.settings.background,
+            image_change,
+            image_id: self.settings.image.id(),
+            scaling: self.settings.scaling,
+            is_centered: self.settings.alignment != Alignment::Left,
+            height: self.settings.height,
+        }
+    }
+
+    /// Remounts the component as if it was freshly initialized. The image
+    /// shown by this component is only provided in the state objects whenever
+    /// the image changes or whenever the component's state is first queried.
+    /// Remounting returns the image again, whenever its state is queried the
+    /// next time.
+    pub fn remount(&mut self) {
+        self.image_id.reset();
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new("Image".into(), self.settings.image.clone().into()),
+            Field::new("Scaling".into(), self.settings.scaling.into()),
+            Field::new("Alignment".into(), self.settings.alignment.into()),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.image = value.into(),
+            2 => self.settings.scaling = value.into(),
+            3 => self.settings.alignment = value.into(),
+            4 => self.settings.height = value.into_uint().unwrap() as _,
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use super::{Component, ImageScaling, Settings};
+use crate::settings::{Alignment, Image};
+
+#[test]
+fn only_provides_the_image_when_it_changes() {
+    let mut component = Component::with_settings(Settings {
+        image: Image::from([1, 2, 3]),
+        ..Default::default()
+    });
+
+    let state = component.state();
+    assert_eq!(&*state.image_change.unwrap(), [1, 2, 3]);
+    assert_eq!(state.image_id, component.settings().image.id());
+    assert!(component.state().image_change.is_none());
+
+    component.remount();
+    assert!(component.state().image_change.is_some());
+    assert!(component.state().image_change.is_none());
+
+    component.settings_mut().image = Image::from([4, 5]);
+    let state = component.state();
+    assert_eq!(&*state.image_change.unwrap(), [4, 5]);
+    assert_eq!(state.image_id, component.settings().image.id());
+}
+
+#[test]
+fn provides_the_scaling_and_alignment() {
+    let mut component = Component::new();
+    let state = component.state();
+    assert_eq!(state.scaling, ImageScaling::Fit);
+    assert!(state.is_centered);
+
+    component.settings_mut().scaling = ImageScaling::Stretch;
+    component.settings_mut().alignment = Alignment::Left;
+    let state = component.state();
+    assert_eq!(state.scaling, ImageScaling::Stretch);
+    assert!(!state.is_centered);
+}
@@ -4,14 +4,24 @@
 //! visualized by any kind of User Interface.
 
 pub mod blank_space;
+pub mod clock;
+pub mod comparison_table;
+pub mod counter;
 pub mod current_comparison;
 pub mod current_pace;
 pub mod delta;
 pub mod detailed_timer;
+pub mod finish_time;
 pub mod graph;
+pub mod image;
 pub mod pb_chance;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod run_history;
+pub mod run_metadata;
+pub mod segment_potential;
+pub mod segment_time;
+pub mod segment_trend;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
@@ -21,14 +31,24 @@ pub mod title;
 pub mod total_playtime;
 
 pub use blank_space::Component as BlankSpace;
+pub use clock::Component as Clock;
+pub use comparison_table::Component as ComparisonTable;
+pub use counter::Component as Counter;
 pub use current_comparison::Component as CurrentComparison;
 pub use current_pace::Component as CurrentPace;
 pub use delta::Component as Delta;
 pub use detailed_timer::Component as DetailedTimer;
+pub use finish_time::Component as FinishTime;
 pub use graph::Component as Graph;
+pub use image::Component as Image;
 pub use pb_chance::Component as PbChance;
 pub use possible_time_save::Component as PossibleTimeSave;
 pub use previous_segment::Component as PreviousSegment;
+pub use run_history::Component as RunHistory;
+pub use run_metadata::Component as RunMetadata;
+pub use segment_potential::Component as SegmentPotential;
+pub use segment_time::Component as SegmentTime;
+pub use segment_trend::Component as SegmentTrend;
 pub use separator::Component as Separator;
 pub use splits::Component as Splits;
 pub use sum_of_best::Component as SumOfBest;
@@ -0,0 +1,289 @@
+//! Provides the Run History Component and relevant types for using it. The Run
+//! History Component visualizes the final times of the most recent finished
+//! attempts as a sparkline. The Personal Best and the time the current attempt
+//! is projected to finish with are marked as well, which gives a quick overview
+//! of how consistent the recent attempts have been.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::analysis::current_pace;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::{comparison, GeneralLayoutSettings, TimeSpan, Timer, TimerPhase};
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Run History Component visualizes the final times of the most recent
+/// finished attempts as a sparkline. The Personal Best and the time the
+/// current attempt is projected to finish with are marked as well, which gives
+/// a quick overview of how consistent the recent attempts have been.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen for projecting the final time of the current
+    /// attempt. Uses the Timer's current comparison if set to `None`.
+    pub comparison_override: Option<String>,
+    /// The amount of finished attempts to show.
+    pub attempt_count: u32,
+    /// Specifies whether the time the current attempt is projected to finish
+    /// with is shown.
+    pub show_projected_time: bool,
+    /// The color of the sparkline.
+    pub line_color: Color,
+    /// The color of the Personal Best marker. If `None` is specified, the color
+    /// is taken from the layout.
+    pub personal_best_color: Option<Color>,
+    /// The color of the projected time of the current attempt.
+    pub projected_color: Color,
+    /// The height of the component.
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            comparison_override: None,
+            attempt_count: 10,
+            show_projected_time: true,
+            line_color: (1.0, 1.0, 1.0, 1.0).into(),
+            personal_best_color: None,
+            projected_color: (1.0, 1.0, 1.0, 0.5).into(),
+            height: 60,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+/// All the coordinates are in the range 0..1.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The points of the sparkline, one for each finished attempt shown, from
+    /// the oldest to the most recent attempt. Connect all of them to visualize
+    /// the sparkline. Longer final times are further up.
+    pub points: Vec<Point>,
+    /// The y coordinate of the Personal Best's final time. This is meant to be
+    /// visualized as a horizontal marker line. If there is no Personal Best,
+    /// this is `None`.
+    pub personal_best: Option<f32>,
+    /// The point describing the time the current attempt is projected to
+    /// finish with. This is meant to be connected to the last point of the
+    /// sparkline. If there is no active attempt or the projected time is not
+    /// supposed to be shown, this is `None`.
+    pub projected: Option<Point>,
+    /// The color of the sparkline.
+    pub line_color: Color,
+    /// The color of the Personal Best marker and of the points of attempts
+    /// that finished with the Personal Best's final time.
+    pub personal_best_color: Color,
+    /// The color of the projected time of the current attempt.
+    pub projected_color: Color,
+    /// The height of the component.
+    pub height: u32,
+}
+
+/// Describes a point of the sparkline to visualize.
+#[derive(Serialize, Deserialize)]
+pub struct Point {
+    /// The x coordinate of the point.
+    pub x: f32,
+    /// The y coordinate of the point.
+    pub y: f32,
+    /// Describes whether the attempt this point is visualizing finished with
+    /// the Personal Best's final time. Use the Personal Best color for it, in
+    /// that case.
+    pub is_personal_best: bool,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Run History Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Run History Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        if let Some(comparison) = &self.settings.comparison_override {
+            format!("Run History ({})", comparison::shorten(comparison)).into()
+        } else {
+            "Run History".into()
+        }
+    }
+
+    /// Calculates the component's state based on the timer and layout settings
+    /// provided.
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let run = timer.timed_run();
+        let timing_method = timer.current_timing_method();
+
+        let final_times = run
+            .attempt_history()
+            .iter()
+            .filter_map(|attempt| attempt.time()[timing_method])
+            .collect::<Vec<_>>();
+        let final_times = &final_times[final_times
+            .len()
+            .saturating_sub(self.settings.attempt_count as usize)..];
+
+        let personal_best = run
+            .segments()
+            .last()
+            .and_then(|s| s.personal_best_split_time()[timing_method]);
+
+        let projected = if self.settings.show_projected_time
+            && timer.current_phase() != TimerPhase::NotRunning
+        {
+            let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+            let comparison = comparison::or_current(comparison, timer);
+            current_pace::calculate(timer, comparison)
+        } else {
+            None
+        };
+
+        let (min, max) = final_times
+            .iter()
+            .chain(personal_best.iter())
+            .chain(projected.iter())
+            .fold(None, |range: Option<(TimeSpan, TimeSpan)>, &time| {
+                Some(range.map_or((time, time), |(min, max)| (min.min(time), max.max(time))))
+            })
+            .unwrap_or_default();
+
+        let calculate_y = |time: TimeSpan| {
+            if max > min {
+                1.0 - ((time - min).total_milliseconds() / (max - min).total_milliseconds()) as f32
+            } else {
+                0.5
+            }
+        };
+
+        // The projected time takes up the slot after the most recent attempt.
+        let slots = final_times.len() + projected.is_some() as usize;
+        let calculate_x = |index: usize| {
+            if slots > 1 {
+                index as f32 / (slots - 1) as f32
+            } else {
+                0.5
+            }
+        };
+
+        let points = final_times
+            .iter()
+            .enumerate()
+            .map(|(index, &time)| Point {
+                x: calculate_x(index),
+                y: calculate_y(time),
+                is_personal_best: Some(time) == personal_best,
+            })
+            .collect();
+
+        let projected = projected.map(|time| Point {
+            x: calculate_x(final_times.len()),
+            y: calculate_y(time),
+            is_personal_best: false,
+        });
+
+        State {
+            background: self.settings.background,
+            points,
+            personal_best: personal_best.map(calculate_y),
+            projected,
+            line_color: self.settings.line_color,
+            personal_best_color: self
+                .settings
+                .personal_best_color
+                .unwrap_or(layout_settings.personal_best_color),
+            projected_color: self.settings.projected_color,
+            height: self.settings.height,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Attempts".into(),
+                u64::from(self.settings.attempt_count).into(),
+            ),
+            Field::new(
+                "Show Projected Time".into(),
+                self.settings.show_projected_time.into(),
+            ),
+            Field::new("Line Color".into(), self.settings.line_color.into()),
+            Field::new(
+                "Personal Best Color".into(),
+                self.settings.personal_best_color.into(),
+            ),
+            Field::new(
+                "Projected Time Color".into(),
+                self.settings.projected_color.into(),
+            ),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.attempt_count = value.into_uint().unwrap() as _,
+            3 => self.settings.show_projected_time = value.into(),
+            4 => self.settings.line_color = value.into(),
+            5 => self.settings.personal_best_color = value.into(),
+            6 => self.settings.projected_color = value.into(),
+            7 => self.settings.height = value.into_uint().unwrap() as _,
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use super::{Component, Settings};
+use crate::tests_helper::{create_timer, run_with_splits, span, start_run};
+use crate::GeneralLayoutSettings;
+
+#[test]
+fn shows_the_most_recent_finished_attempts() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 40.0]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[10.0]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+
+    let component = Component::with_settings(Settings {
+        attempt_count: 3,
+        ..Default::default()
+    });
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+
+    let coordinates = state
+        .points
+        .iter()
+        .map(|p| (p.x, p.y, p.is_personal_best))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        coordinates,
+        [(0.0, 1.0, true), (0.5, 0.0, false), (1.0, 0.5, false)]
+    );
+    assert_eq!(state.personal_best, Some(1.0));
+    assert!(state.projected.is_none());
+}
+
+#[test]
+fn shows_the_projected_time_of_the_current_attempt() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 20.0]);
+    run_with_splits(&mut timer, &[10.0, 30.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(15.0));
+    timer.split();
+
+    let state = Component::new().state(&timer, &GeneralLayoutSettings::default());
+    assert_eq!(state.points.len(), 2);
+
+    // The attempt is 5 seconds behind the Personal Best, so it's projected to
+    // finish with a time of 25 seconds.
+    let projected = state.projected.unwrap();
+    assert_eq!(projected.x, 1.0);
+    assert_eq!(projected.y, 0.5);
+    assert_eq!(state.points[1].x, 0.5);
+}
+
+#[test]
+fn is_empty_without_any_attempts() {
+    let timer = create_timer(&["A"]);
+    let state = Component::new().state(&timer, &GeneralLayoutSettings::default());
+    assert!(state.points.is_empty());
+    assert!(state.personal_best.is_none());
+    assert!(state.projected.is_none());
+}
@@ -0,0 +1,256 @@
+//! Provides the Run Metadata Component and relevant types for using it. The Run
+//! Metadata Component shows the information stored in the run's metadata, like
+//! the platform and region of the game, whether an emulator is used and the
+//! values of the speedrun.com variables chosen, along with the runtime values
+//! provided to the timer, each in its own row.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::Timer;
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+const SETTINGS_BEFORE_VARIABLES: usize = 9;
+
+/// The Run Metadata Component shows the information stored in the run's
+/// metadata, like the platform and region of the game, whether an emulator is
+/// used and the values of the speedrun.com variables chosen, along with the
+/// runtime values provided to the timer, each in its own row.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// Specifies whether to display the label of each row and its value in two
+    /// separate rows.
+    pub display_two_rows: bool,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// Specifies whether the platform the game is run on is shown.
+    pub show_platform: bool,
+    /// Specifies whether the region of the game is shown.
+    pub show_region: bool,
+    /// Specifies whether it is shown if the game is run on an emulator.
+    pub show_emulator: bool,
+    /// Specifies whether the runtime values provided to the timer, like the
+    /// ones of an auto splitter, are shown after the speedrun.com variables.
+    pub show_runtime_values: bool,
+    /// The names of the speedrun.com variables to show, in the order they are
+    /// supposed to be shown in.
+    pub variables: Vec<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            show_platform: true,
+            show_region: true,
+            show_emulator: false,
+            show_runtime_values: true,
+            variables: Vec::new(),
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the labels. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the values. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The rows to show, one for each of the values that are enabled and
+    /// specified by the run's metadata, followed by the runtime values.
+    pub rows: Vec<RowState>,
+    /// Specifies whether to display the label of each row and its value in two
+    /// separate rows.
+    pub display_two_rows: bool,
+}
+
+/// The state object of a single row of the component.
+#[derive(Serialize, Deserialize)]
+pub struct RowState {
+    /// The label of the row.
+    pub label: String,
+    /// The value of the row.
+    pub value: String,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Run Metadata Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Run Metadata Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Run Metadata".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        let metadata = timer.run().metadata();
+
+        let mut rows = Vec::new();
+        let mut push_row = |label: &str, value: &str| {
+            // Values that aren't specified by the run's metadata are left out.
+            if !value.is_empty() {
+                rows.push(RowState {
+                    label: label.to_owned(),
+                    value: value.to_owned(),
+                });
+            }
+        };
+
+        if self.settings.show_platform {
+            push_row("Platform", metadata.platform_name());
+        }
+        if self.settings.show_region {
+            push_row("Region", metadata.region_name());
+        }
+        if self.settings.show_emulator {
+            push_row(
+                "Emulator",
+                if metadata.uses_emulator() {
+                    "Yes"
+                } else {
+                    "No"
+                },
+            );
+        }
+        for name in &self.settings.variables {
+            if let Some(value) = metadata.variables.get(name) {
+                push_row(name, value);
+            }
+        }
+        if self.settings.show_runtime_values {
+            for (name, value) in timer.runtime_values() {
+                push_row(name, value);
+            }
+        }
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            rows,
+            display_two_rows: self.settings.display_two_rows,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        let mut settings = SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Show Platform".into(), self.settings.show_platform.into()),
+            Field::new("Show Region".into(), self.settings.show_region.into()),
+            Field::new("Show Emulator".into(), self.settings.show_emulator.into()),
+            Field::new(
+                "Show Runtime Values".into(),
+                self.settings.show_runtime_values.into(),
+            ),
+            Field::new(
+                "Variables".into(),
+                Value::UInt(self.settings.variables.len() as _),
+            ),
+        ]);
+
+        for name in &self.settings.variables {
+            settings
+                .fields
+                .push(Field::new("Variable Name".into(), name.clone().into()));
+        }
+
+        settings
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.display_two_rows = value.into(),
+            2 => self.settings.label_color = value.into(),
+            3 => self.settings.value_color = value.into(),
+            4 => self.settings.show_platform = value.into(),
+            5 => self.settings.show_region = value.into(),
+            6 => self.settings.show_emulator = value.into(),
+            7 => self.settings.show_runtime_values = value.into(),
+            8 => {
+                let new_len = value.into_uint().unwrap() as usize;
+                self.settings.variables.resize(new_len, String::new());
+            }
+            index => {
+                if let Some(name) = self
+                    .settings
+                    .variables
+                    .get_mut(index - SETTINGS_BEFORE_VARIABLES)
+                {
+                    *name = value.into();
+                } else {
+                    panic!("Unsupported Setting Index")
+                }
+            }
+        }
+    }
+}
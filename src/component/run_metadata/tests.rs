@@ -0,0 +1,109 @@
+use super::{Component, Settings};
+use crate::settings::Value;
+use crate::tests_helper::create_run;
+use crate::Timer;
+
+fn rows(component: &Component, timer: &Timer) -> Vec<(String, String)> {
+    component
+        .state(timer)
+        .rows
+        .into_iter()
+        .map(|row| (row.label, row.value))
+        .collect()
+}
+
+#[test]
+fn shows_the_metadata_enabled() {
+    let mut run = create_run(&["A"]);
+    run.metadata_mut().set_platform_name("GameCube");
+    run.metadata_mut().set_emulator_usage(true);
+    let timer = Timer::new(run).unwrap();
+
+    let mut component = Component::new();
+    // The region isn't specified, so it's left out.
+    assert_eq!(
+        rows(&component, &timer),
+        [(String::from("Platform"), String::from("GameCube"))]
+    );
+
+    component.settings_mut().show_platform = false;
+    component.settings_mut().show_emulator = true;
+    assert_eq!(
+        rows(&component, &timer),
+        [(String::from("Emulator"), String::from("Yes"))]
+    );
+}
+
+#[test]
+fn shows_the_variables_chosen_in_order() {
+    let mut run = create_run(&["A"]);
+    run.metadata_mut().set_variable("Amiibos", "No Amiibos");
+    run.metadata_mut().set_variable("Difficulty", "Hard");
+    let timer = Timer::new(run).unwrap();
+
+    let component = Component::with_settings(Settings {
+        variables: vec![
+            String::from("Difficulty"),
+            String::from("Version"),
+            String::from("Amiibos"),
+        ],
+        ..Default::default()
+    });
+    assert_eq!(
+        rows(&component, &timer),
+        [
+            (String::from("Difficulty"), String::from("Hard")),
+            (String::from("Amiibos"), String::from("No Amiibos")),
+        ]
+    );
+}
+
+#[test]
+fn variables_can_be_chosen_through_the_settings() {
+    let mut component = Component::new();
+    assert_eq!(component.settings_description().fields.len(), 9);
+
+    component.set_value(8, Value::UInt(2));
+    component.set_value(10, String::from("Difficulty").into());
+    assert_eq!(component.settings().variables, ["", "Difficulty"]);
+    assert_eq!(component.settings_description().fields.len(), 11);
+
+    component.set_value(8, Value::UInt(1));
+    assert_eq!(component.settings().variables, [""]);
+}
+
+#[test]
+fn shows_the_runtime_values_after_the_variables() {
+    let mut run = create_run(&["A"]);
+    run.metadata_mut().set_variable("Difficulty", "Hard");
+    let mut timer = Timer::new(run).unwrap();
+    timer.set_runtime_value("Stars", "12");
+    timer.set_runtime_value("Coins", "");
+    timer.set_runtime_value("Lives", "3");
+    timer.set_runtime_value("Stars", "13");
+    timer.remove_runtime_value("Lives");
+
+    let mut component = Component::with_settings(Settings {
+        show_platform: false,
+        show_region: false,
+        variables: vec![String::from("Difficulty")],
+        ..Default::default()
+    });
+    assert_eq!(
+        rows(&component, &timer),
+        [
+            (String::from("Difficulty"), String::from("Hard")),
+            (String::from("Stars"), String::from("13")),
+        ]
+    );
+
+    component.set_value(7, Value::Bool(false));
+    assert_eq!(
+        rows(&component, &timer),
+        [(String::from("Difficulty"), String::from("Hard"))]
+    );
+
+    let mut buf = Vec::new();
+    crate::run::saver::livesplit::save_timer(&timer, &mut buf).unwrap();
+    assert!(!String::from_utf8(buf).unwrap().contains("Stars"));
+}
@@ -0,0 +1,217 @@
+//! Provides the Segment Potential Component and relevant types for using it.
+//! The Segment Potential Component is a component that shows which of the
+//! remaining segments has the most time that is expected to be saved compared
+//! to the chosen comparison, along with the empirical probability of getting a
+//! new best segment on it. This helps deciding which segments to focus on when
+//! practicing.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::analysis::segment_potential;
+use crate::settings::{Color, Field, Gradient, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, PossibleTimeSave, TimeFormatter};
+use crate::{comparison, Timer};
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// The Segment Potential Component is a component that shows which of the
+/// remaining segments has the most time that is expected to be saved compared
+/// to the chosen comparison, along with the empirical probability of getting a
+/// new best segment on it. This helps deciding which segments to focus on when
+/// practicing.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The accuracy of the time shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            value_color: None,
+            accuracy: Accuracy::Hundredths,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The color of the value. If `None` is specified, the color is taken from
+    /// the layout.
+    pub value_color: Option<Color>,
+    /// The label's text, which includes the name of the segment with the most
+    /// potential if there is one.
+    pub text: String,
+    /// The time that is expected to be saved on the segment and the probability
+    /// of getting a new best segment on it.
+    pub value: String,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Segment Potential Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment Potential Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Segment Potential".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        let run = timer.timed_run();
+        let method = timer.current_timing_method();
+        let comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(comparison, timer);
+        let first_index = timer.current_split_index().unwrap_or(0);
+        let allowed_attempts = run.allowed_attempts();
+
+        let mut focus = None;
+        for segment_index in first_index..run.len() {
+            if let Some(time_save) = segment_potential::expected_time_save(
+                run,
+                allowed_attempts.as_ref(),
+                segment_index,
+                comparison,
+                method,
+            ) {
+                if focus.map_or(true, |(_, best)| time_save > best) {
+                    focus = Some((segment_index, time_save));
+                }
+            }
+        }
+
+        let (text, value) = if let Some((segment_index, time_save)) = focus {
+            let gold_probability = segment_potential::gold_probability(
+                run,
+                allowed_attempts.as_ref(),
+                segment_index,
+                method,
+            )
+            .unwrap_or(0.0);
+            (
+                format!("Focus: {}", run.segment(segment_index).name()),
+                format!(
+                    "{} ({:.1}% Gold)",
+                    PossibleTimeSave::with_accuracy(self.settings.accuracy).format(time_save),
+                    100.0 * gold_probability,
+                ),
+            )
+        } else {
+            (
+                self.name().into_owned(),
+                PossibleTimeSave::with_accuracy(self.settings.accuracy)
+                    .format(None)
+                    .to_string(),
+            )
+        };
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            value_color: self.settings.value_color,
+            text,
+            value,
+            display_two_rows: self.settings.display_two_rows,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new("Value Color".into(), self.settings.value_color.into()),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.value_color = value.into(),
+            5 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
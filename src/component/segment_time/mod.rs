@@ -0,0 +1,272 @@
+//! Provides the Segment Time Component and relevant types for using it. The
+//! Segment Time Component is a component that shows the time of the current
+//! segment as it is ticking. Next to it, the comparison's segment time and the
+//! best segment time of the segment can be displayed. The live segment time is
+//! colored based on whether the runner is ahead or behind the comparison's
+//! segment time.
+
+use super::DEFAULT_KEY_VALUE_GRADIENT;
+use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
+use crate::timing::formatter::{Accuracy, Regular, TimeFormatter};
+use crate::{analysis, comparison, GeneralLayoutSettings, Timer, TimerPhase};
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::fmt::Write as FmtWrite;
+use std::io::Write;
+
+#[cfg(test)]
+mod tests;
+
+/// The Segment Time Component is a component that shows the time of the
+/// current segment as it is ticking. Next to it, the comparison's segment time
+/// and the best segment time of the segment can be displayed. The live segment
+/// time is colored based on whether the runner is ahead or behind the
+/// comparison's segment time.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The comparison chosen. Uses the Timer's current comparison if set to
+    /// `None`.
+    pub comparison_override: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// Specifies whether the comparison's segment time is shown next to the
+    /// live segment time.
+    pub show_comparison_segment_time: bool,
+    /// Specifies whether the best segment time is shown next to the live
+    /// segment time.
+    pub show_best_segment_time: bool,
+    /// The accuracy of the times shown.
+    pub accuracy: Accuracy,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            background: DEFAULT_KEY_VALUE_GRADIENT,
+            comparison_override: None,
+            display_two_rows: false,
+            label_color: None,
+            show_comparison_segment_time: true,
+            show_best_segment_time: true,
+            accuracy: Accuracy::Tenths,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The background shown behind the component.
+    pub background: Gradient,
+    /// The color of the label. If `None` is specified, the color is taken from
+    /// the layout.
+    pub label_color: Option<Color>,
+    /// The label's text.
+    pub text: String,
+    /// The live segment time.
+    pub time: String,
+    /// The semantic coloring information the live segment time carries.
+    pub semantic_color: SemanticColor,
+    /// The visual color of the live segment time.
+    pub visual_color: Color,
+    /// The comparison's segment time. This is `None` if it's not supposed to
+    /// be visualized.
+    pub comparison_time: Option<String>,
+    /// The best segment time. This is `None` if it's not supposed to be
+    /// visualized.
+    pub best_time: Option<String>,
+    /// Specifies whether to display the name of the component and its value in
+    /// two separate rows.
+    pub display_two_rows: bool,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Segment Time Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment Time Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        self.text(
+            self.settings
+                .comparison_override
+                .as_ref()
+                .map(String::as_ref),
+        )
+    }
+
+    fn text(&self, comparison: Option<&str>) -> Cow<'_, str> {
+        let mut text = Cow::from("Segment Time");
+        if let Some(comparison) = comparison {
+            write!(text.to_mut(), " ({})", comparison::shorten(comparison)).unwrap();
+        }
+        text
+    }
+
+    /// Calculates the component's state based on the timer and the layout
+    /// settings provided.
+    pub fn state(&self, timer: &Timer, layout_settings: &GeneralLayoutSettings) -> State {
+        let resolved_comparison = comparison::resolve(&self.settings.comparison_override, timer);
+        let comparison = comparison::or_current(resolved_comparison, timer);
+        let method = timer.current_timing_method();
+        let run = timer.timed_run();
+
+        let (live_time, comparison_time, best_time, semantic_color) = if run.is_empty() {
+            (None, None, None, SemanticColor::Default)
+        } else {
+            let phase = timer.current_phase();
+            // Once the attempt is finished, the last segment stays visible.
+            let segment_index = timer.current_split_index().unwrap_or(0).min(run.len() - 1);
+
+            let comparison_time =
+                analysis::comparison_segment_time(run, segment_index, comparison, method);
+            let best_time = run.segment(segment_index).best_segment_time()[method];
+
+            let (live_time, semantic_color) = match phase {
+                TimerPhase::NotRunning => (None, SemanticColor::Default),
+                TimerPhase::Ended => {
+                    let delta =
+                        analysis::previous_segment_delta(timer, segment_index, comparison, method);
+                    (
+                        analysis::previous_segment_time(timer, segment_index, method),
+                        analysis::split_color(
+                            timer,
+                            delta,
+                            segment_index,
+                            false,
+                            true,
+                            comparison,
+                            method,
+                        ),
+                    )
+                }
+                TimerPhase::Running | TimerPhase::Paused => {
+                    let delta =
+                        analysis::live_segment_delta(timer, segment_index, comparison, method);
+                    (
+                        analysis::live_segment_time(timer, segment_index, method),
+                        analysis::split_color(
+                            timer,
+                            delta,
+                            segment_index,
+                            false,
+                            false,
+                            comparison,
+                            method,
+                        ),
+                    )
+                }
+            };
+
+            (live_time, comparison_time, best_time, semantic_color)
+        };
+
+        let formatter = Regular::with_accuracy(self.settings.accuracy);
+
+        State {
+            background: self.settings.background,
+            label_color: self.settings.label_color,
+            text: self.text(resolved_comparison).into_owned(),
+            time: formatter.format(live_time).to_string(),
+            semantic_color,
+            visual_color: semantic_color.visualize(layout_settings),
+            comparison_time: if self.settings.show_comparison_segment_time {
+                Some(formatter.format(comparison_time).to_string())
+            } else {
+                None
+            },
+            best_time: if self.settings.show_best_segment_time {
+                Some(formatter.format(best_time).to_string())
+            } else {
+                None
+            },
+            display_two_rows: self.settings.display_two_rows,
+        }
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Background".into(), self.settings.background.into()),
+            Field::new(
+                "Comparison".into(),
+                self.settings.comparison_override.clone().into(),
+            ),
+            Field::new(
+                "Display 2 Rows".into(),
+                self.settings.display_two_rows.into(),
+            ),
+            Field::new("Label Color".into(), self.settings.label_color.into()),
+            Field::new(
+                "Show Comparison Segment Time".into(),
+                self.settings.show_comparison_segment_time.into(),
+            ),
+            Field::new(
+                "Show Best Segment Time".into(),
+                self.settings.show_best_segment_time.into(),
+            ),
+            Field::new("Accuracy".into(), self.settings.accuracy.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.background = value.into(),
+            1 => self.settings.comparison_override = value.into(),
+            2 => self.settings.display_two_rows = value.into(),
+            3 => self.settings.label_color = value.into(),
+            4 => self.settings.show_comparison_segment_time = value.into(),
+            5 => self.settings.show_best_segment_time = value.into(),
+            6 => self.settings.accuracy = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use super::{Component, Settings};
+use crate::settings::SemanticColor;
+use crate::tests_helper::{create_timer, run_with_splits, span, start_run};
+use crate::GeneralLayoutSettings;
+
+#[test]
+fn shows_live_comparison_and_best_segment_time() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+
+    let component = Component::new();
+    let layout_settings = GeneralLayoutSettings::default();
+
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.text, "Segment Time");
+    assert_eq!(state.time, "—");
+    assert_eq!(state.comparison_time.as_ref().unwrap(), "0:10.0");
+    assert_eq!(state.best_time.as_ref().unwrap(), "0:10.0");
+    assert_eq!(state.semantic_color, SemanticColor::Default);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(5.0));
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.time, "0:05.0");
+    assert_eq!(state.comparison_time.as_ref().unwrap(), "0:10.0");
+    assert_eq!(state.best_time.as_ref().unwrap(), "0:10.0");
+    assert_eq!(state.semantic_color, SemanticColor::AheadGainingTime);
+
+    timer.set_game_time(span(12.0));
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.time, "0:12.0");
+    assert_eq!(state.comparison_time.as_ref().unwrap(), "0:10.0");
+    assert_eq!(state.best_time.as_ref().unwrap(), "0:10.0");
+    assert_eq!(state.semantic_color, SemanticColor::BehindLosingTime);
+
+    timer.split();
+    timer.set_game_time(span(20.0));
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.time, "0:08.0");
+    assert_eq!(state.comparison_time.as_ref().unwrap(), "0:15.0");
+    assert_eq!(state.best_time.as_ref().unwrap(), "0:15.0");
+}
+
+#[test]
+fn keeps_showing_the_last_segment_once_finished() {
+    let mut timer = create_timer(&["A", "B"]);
+    run_with_splits(&mut timer, &[10.0, 25.0]);
+
+    start_run(&mut timer);
+    timer.set_game_time(span(10.0));
+    timer.split();
+    timer.set_game_time(span(22.0));
+    timer.split();
+
+    let state = Component::new().state(&timer, &GeneralLayoutSettings::default());
+    assert_eq!(state.time, "0:12.0");
+    assert_eq!(state.comparison_time.as_ref().unwrap(), "0:15.0");
+    assert_eq!(state.best_time.as_ref().unwrap(), "0:15.0");
+    assert_eq!(state.semantic_color, SemanticColor::BestSegment);
+}
+
+#[test]
+fn comparison_and_best_segment_time_can_be_hidden() {
+    let timer = create_timer(&["A"]);
+    let component = Component::with_settings(Settings {
+        show_comparison_segment_time: false,
+        show_best_segment_time: false,
+        ..Default::default()
+    });
+
+    let state = component.state(&timer, &GeneralLayoutSettings::default());
+    assert_eq!(state.time, "—");
+    assert!(state.comparison_time.is_none());
+    assert!(state.best_time.is_none());
+}
@@ -0,0 +1,322 @@
+//! Provides the Segment Trend Component and relevant types for using it. The
+//! Segment Trend Component is a graph that visualizes how the segment times of
+//! a segment evolve over time. Next to the individual segment times, a rolling
+//! average, the best segment time so far and the fitted trend are plotted. This
+//! helps seeing whether practicing a segment is paying off.
+
+use crate::analysis::trend::{self, TrendAxis};
+use crate::settings::{Color, Field, SettingsDescription, Value};
+use crate::Timer;
+use serde::{Deserialize, Serialize};
+use serde_json::{to_writer, Result};
+use std::borrow::Cow;
+use std::io::Write;
+
+const TREND_RESOLUTION: usize = 20;
+
+/// The Segment Trend Component is a graph that visualizes how the segment
+/// times of a segment evolve over time. Next to the individual segment times, a
+/// rolling average, the best segment time so far and the fitted trend are
+/// plotted. This helps seeing whether practicing a segment is paying off.
+#[derive(Default, Clone)]
+pub struct Component {
+    settings: Settings,
+}
+
+/// The Settings for this component.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// The number of the segment to visualize, starting at 1. If this is 0,
+    /// the current segment is visualized.
+    pub segment: u32,
+    /// Specifies whether the segment times are placed by the date of their
+    /// attempt instead of the attempt's index.
+    pub plot_over_date: bool,
+    /// The amount of most recent segment times the rolling average considers.
+    /// A window of 0 means that all the segment times up to that point are
+    /// considered.
+    pub window: u32,
+    /// Specifies whether the rolling median is shown instead of the rolling
+    /// mean.
+    pub rolling_median: bool,
+    /// Specifies whether an exponential trend is shown instead of a linear
+    /// trend.
+    pub exponential_trend: bool,
+    /// The background color of the graph.
+    pub background_color: Color,
+    /// The color of the individual segment times.
+    pub times_color: Color,
+    /// The color of the rolling average.
+    pub rolling_color: Color,
+    /// The color of the best segment time so far.
+    pub best_color: Color,
+    /// The color of the trend.
+    pub trend_color: Color,
+    /// The height of the graph.
+    pub height: u32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            segment: 0,
+            plot_over_date: false,
+            window: 10,
+            rolling_median: false,
+            exponential_trend: false,
+            background_color: (0.0, 0.0, 0.0, 0.15).into(),
+            times_color: (1.0, 1.0, 1.0, 0.5).into(),
+            rolling_color: (1.0, 1.0, 1.0, 1.0).into(),
+            best_color: (1.0, 212.0 / 255.0, 0.0, 1.0).into(),
+            trend_color: (0.0, 204.0 / 255.0, 1.0, 1.0).into(),
+            height: 80,
+        }
+    }
+}
+
+/// The state object describes the information to visualize for this component.
+/// All the coordinates are in the range 0..1.
+#[derive(Serialize, Deserialize)]
+pub struct State {
+    /// The name of the segment that is visualized.
+    pub segment_name: String,
+    /// The individual segment times. Each of them is to be visualized as a
+    /// separate point.
+    pub times: Vec<Point>,
+    /// The points of the rolling average. Connect all of them to visualize it.
+    pub rolling: Vec<Point>,
+    /// The points of the best segment time so far. Connect all of them to
+    /// visualize it.
+    pub best: Vec<Point>,
+    /// The points of the trend. Connect all of them to visualize it. This is
+    /// empty if there is no trend.
+    pub trend: Vec<Point>,
+    /// The background color of the graph.
+    pub background_color: Color,
+    /// The color of the individual segment times.
+    pub times_color: Color,
+    /// The color of the rolling average.
+    pub rolling_color: Color,
+    /// The color of the best segment time so far.
+    pub best_color: Color,
+    /// The color of the trend.
+    pub trend_color: Color,
+    /// The height of the graph.
+    pub height: u32,
+}
+
+/// Describes a point on the graph to visualize.
+#[derive(Copy, Clone, Serialize, Deserialize)]
+pub struct Point {
+    /// The x coordinate of the point.
+    pub x: f32,
+    /// The y coordinate of the point.
+    pub y: f32,
+}
+
+impl State {
+    /// Encodes the state object's information as JSON.
+    pub fn write_json<W>(&self, writer: W) -> Result<()>
+    where
+        W: Write,
+    {
+        to_writer(writer, self)
+    }
+}
+
+impl Component {
+    /// Creates a new Segment Trend Component.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Creates a new Segment Trend Component with the given settings.
+    pub fn with_settings(settings: Settings) -> Self {
+        Self { settings }
+    }
+
+    /// Accesses the settings of the component.
+    pub fn settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// Grants mutable access to the settings of the component.
+    pub fn settings_mut(&mut self) -> &mut Settings {
+        &mut self.settings
+    }
+
+    /// Accesses the name of the component.
+    pub fn name(&self) -> Cow<'_, str> {
+        "Segment Trend".into()
+    }
+
+    /// Calculates the component's state based on the timer provided.
+    pub fn state(&self, timer: &Timer) -> State {
+        let mut state = State {
+            segment_name: String::new(),
+            times: Vec::new(),
+            rolling: Vec::new(),
+            best: Vec::new(),
+            trend: Vec::new(),
+            background_color: self.settings.background_color,
+            times_color: self.settings.times_color,
+            rolling_color: self.settings.rolling_color,
+            best_color: self.settings.best_color,
+            trend_color: self.settings.trend_color,
+            height: self.settings.height,
+        };
+
+        let run = timer.timed_run();
+        if run.is_empty() {
+            return state;
+        }
+
+        let segment_index = if self.settings.segment == 0 {
+            timer.current_split_index().unwrap_or(0)
+        } else {
+            self.settings.segment as usize - 1
+        };
+        let segment_index = segment_index.min(run.len() - 1);
+        state.segment_name = run.segment(segment_index).name().to_owned();
+
+        let axis = if self.settings.plot_over_date {
+            TrendAxis::Date
+        } else {
+            TrendAxis::Attempt
+        };
+
+        let trend = trend::calculate(
+            run,
+            segment_index,
+            timer.current_timing_method(),
+            axis,
+            self.settings.window as usize,
+        );
+
+        let (first, last) = match (trend.points.first(), trend.points.last()) {
+            (Some(first), Some(last)) => (first.x, last.x),
+            _ => return state,
+        };
+
+        let trend_times = (0..=TREND_RESOLUTION)
+            .filter_map(|i| {
+                let x = first + (last - first) * i as f64 / TREND_RESOLUTION as f64;
+                let time = if self.settings.exponential_trend {
+                    trend.exponential?.at(x)
+                } else {
+                    trend.linear?.at(x)
+                };
+                Some((x, time.total_seconds()))
+            })
+            .collect::<Vec<_>>();
+
+        let (mut min, mut max) = (
+            trend.points[0].best.total_seconds(),
+            trend.points[0].time.total_seconds(),
+        );
+        for point in &trend.points {
+            min = min.min(point.best.total_seconds());
+            max = max.max(point.time.total_seconds());
+        }
+        for &(_, time) in &trend_times {
+            min = min.min(time);
+            max = max.max(time);
+        }
+
+        let to_point = |x: f64, y: f64| {
+            let x = if last > first {
+                (x - first) / (last - first)
+            } else {
+                0.5
+            };
+            let y = if max > min {
+                (y - min) / (max - min)
+            } else {
+                0.5
+            };
+            Point {
+                x: (0.05 + 0.9 * x) as f32,
+                y: (0.9 - 0.8 * y) as f32,
+            }
+        };
+
+        for point in &trend.points {
+            let rolling = if self.settings.rolling_median {
+                point.rolling_median
+            } else {
+                point.rolling_mean
+            };
+            state
+                .times
+                .push(to_point(point.x, point.time.total_seconds()));
+            state
+                .rolling
+                .push(to_point(point.x, rolling.total_seconds()));
+            state
+                .best
+                .push(to_point(point.x, point.best.total_seconds()));
+        }
+
+        state.trend = trend_times
+            .into_iter()
+            .map(|(x, time)| to_point(x, time))
+            .collect();
+
+        state
+    }
+
+    /// Accesses a generic description of the settings available for this
+    /// component and their current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new(
+                "Segment (0 = Current)".into(),
+                u64::from(self.settings.segment).into(),
+            ),
+            Field::new("Plot Over Date".into(), self.settings.plot_over_date.into()),
+            Field::new("Window".into(), u64::from(self.settings.window).into()),
+            Field::new("Rolling Median".into(), self.settings.rolling_median.into()),
+            Field::new(
+                "Exponential Trend".into(),
+                self.settings.exponential_trend.into(),
+            ),
+            Field::new("Height".into(), u64::from(self.settings.height).into()),
+            Field::new(
+                "Background Color".into(),
+                self.settings.background_color.into(),
+            ),
+            Field::new("Times Color".into(), self.settings.times_color.into()),
+            Field::new(
+                "Rolling Average Color".into(),
+                self.settings.rolling_color.into(),
+            ),
+            Field::new("Best Color".into(), self.settings.best_color.into()),
+            Field::new("Trend Color".into(), self.settings.trend_color.into()),
+        ])
+    }
+
+    /// Sets a setting's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.settings.segment = value.into_uint().unwrap() as _,
+            1 => self.settings.plot_over_date = value.into(),
+            2 => self.settings.window = value.into_uint().unwrap() as _,
+            3 => self.settings.rolling_median = value.into(),
+            4 => self.settings.exponential_trend = value.into(),
+            5 => self.settings.height = value.into_uint().unwrap() as _,
+            6 => self.settings.background_color = value.into(),
+            7 => self.settings.times_color = value.into(),
+            8 => self.settings.rolling_color = value.into(),
+            9 => self.settings.best_color = value.into(),
+            10 => self.settings.trend_color = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -1,11 +1,16 @@
 use crate::{
-    analysis::{self, possible_time_save, split_color},
+    analysis::{
+        self, possible_time_save,
+        reset_points::{self, ResetPoints},
+        segment_potential, split_color,
+    },
     comparison,
     settings::{Color, SemanticColor},
-    timing::formatter::{Delta, PossibleTimeSave, Regular, TimeFormatter},
-    GeneralLayoutSettings, Segment, TimeSpan, Timer, TimingMethod,
+    timing::formatter::{Delta, PossibleTimeSave, Regular, TimeFormatter, DASH},
+    GeneralLayoutSettings, Run, Segment, TimeSpan, Timer, TimingMethod,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 /// The settings of an individual column showing timing information on each
 /// split.
@@ -47,6 +52,25 @@ pub enum ColumnStartWith {
     /// The column starts out with the time that can be saved on each individual
     /// segment stored in the comparison that is being compared against.
     PossibleTimeSave,
+    /// The column starts out with the empirical probability of getting a new
+    /// best segment on each individual segment.
+    GoldProbability,
+    /// The column starts out with the time that is expected to be saved on each
+    /// individual segment compared to the comparison that is being compared
+    /// against, based on the segment history.
+    ExpectedTimeSave,
+    /// The column starts out with the best segment time of each individual
+    /// segment.
+    BestSegmentTime,
+    /// The column starts out with the average of the segment times in the
+    /// segment history of each individual segment.
+    AverageSegmentTime,
+    /// The column starts out with the amount of attempts that got reset during
+    /// each individual segment.
+    ResetCount,
+    /// The column starts out with the amount of attempts that reached each
+    /// individual segment.
+    ReachedCount,
 }
 
 /// Once a certain condition is met, which is usually being on the split or
@@ -79,6 +103,9 @@ pub enum ColumnUpdateWith {
     /// Segment component. If there is no time saved or lost, then value gets
     /// replaced by the current attempt's segment time instead.
     SegmentDeltaWithFallback,
+    /// The value gets replaced by how much faster or slower the current
+    /// attempt's segment time is compared to the best segment time.
+    BestSegmentDelta,
 }
 
 /// Specifies when a column's value gets updated.
@@ -124,20 +151,58 @@ enum ColumnFormatter {
     Time,
     Delta,
     PossibleTimeSave,
+    Percentage(Option<f64>),
+    Count(Option<u32>),
+}
+
+/// The information the columns need that is calculated once for the whole
+/// Splits state, so it doesn't need to be calculated again for every row.
+pub struct SharedColumnData {
+    /// The reset points for each of the timing methods used by columns showing
+    /// reset or reached counts, indexed by the timing method.
+    reset_points: [Option<ResetPoints>; 2],
+    /// The attempts considered by the Run's History Filter.
+    allowed_attempts: Option<HashSet<i32>>,
+}
+
+impl SharedColumnData {
+    /// Calculates the information needed by the columns provided.
+    pub fn calculate(columns: &[ColumnSettings], run: &Run, method: TimingMethod) -> Self {
+        let mut reset_points = [None, None];
+        for column in columns {
+            if let ColumnStartWith::ResetCount | ColumnStartWith::ReachedCount = column.start_with {
+                let method = column.timing_method.unwrap_or(method);
+                let points = &mut reset_points[method as usize];
+                if points.is_none() {
+                    *points = Some(reset_points::calculate(run, method));
+                }
+            }
+        }
+
+        Self {
+            reset_points,
+            allowed_attempts: run.allowed_attempts(),
+        }
+    }
 }
 
 pub fn state(
     column: &ColumnSettings,
     timer: &Timer,
     layout_settings: &GeneralLayoutSettings,
-    segment: &Segment,
     segment_index: usize,
     current_split: Option<usize>,
     method: TimingMethod,
+    shared: &SharedColumnData,
 ) -> ColumnState {
+    let segment = timer.timed_run().segment(segment_index);
     let method = column.timing_method.unwrap_or_else(|| method);
     let resolved_comparison = comparison::resolve(&column.comparison_override, timer);
     let comparison = comparison::or_current(resolved_comparison, timer);
+    let allowed_attempts = shared.allowed_attempts.as_ref();
+    let segment_resets = shared.reset_points[method as usize]
+        .as_ref()
+        .and_then(|points| points.segments.get(segment_index));
 
     let update_value = column_update_value(
         column,
@@ -160,7 +225,12 @@ pub fn state(
                 ColumnFormatter::Time,
             ),
             ColumnStartWith::ComparisonSegmentTime => (
-                analysis::comparison_segment_time(timer.run(), segment_index, comparison, method),
+                analysis::comparison_segment_time(
+                    timer.timed_run(),
+                    segment_index,
+                    comparison,
+                    method,
+                ),
                 SemanticColor::Default,
                 ColumnFormatter::Time,
             ),
@@ -169,6 +239,52 @@ pub fn state(
                 SemanticColor::Default,
                 ColumnFormatter::PossibleTimeSave,
             ),
+            ColumnStartWith::GoldProbability => (
+                None,
+                SemanticColor::Default,
+                ColumnFormatter::Percentage(segment_potential::gold_probability(
+                    timer.timed_run(),
+                    allowed_attempts,
+                    segment_index,
+                    method,
+                )),
+            ),
+            ColumnStartWith::ExpectedTimeSave => (
+                segment_potential::expected_time_save(
+                    timer.timed_run(),
+                    allowed_attempts,
+                    segment_index,
+                    comparison,
+                    method,
+                ),
+                SemanticColor::Default,
+                ColumnFormatter::PossibleTimeSave,
+            ),
+            ColumnStartWith::BestSegmentTime => (
+                segment.best_segment_time()[method],
+                SemanticColor::Default,
+                ColumnFormatter::Time,
+            ),
+            ColumnStartWith::AverageSegmentTime => (
+                segment_potential::average_segment_time(
+                    timer.timed_run(),
+                    allowed_attempts,
+                    segment_index,
+                    method,
+                ),
+                SemanticColor::Default,
+                ColumnFormatter::Time,
+            ),
+            ColumnStartWith::ResetCount => (
+                None,
+                SemanticColor::Default,
+                ColumnFormatter::Count(segment_resets.map(|s| s.resets)),
+            ),
+            ColumnStartWith::ReachedCount => (
+                None,
+                SemanticColor::Default,
+                ColumnFormatter::Count(segment_resets.map(|s| s.reached)),
+            ),
         });
 
     let is_empty = column.start_with == ColumnStartWith::Empty && !updated;
@@ -184,6 +300,12 @@ pub fn state(
             ColumnFormatter::PossibleTimeSave => {
                 PossibleTimeSave::new().format(column_value).to_string()
             }
+            ColumnFormatter::Percentage(percentage) => {
+                percentage.map_or_else(|| DASH.to_string(), |p| format!("{:.1}%", 100.0 * p))
+            }
+            ColumnFormatter::Count(count) => {
+                count.map_or_else(|| DASH.to_string(), |c| c.to_string())
+            }
         }
     };
 
@@ -306,6 +428,29 @@ fn column_update_value(
             SemanticColor::Default,
             ColumnFormatter::Delta,
         )),
+
+        (BestSegmentDelta, false) => {
+            // The best segment times only get updated when the attempt gets
+            // reset, so a negative delta means a new best segment.
+            let delta = catch! {
+                analysis::previous_segment_time(timer, segment_index, method)?
+                    - segment.best_segment_time()[method]?
+            };
+            let semantic_color = if delta.map_or(false, |d| d < TimeSpan::zero()) {
+                SemanticColor::BestSegment
+            } else {
+                SemanticColor::Default
+            };
+            Some((delta, semantic_color, ColumnFormatter::Delta))
+        }
+        (BestSegmentDelta, true) => Some((
+            catch! {
+                analysis::live_segment_time(timer, segment_index, method)?
+                    - segment.best_segment_time()[method]?
+            },
+            SemanticColor::Default,
+            ColumnFormatter::Delta,
+        )),
     }
 }
 
@@ -313,7 +458,7 @@ impl ColumnUpdateWith {
     fn is_segment_based(self) -> bool {
         use self::ColumnUpdateWith::*;
         match self {
-            SegmentDelta | SegmentTime | SegmentDeltaWithFallback => true,
+            SegmentDelta | SegmentTime | SegmentDeltaWithFallback | BestSegmentDelta => true,
             _ => false,
         }
     }
@@ -270,7 +270,7 @@ impl Component {
             self.scroll_offset = 0;
         }
 
-        let run = timer.run();
+        let run = timer.timed_run();
         self.icon_ids.resize(run.len(), CachedImageId::default());
 
         let mut visual_split_count = self.settings.visual_split_count;
@@ -317,6 +317,8 @@ impl Component {
             ..
         } = self.settings;
 
+        let shared_column_data = column::SharedColumnData::calculate(columns, run, method);
+
         let mut icon_changes = Vec::new();
 
         let mut splits: Vec<_> = run
@@ -336,10 +338,10 @@ impl Component {
                             column,
                             timer,
                             layout_settings,
-                            segment,
                             i,
                             current_split,
                             method,
+                            &shared_column_data,
                         )
                     })
                     .collect();
@@ -1319,6 +1319,100 @@ fn delta_or_split_time() {
     timer.reset(true);
 }
 
+#[test]
+fn history_based_start_with() {
+    let mut timer = timer();
+
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(10.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+    run_with_splits_opt(&mut timer, &[Some(7.0), Some(14.0)]);
+    run_with_splits_opt(&mut timer, &[Some(3.0)]);
+
+    let layout_settings = Default::default();
+    let check = |start_with, expected_values: [&str; 6]| {
+        let mut component = Component::with_settings(Settings {
+            columns: vec![ColumnSettings {
+                start_with,
+                update_with: ColumnUpdateWith::DontUpdate,
+                ..Default::default()
+            }],
+            fill_with_blank_space: false,
+            ..Default::default()
+        });
+        let state = component.state(&timer, &layout_settings);
+        let actual_values = state
+            .splits
+            .iter()
+            .map(|split| split.columns[0].value.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(actual_values, expected_values);
+    };
+
+    check(
+        ColumnStartWith::BestSegmentTime,
+        ["0:03", "0:05", "0:05", "0:05", "0:05", "0:05"],
+    );
+    check(
+        ColumnStartWith::AverageSegmentTime,
+        ["0:05", "0:06", "0:05", "0:05", "0:05", "0:05"],
+    );
+    check(ColumnStartWith::ResetCount, ["0", "1", "1", "0", "0", "0"]);
+    check(
+        ColumnStartWith::ReachedCount,
+        ["3", "3", "2", "1", "1", "1"],
+    );
+}
+
+#[test]
+fn best_segment_delta() {
+    let mut timer = timer();
+
+    run_with_splits_opt(
+        &mut timer,
+        &[
+            Some(5.0),
+            Some(10.0),
+            Some(15.0),
+            Some(20.0),
+            Some(25.0),
+            Some(30.0),
+        ],
+    );
+
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        columns: vec![ColumnSettings {
+            start_with: ColumnStartWith::Empty,
+            update_with: ColumnUpdateWith::BestSegmentDelta,
+            update_trigger: ColumnUpdateTrigger::OnEndingSegment,
+            ..Default::default()
+        }],
+        fill_with_blank_space: false,
+        ..Default::default()
+    });
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(4.0), Some(10.0)]);
+
+    check_column_state(
+        &component.state(&timer, &layout_settings),
+        0,
+        &[(
+            ["−1.0", "+1.0", "", "", "", ""],
+            [Best, Text, Text, Text, Text, Text],
+        )],
+    );
+}
+
 fn check_column_color(state: &State, split_index: usize, expected_color: SemanticColor) {
     assert_eq!(
         state.splits[split_index].columns[0].semantic_color,
@@ -119,7 +119,7 @@ impl Component {
     /// Calculates the component's state based on the timer provided.
     pub fn state(&self, timer: &Timer) -> State {
         let time = calculate_best(
-            timer.run().segments(),
+            timer.timed_run().segments(),
             false,
             true,
             timer.current_timing_method(),
@@ -1,7 +1,8 @@
 //! Provides the Timer Component and relevant types for using it. The Timer
 //! Component is a component that shows the total time of the current attempt as
 //! a digital clock. The color of the time shown is based on a how well the
-//! current attempt is doing compared to the chosen comparison.
+//! current attempt is doing compared to the chosen comparison. While the time
+//! is negative, the Timer Component shows a countdown instead.
 
 use crate::analysis::split_color;
 use crate::settings::{Color, Field, Gradient, SemanticColor, SettingsDescription, Value};
@@ -47,6 +48,15 @@ pub struct Settings {
     pub digits_format: DigitsFormat,
     /// The accuracy of the time shown.
     pub accuracy: Accuracy,
+    /// The color to use for the time while the timer is counting down, which
+    /// is the case while the time is negative. If it's not specified, the
+    /// color is determined like usual. A color override always takes
+    /// precedence over this.
+    pub countdown_color: Option<Color>,
+    /// Specifies whether the minus sign should be shown while the timer is
+    /// counting down. If this is set to `false`, the time that remains until
+    /// the countdown ends is shown instead.
+    pub show_countdown_sign: bool,
 }
 
 impl Default for Settings {
@@ -59,6 +69,8 @@ impl Default for Settings {
             show_gradient: true,
             digits_format: DigitsFormat::SingleDigitSeconds,
             accuracy: Accuracy::Hundredths,
+            countdown_color: None,
+            show_countdown_sign: true,
         }
     }
 }
@@ -154,7 +166,7 @@ impl Component {
             TimerPhase::Paused => SemanticColor::Paused,
             TimerPhase::Ended => {
                 let pb_time = timer
-                    .run()
+                    .timed_run()
                     .segments()
                     .last()
                     .unwrap()
@@ -169,12 +181,22 @@ impl Component {
             _ => SemanticColor::NotRunning,
         };
 
+        let is_counting_down = time < TimeSpan::zero();
+
         let visual_color = if let Some(color) = self.settings.color_override {
             color
+        } else if let Some(color) = self.settings.countdown_color.filter(|_| is_counting_down) {
+            color
         } else {
             semantic_color.visualize(layout_settings)
         };
 
+        let time = if is_counting_down && !self.settings.show_countdown_sign {
+            -time
+        } else {
+            time
+        };
+
         let (top_color, bottom_color) = if self.settings.show_gradient {
             top_and_bottom_color(visual_color)
         } else {
@@ -207,6 +229,14 @@ impl Component {
             Field::new("Show Gradient".into(), self.settings.show_gradient.into()),
             Field::new("Digits Format".into(), self.settings.digits_format.into()),
             Field::new("Accuracy".into(), self.settings.accuracy.into()),
+            Field::new(
+                "Countdown Color".into(),
+                self.settings.countdown_color.into(),
+            ),
+            Field::new(
+                "Show Minus Sign During Countdown".into(),
+                self.settings.show_countdown_sign.into(),
+            ),
         ])
     }
 
@@ -226,6 +256,8 @@ impl Component {
             4 => self.settings.show_gradient = value.into(),
             5 => self.settings.digits_format = value.into(),
             6 => self.settings.accuracy = value.into(),
+            7 => self.settings.countdown_color = value.into(),
+            8 => self.settings.show_countdown_sign = value.into(),
             _ => panic!("Unsupported Setting Index"),
         }
     }
@@ -161,11 +161,11 @@ impl Component {
 
     /// Calculates the component's state based on the timer provided.
     pub fn state(&mut self, timer: &Timer) -> State {
-        let run = timer.run();
+        let run = timer.timed_run();
 
         let finished_runs = if self.settings.show_finished_runs_count {
             let mut count = timer
-                .run()
+                .timed_run()
                 .attempt_history()
                 .iter()
                 .filter(|a| a.time().real_time.is_some())
@@ -1,5 +1,8 @@
+use crate::component::counter::Counter;
 use crate::hotkey::{Hook, KeyCode};
 use crate::{HotkeyConfig, SharedTimer};
+use parking_lot::RwLock;
+use std::mem;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -9,11 +12,15 @@ pub use crate::hotkey::{Error, Result};
 /// the Timer. The hotkeys are global, so the application doesn't need to be in
 /// focus. The behavior of the hotkeys depends on the platform and is stubbed
 /// out on platforms that don't support hotkeys. You can turn off a Hotkey
-/// System temporarily. By default the Hotkey System is activated.
+/// System temporarily. By default the Hotkey System is activated. The counters
+/// of Counter Components can be connected to the Hotkey System, so that they
+/// can be modified by the hotkeys configured in their settings.
 pub struct HotkeySystem {
     config: HotkeyConfig,
     hook: Hook,
     timer: SharedTimer,
+    counters: Arc<RwLock<Vec<Counter>>>,
+    counter_hotkeys: Vec<KeyCode>,
     is_active: Arc<AtomicBool>,
 }
 
@@ -124,6 +131,8 @@ impl HotkeySystem {
             config,
             hook,
             timer,
+            counters: Default::default(),
+            counter_hotkeys: Vec::new(),
             is_active,
         })
     }
@@ -320,6 +329,77 @@ impl HotkeySystem {
         Ok(())
     }
 
+    /// Connects the counter provided to the Hotkey System, so that the hotkeys
+    /// configured in the settings of its Counter Component modify it. Any
+    /// number of counters can be connected at the same time. If the hotkeys of
+    /// a connected counter change, it needs to be connected again for the new
+    /// hotkeys to be registered. This operation may fail if one of the hotkeys
+    /// is already used for controlling the Timer.
+    pub fn connect_counter(&mut self, counter: Counter) -> Result<()> {
+        let mut counters = self.counters.read().clone();
+        if !counters.iter().any(|c| c.is_same(&counter)) {
+            counters.push(counter);
+        }
+        self.set_counters(counters)
+    }
+
+    /// Disconnects the counter provided from the Hotkey System. Its hotkeys
+    /// don't modify it anymore. If the counter is not connected, nothing
+    /// happens.
+    pub fn disconnect_counter(&mut self, counter: &Counter) -> Result<()> {
+        let mut counters = self.counters.read().clone();
+        counters.retain(|c| !c.is_same(counter));
+        self.set_counters(counters)
+    }
+
+    fn set_counters(&mut self, counters: Vec<Counter>) -> Result<()> {
+        let mut hotkeys = Vec::new();
+        for counter in &counters {
+            for hotkey in counter.hotkeys() {
+                if !hotkeys.contains(&hotkey) {
+                    hotkeys.push(hotkey);
+                }
+            }
+        }
+
+        // Multiple counters may share the same hotkey, so each hotkey is only
+        // registered once and modifies all the counters that use it. The
+        // hotkeys that are new get registered first, so that nothing changes
+        // if any of them can't be registered.
+        let mut registered = Vec::new();
+        for &hotkey in &hotkeys {
+            if self.counter_hotkeys.contains(&hotkey) {
+                continue;
+            }
+            let inner = self.counters.clone();
+            let active = self.is_active.clone();
+            let result = self.hook.register(hotkey, move || {
+                if active.load(Ordering::Acquire) {
+                    for counter in inner.read().iter() {
+                        counter.handle_hotkey(hotkey);
+                    }
+                }
+            });
+            if let Err(error) = result {
+                for hotkey in registered {
+                    let _ = self.hook.unregister(hotkey);
+                }
+                return Err(error);
+            }
+            registered.push(hotkey);
+        }
+
+        *self.counters.write() = counters;
+
+        for hotkey in mem::replace(&mut self.counter_hotkeys, hotkeys) {
+            if !self.counter_hotkeys.contains(&hotkey) {
+                self.hook.unregister(hotkey)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Deactivates the Hotkey System. No hotkeys will go through until it gets
     /// activated again. If it's already deactivated, nothing happens.
     pub fn deactivate(&self) {
@@ -1,8 +1,9 @@
 use super::{ComponentSettings, ComponentState, GeneralSettings};
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
-    total_playtime,
+    blank_space, clock, comparison_table, counter, current_comparison, current_pace, delta,
+    detailed_timer, finish_time, graph, image, pb_chance, possible_time_save, previous_segment,
+    run_history, run_metadata, segment_potential, segment_time, segment_trend, separator, splits,
+    sum_of_best, text, timer, title, total_playtime,
 };
 use crate::settings::{SettingsDescription, Value};
 use crate::Timer;
@@ -14,6 +15,12 @@ use std::borrow::Cow;
 pub enum Component {
     /// The Blank Space Component.
     BlankSpace(blank_space::Component),
+    /// The Clock Component.
+    Clock(clock::Component),
+    /// The Comparison Table Component.
+    ComparisonTable(comparison_table::Component),
+    /// The Counter Component.
+    Counter(counter::Component),
     /// The Current Comparison Component.
     CurrentComparison(current_comparison::Component),
     /// The Current Pace Component.
@@ -22,14 +29,28 @@ pub enum Component {
     Delta(delta::Component),
     /// The Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::Component>),
+    /// The Finish Time Component.
+    FinishTime(finish_time::Component),
     /// The Graph Component.
     Graph(graph::Component),
+    /// The Image Component.
+    Image(image::Component),
     /// The PB Chance Component.
     PbChance(pb_chance::Component),
     /// The Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Component),
     /// The Previous Segment Component.
     PreviousSegment(previous_segment::Component),
+    /// The Run History Component.
+    RunHistory(run_history::Component),
+    /// The Run Metadata Component.
+    RunMetadata(run_metadata::Component),
+    /// The Segment Potential Component.
+    SegmentPotential(segment_potential::Component),
+    /// The Segment Time Component.
+    SegmentTime(segment_time::Component),
+    /// The Segment Trend Component.
+    SegmentTrend(segment_trend::Component),
     /// The Separator Component.
     Separator(separator::Component),
     /// The Splits Component.
@@ -54,6 +75,11 @@ impl Component {
     pub fn state(&mut self, timer: &Timer, layout_settings: &GeneralSettings) -> ComponentState {
         match self {
             Component::BlankSpace(component) => ComponentState::BlankSpace(component.state(timer)),
+            Component::Clock(component) => ComponentState::Clock(component.state(timer)),
+            Component::ComparisonTable(component) => {
+                ComponentState::ComparisonTable(component.state(timer, layout_settings))
+            }
+            Component::Counter(component) => ComponentState::Counter(component.state()),
             Component::CurrentComparison(component) => {
                 ComponentState::CurrentComparison(component.state(timer))
             }
@@ -66,9 +92,11 @@ impl Component {
             Component::DetailedTimer(component) => {
                 ComponentState::DetailedTimer(Box::new(component.state(timer, layout_settings)))
             }
+            Component::FinishTime(component) => ComponentState::FinishTime(component.state(timer)),
             Component::Graph(component) => {
                 ComponentState::Graph(component.state(timer, layout_settings))
             }
+            Component::Image(component) => ComponentState::Image(component.state()),
             Component::PbChance(component) => ComponentState::PbChance(component.state(timer)),
             Component::PossibleTimeSave(component) => {
                 ComponentState::PossibleTimeSave(component.state(timer))
@@ -76,6 +104,21 @@ impl Component {
             Component::PreviousSegment(component) => {
                 ComponentState::PreviousSegment(component.state(timer, layout_settings))
             }
+            Component::RunHistory(component) => {
+                ComponentState::RunHistory(component.state(timer, layout_settings))
+            }
+            Component::RunMetadata(component) => {
+                ComponentState::RunMetadata(component.state(timer))
+            }
+            Component::SegmentPotential(component) => {
+                ComponentState::SegmentPotential(component.state(timer))
+            }
+            Component::SegmentTime(component) => {
+                ComponentState::SegmentTime(component.state(timer, layout_settings))
+            }
+            Component::SegmentTrend(component) => {
+                ComponentState::SegmentTrend(component.state(timer))
+            }
             Component::Separator(component) => ComponentState::Separator(component.state(timer)),
             Component::Splits(component) => {
                 ComponentState::Splits(component.state(timer, layout_settings))
@@ -101,6 +144,13 @@ impl Component {
             Component::BlankSpace(component) => {
                 ComponentSettings::BlankSpace(component.settings().clone())
             }
+            Component::Clock(component) => ComponentSettings::Clock(component.settings().clone()),
+            Component::ComparisonTable(component) => {
+                ComponentSettings::ComparisonTable(component.settings().clone())
+            }
+            Component::Counter(component) => {
+                ComponentSettings::Counter(component.settings().clone())
+            }
             Component::CurrentComparison(component) => {
                 ComponentSettings::CurrentComparison(component.settings().clone())
             }
@@ -111,7 +161,11 @@ impl Component {
             Component::DetailedTimer(component) => {
                 ComponentSettings::DetailedTimer(Box::new(component.settings().clone()))
             }
+            Component::FinishTime(component) => {
+                ComponentSettings::FinishTime(component.settings().clone())
+            }
             Component::Graph(component) => ComponentSettings::Graph(component.settings().clone()),
+            Component::Image(component) => ComponentSettings::Image(component.settings().clone()),
             Component::PbChance(component) => {
                 ComponentSettings::PbChance(component.settings().clone())
             }
@@ -121,6 +175,21 @@ impl Component {
             Component::PreviousSegment(component) => {
                 ComponentSettings::PreviousSegment(component.settings().clone())
             }
+            Component::RunHistory(component) => {
+                ComponentSettings::RunHistory(component.settings().clone())
+            }
+            Component::RunMetadata(component) => {
+                ComponentSettings::RunMetadata(component.settings().clone())
+            }
+            Component::SegmentPotential(component) => {
+                ComponentSettings::SegmentPotential(component.settings().clone())
+            }
+            Component::SegmentTime(component) => {
+                ComponentSettings::SegmentTime(component.settings().clone())
+            }
+            Component::SegmentTrend(component) => {
+                ComponentSettings::SegmentTrend(component.settings().clone())
+            }
             Component::Separator(_) => ComponentSettings::Separator,
             Component::Splits(component) => ComponentSettings::Splits(component.settings().clone()),
             Component::SumOfBest(component) => {
@@ -139,14 +208,24 @@ impl Component {
     pub fn name(&self) -> Cow<'_, str> {
         match self {
             Component::BlankSpace(component) => component.name(),
+            Component::Clock(component) => component.name(),
+            Component::ComparisonTable(component) => component.name(),
+            Component::Counter(component) => component.name(),
             Component::CurrentComparison(component) => component.name(),
             Component::CurrentPace(component) => component.name(),
             Component::Delta(component) => component.name(),
             Component::DetailedTimer(component) => component.name(),
+            Component::FinishTime(component) => component.name(),
             Component::Graph(component) => component.name(),
+            Component::Image(component) => component.name(),
             Component::PbChance(component) => component.name(),
             Component::PossibleTimeSave(component) => component.name(),
             Component::PreviousSegment(component) => component.name(),
+            Component::RunHistory(component) => component.name(),
+            Component::RunMetadata(component) => component.name(),
+            Component::SegmentPotential(component) => component.name(),
+            Component::SegmentTime(component) => component.name(),
+            Component::SegmentTrend(component) => component.name(),
             Component::Separator(component) => component.name(),
             Component::Splits(component) => component.name(),
             Component::SumOfBest(component) => component.name(),
@@ -180,6 +259,7 @@ impl Component {
     pub fn remount(&mut self) {
         match self {
             Component::DetailedTimer(component) => component.remount(),
+            Component::Image(component) => component.remount(),
             Component::Splits(component) => component.remount(),
             Component::Title(component) => component.remount(),
             _ => {}
@@ -193,14 +273,24 @@ impl Component {
     pub fn settings_description(&self) -> SettingsDescription {
         match self {
             Component::BlankSpace(component) => component.settings_description(),
+            Component::Clock(component) => component.settings_description(),
+            Component::ComparisonTable(component) => component.settings_description(),
+            Component::Counter(component) => component.settings_description(),
             Component::CurrentComparison(component) => component.settings_description(),
             Component::CurrentPace(component) => component.settings_description(),
             Component::Delta(component) => component.settings_description(),
             Component::DetailedTimer(component) => component.settings_description(),
+            Component::FinishTime(component) => component.settings_description(),
             Component::Graph(component) => component.settings_description(),
+            Component::Image(component) => component.settings_description(),
             Component::PbChance(component) => component.settings_description(),
             Component::PossibleTimeSave(component) => component.settings_description(),
             Component::PreviousSegment(component) => component.settings_description(),
+            Component::RunHistory(component) => component.settings_description(),
+            Component::RunMetadata(component) => component.settings_description(),
+            Component::SegmentPotential(component) => component.settings_description(),
+            Component::SegmentTime(component) => component.settings_description(),
+            Component::SegmentTrend(component) => component.settings_description(),
             Component::Separator(component) => component.settings_description(),
             Component::Splits(component) => component.settings_description(),
             Component::SumOfBest(component) => component.settings_description(),
@@ -222,14 +312,24 @@ impl Component {
     pub fn set_value(&mut self, index: usize, value: Value) {
         match self {
             Component::BlankSpace(component) => component.set_value(index, value),
+            Component::Clock(component) => component.set_value(index, value),
+            Component::ComparisonTable(component) => component.set_value(index, value),
+            Component::Counter(component) => component.set_value(index, value),
             Component::CurrentComparison(component) => component.set_value(index, value),
             Component::CurrentPace(component) => component.set_value(index, value),
             Component::Delta(component) => component.set_value(index, value),
             Component::DetailedTimer(component) => component.set_value(index, value),
+            Component::FinishTime(component) => component.set_value(index, value),
             Component::Graph(component) => component.set_value(index, value),
+            Component::Image(component) => component.set_value(index, value),
             Component::PbChance(component) => component.set_value(index, value),
             Component::PossibleTimeSave(component) => component.set_value(index, value),
             Component::PreviousSegment(component) => component.set_value(index, value),
+            Component::RunHistory(component) => component.set_value(index, value),
+            Component::RunMetadata(component) => component.set_value(index, value),
+            Component::SegmentPotential(component) => component.set_value(index, value),
+            Component::SegmentTime(component) => component.set_value(index, value),
+            Component::SegmentTrend(component) => component.set_value(index, value),
             Component::Separator(component) => component.set_value(index, value),
             Component::Splits(component) => component.set_value(index, value),
             Component::SumOfBest(component) => component.set_value(index, value),
@@ -1,8 +1,9 @@
 use super::Component;
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
-    total_playtime,
+    blank_space, clock, comparison_table, counter, current_comparison, current_pace, delta,
+    detailed_timer, finish_time, graph, image, pb_chance, possible_time_save, previous_segment,
+    run_history, run_metadata, segment_potential, segment_time, segment_trend, separator, splits,
+    sum_of_best, text, timer, title, total_playtime,
 };
 use serde::{Deserialize, Serialize};
 
@@ -11,6 +12,12 @@ use serde::{Deserialize, Serialize};
 pub enum ComponentSettings {
     /// The Settings for the Blank Space Component.
     BlankSpace(blank_space::Settings),
+    /// The Settings for the Clock Component.
+    Clock(clock::Settings),
+    /// The Settings for the Comparison Table Component.
+    ComparisonTable(comparison_table::Settings),
+    /// The Settings for the Counter Component.
+    Counter(counter::Settings),
     /// The Settings for the Current Comparison Component.
     CurrentComparison(current_comparison::Settings),
     /// The Settings for the Current Pace Component.
@@ -19,14 +26,28 @@ pub enum ComponentSettings {
     Delta(delta::Settings),
     /// The Settings for the Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::Settings>),
+    /// The Settings for the Finish Time Component.
+    FinishTime(finish_time::Settings),
     /// The Settings for the Graph Component.
     Graph(graph::Settings),
+    /// The Settings for the Image Component.
+    Image(image::Settings),
     /// The Settings for the PB Chance Component.
     PbChance(pb_chance::Settings),
     /// The Settings for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::Settings),
     /// The Settings for the Previous Segment Component.
     PreviousSegment(previous_segment::Settings),
+    /// The Settings for the Run History Component.
+    RunHistory(run_history::Settings),
+    /// The Settings for the Run Metadata Component.
+    RunMetadata(run_metadata::Settings),
+    /// The Settings for the Segment Potential Component.
+    SegmentPotential(segment_potential::Settings),
+    /// The Settings for the Segment Time Component.
+    SegmentTime(segment_time::Settings),
+    /// The Settings for the Segment Trend Component.
+    SegmentTrend(segment_trend::Settings),
     /// The Settings for the Separator Component.
     Separator,
     /// The Settings for the Splits Component.
@@ -49,6 +70,15 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::BlankSpace(settings) => {
                 Component::BlankSpace(blank_space::Component::with_settings(settings))
             }
+            ComponentSettings::Clock(settings) => {
+                Component::Clock(clock::Component::with_settings(settings))
+            }
+            ComponentSettings::ComparisonTable(settings) => {
+                Component::ComparisonTable(comparison_table::Component::with_settings(settings))
+            }
+            ComponentSettings::Counter(settings) => {
+                Component::Counter(counter::Component::with_settings(settings))
+            }
             ComponentSettings::CurrentComparison(settings) => {
                 Component::CurrentComparison(current_comparison::Component::with_settings(settings))
             }
@@ -61,9 +91,15 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::DetailedTimer(settings) => Component::DetailedTimer(Box::new(
                 detailed_timer::Component::with_settings(*settings),
             )),
+            ComponentSettings::FinishTime(settings) => {
+                Component::FinishTime(finish_time::Component::with_settings(settings))
+            }
             ComponentSettings::Graph(settings) => {
                 Component::Graph(graph::Component::with_settings(settings))
             }
+            ComponentSettings::Image(settings) => {
+                Component::Image(image::Component::with_settings(settings))
+            }
             ComponentSettings::PbChance(settings) => {
                 Component::PbChance(pb_chance::Component::with_settings(settings))
             }
@@ -73,6 +109,21 @@ impl From<ComponentSettings> for Component {
             ComponentSettings::PreviousSegment(settings) => {
                 Component::PreviousSegment(previous_segment::Component::with_settings(settings))
             }
+            ComponentSettings::RunHistory(settings) => {
+                Component::RunHistory(run_history::Component::with_settings(settings))
+            }
+            ComponentSettings::RunMetadata(settings) => {
+                Component::RunMetadata(run_metadata::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentPotential(settings) => {
+                Component::SegmentPotential(segment_potential::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentTime(settings) => {
+                Component::SegmentTime(segment_time::Component::with_settings(settings))
+            }
+            ComponentSettings::SegmentTrend(settings) => {
+                Component::SegmentTrend(segment_trend::Component::with_settings(settings))
+            }
             ComponentSettings::Separator => Component::Separator(separator::Component::new()),
             ComponentSettings::Splits(settings) => {
                 Component::Splits(splits::Component::with_settings(settings))
@@ -1,7 +1,8 @@
 use crate::component::{
-    blank_space, current_comparison, current_pace, delta, detailed_timer, graph, pb_chance,
-    possible_time_save, previous_segment, separator, splits, sum_of_best, text, timer, title,
-    total_playtime,
+    blank_space, clock, comparison_table, counter, current_comparison, current_pace, delta,
+    detailed_timer, finish_time, graph, image, pb_chance, possible_time_save, previous_segment,
+    run_history, run_metadata, segment_potential, segment_time, segment_trend, separator, splits,
+    sum_of_best, text, timer, title, total_playtime,
 };
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +11,12 @@ use serde::{Deserialize, Serialize};
 pub enum ComponentState {
     /// The state object for the Blank Space Component.
     BlankSpace(blank_space::State),
+    /// The state object for the Clock Component.
+    Clock(clock::State),
+    /// The state object for the Comparison Table Component.
+    ComparisonTable(comparison_table::State),
+    /// The state object for the Counter Component.
+    Counter(counter::State),
     /// The state object for the Current Comparison Component.
     CurrentComparison(current_comparison::State),
     /// The state object for the Current Pace Component.
@@ -18,14 +25,28 @@ pub enum ComponentState {
     Delta(delta::State),
     /// The state object for the Detailed Timer Component.
     DetailedTimer(Box<detailed_timer::State>),
+    /// The state object for the Finish Time Component.
+    FinishTime(finish_time::State),
     /// The state object for the Graph Component.
     Graph(graph::State),
+    /// The state object for the Image Component.
+    Image(image::State),
     /// The state object for the PB Chance Component.
     PbChance(pb_chance::State),
     /// The state object for the Possible Time Save Component.
     PossibleTimeSave(possible_time_save::State),
     /// The state object for the Previous Segment Component.
     PreviousSegment(previous_segment::State),
+    /// The state object for the Run History Component.
+    RunHistory(run_history::State),
+    /// The state object for the Run Metadata Component.
+    RunMetadata(run_metadata::State),
+    /// The state object for the Segment Potential Component.
+    SegmentPotential(segment_potential::State),
+    /// The state object for the Segment Time Component.
+    SegmentTime(segment_time::State),
+    /// The state object for the Segment Trend Component.
+    SegmentTrend(segment_trend::State),
     /// The state object for the Separator Component.
     Separator(separator::State),
     /// The state object for the Splits Component.
@@ -58,9 +58,12 @@ impl Editor {
 
     /// Calculates the layout's state based on the timer provided. You can use
     /// this to visualize all of the components of a layout, while it is still
-    /// being edited by the Layout Editor.
+    /// being edited by the Layout Editor. Unlike the state of the layout itself,
+    /// this includes the components that are currently hidden by their
+    /// visibility conditions, so the components line up with the ones in the
+    /// Layout Editor's state.
     pub fn layout_state(&mut self, timer: &Timer) -> LayoutState {
-        self.layout.state(timer)
+        self.layout.state_unfiltered(timer)
     }
 
     /// Selects the component with the given index in order to modify its
@@ -92,7 +95,9 @@ impl Editor {
     /// component instead.
     pub fn remove_component(&mut self) {
         if self.can_remove_component() {
+            self.layout.sync_visibility();
             self.layout.components.remove(self.selected_component);
+            self.layout.visibility.remove(self.selected_component);
             if self.selected_component >= self.layout.components.len() {
                 self.selected_component = self.layout.components.len() - 1;
             }
@@ -109,9 +114,13 @@ impl Editor {
     /// Moves the selected component up, unless the first component is selected.
     pub fn move_component_up(&mut self) {
         if self.can_move_component_up() {
+            self.layout.sync_visibility();
             self.layout
                 .components
                 .swap(self.selected_component, self.selected_component - 1);
+            self.layout
+                .visibility
+                .swap(self.selected_component, self.selected_component - 1);
             self.selected_component -= 1;
             self.layout.remount();
         }
@@ -127,9 +136,13 @@ impl Editor {
     /// selected.
     pub fn move_component_down(&mut self) {
         if self.can_move_component_down() {
+            self.layout.sync_visibility();
             self.layout
                 .components
                 .swap(self.selected_component, self.selected_component + 1);
+            self.layout
+                .visibility
+                .swap(self.selected_component, self.selected_component + 1);
             self.selected_component += 1;
             self.layout.remount();
         }
@@ -155,7 +168,10 @@ impl Editor {
         let new_index = index + 1;
 
         let component = self.layout.components[index].clone();
+        let visibility = self.layout.visibility(index);
+        self.layout.sync_visibility();
         self.layout.components.insert(new_index, component);
+        self.layout.visibility.insert(new_index, visibility);
 
         self.selected_component = new_index;
         self.layout.remount();
@@ -173,6 +189,20 @@ impl Editor {
         self.layout.components[self.selected_component].set_value(index, value);
     }
 
+    /// Sets a visibility condition's value of the selected component by its
+    /// index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_component_visibility_value(&mut self, index: usize, value: Value) {
+        self.layout
+            .visibility_mut(self.selected_component)
+            .set_value(index, value);
+    }
+
     /// Sets a setting's value of the general settings by its setting index to
     /// the given value.
     ///
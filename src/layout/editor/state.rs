@@ -17,6 +17,9 @@ pub struct State {
     /// A generic description of the settings available for the selected
     /// component and their current values.
     pub component_settings: SettingsDescription,
+    /// A generic description of the conditions under which the selected
+    /// component is visible and their current values.
+    pub component_visibility: SettingsDescription,
     /// A generic description of the general settings available for the layout
     /// and their current values.
     pub general_settings: SettingsDescription,
@@ -70,6 +73,10 @@ impl Editor {
             selected_component: self.selected_component as u32,
             component_settings: self.layout.components[self.selected_component]
                 .settings_description(),
+            component_visibility: self
+                .layout
+                .visibility(self.selected_component)
+                .settings_description(),
             general_settings: self.layout.general_settings().settings_description(),
         }
     }
@@ -1,4 +1,4 @@
-use super::{ComponentSettings, GeneralSettings};
+use super::{ComponentSettings, GeneralSettings, Visibility};
 use serde::{Deserialize, Serialize};
 use serde_json::{from_reader, to_writer, Result};
 use std::io::{Read, Write};
@@ -11,6 +11,11 @@ pub struct LayoutSettings {
     pub components: Vec<ComponentSettings>,
     /// The general settings of the layout that apply to all components.
     pub general: GeneralSettings,
+    /// The conditions under which each of the components is visible. The
+    /// conditions are in the same order as the components. Components without
+    /// conditions are always visible.
+    #[serde(default)]
+    pub visibility: Vec<Visibility>,
 }
 
 impl LayoutSettings {
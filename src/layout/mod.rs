@@ -11,6 +11,10 @@ mod layout_direction;
 mod layout_settings;
 mod layout_state;
 pub mod parser;
+mod visibility;
+
+#[cfg(test)]
+mod tests;
 
 pub use self::component::Component;
 pub use self::component_settings::ComponentSettings;
@@ -20,6 +24,7 @@ pub use self::general_settings::GeneralSettings;
 pub use self::layout_direction::LayoutDirection;
 pub use self::layout_settings::LayoutSettings;
 pub use self::layout_state::LayoutState;
+pub use self::visibility::Visibility;
 
 use crate::component::{previous_segment, splits, timer, title};
 use crate::timing::Timer;
@@ -31,6 +36,8 @@ pub struct Layout {
     /// All of the layout's components.
     pub components: Vec<Component>,
     settings: GeneralSettings,
+    visibility: Vec<Visibility>,
+    last_visible: Vec<bool>,
 }
 
 impl Layout {
@@ -52,6 +59,8 @@ impl Layout {
                 previous_segment::Component::new().into(),
             ],
             settings: GeneralSettings::default(),
+            visibility: Vec::new(),
+            last_visible: Vec::new(),
         }
     }
 
@@ -64,6 +73,8 @@ impl Layout {
                 .map(Into::into)
                 .collect(),
             settings: layout_settings.general,
+            visibility: layout_settings.visibility,
+            last_visible: Vec::new(),
         }
     }
 
@@ -81,18 +92,70 @@ impl Layout {
 
     /// Adds a new component to the end of the layout.
     pub fn push<C: Into<Component>>(&mut self, component: C) {
+        self.sync_visibility();
         self.components.push(component.into());
+        self.visibility.push(Visibility::default());
+    }
+
+    /// Accesses the conditions under which the component with the index
+    /// provided is visible.
+    pub fn visibility(&self, index: usize) -> Visibility {
+        self.visibility.get(index).cloned().unwrap_or_default()
+    }
+
+    /// Sets the conditions under which the component with the index provided
+    /// is visible. You may not provide an invalid index.
+    pub fn set_visibility(&mut self, index: usize, visibility: Visibility) {
+        *self.visibility_mut(index) = visibility;
+    }
+
+    pub(crate) fn visibility_mut(&mut self, index: usize) -> &mut Visibility {
+        self.sync_visibility();
+        &mut self.visibility[index]
+    }
+
+    /// Ensures that there are visibility conditions for each component, as
+    /// components may have been added to the layout directly.
+    pub(crate) fn sync_visibility(&mut self) {
+        self.visibility
+            .resize(self.components.len(), Visibility::default());
     }
 
     /// Calculates the layout's state based on the timer provided. You can use
-    /// this to visualize all of the components of a layout.
+    /// this to visualize all of the components of a layout. Only the components
+    /// that are visible according to their visibility conditions are included.
     pub fn state(&mut self, timer: &Timer) -> LayoutState {
+        let visible = (0..self.components.len())
+            .map(|i| self.visibility(i).is_visible(timer))
+            .collect();
+
+        self.state_with_visibility(timer, visible)
+    }
+
+    /// Calculates the layout's state with every component included, regardless
+    /// of its visibility conditions. This way the components in the state
+    /// always line up with the components of the layout.
+    fn state_unfiltered(&mut self, timer: &Timer) -> LayoutState {
+        let visible = vec![true; self.components.len()];
+        self.state_with_visibility(timer, visible)
+    }
+
+    fn state_with_visibility(&mut self, timer: &Timer, visible: Vec<bool>) -> LayoutState {
+        if visible != self.last_visible {
+            // The components shown have changed, so the information that is
+            // only provided on changes needs to be provided again.
+            self.remount();
+            self.last_visible = visible;
+        }
+
         let settings = &self.settings;
         LayoutState {
             components: self
                 .components
                 .iter_mut()
-                .map(|c| c.state(timer, settings))
+                .zip(&self.last_visible)
+                .filter(|(_, &visible)| visible)
+                .map(|(c, _)| c.state(timer, settings))
                 .collect(),
             background: settings.background,
             thin_separators_color: settings.thin_separators_color,
@@ -107,6 +170,9 @@ impl Layout {
         LayoutSettings {
             components: self.components.iter().map(Component::settings).collect(),
             general: self.settings.clone(),
+            visibility: (0..self.components.len())
+                .map(|i| self.visibility(i))
+                .collect(),
         }
     }
 
@@ -17,11 +17,13 @@ use quick_xml::Reader;
 use std::io::BufRead;
 
 mod blank_space;
+mod counter;
 mod current_comparison;
 mod current_pace;
 mod delta;
 mod detailed_timer;
 mod graph;
+mod image;
 mod pb_chance;
 mod possible_time_save;
 mod previous_segment;
@@ -343,6 +345,7 @@ where
             text_err(reader, tag.into_buf(), |text| {
                 component = Some(match &*text {
                     "LiveSplit.BlankSpace.dll" => blank_space::Component::new().into(),
+                    "LiveSplit.Counter.dll" => counter::Component::new().into(),
                     "LiveSplit.CurrentComparison.dll" => {
                         current_comparison::Component::new().into()
                     }
@@ -364,7 +367,10 @@ where
                     "LiveSplit.Timer.dll" => timer::Component::new().into(),
                     "LiveSplit.Title.dll" => title::Component::new().into(),
                     "LiveSplit.TotalPlaytime.dll" => total_playtime::Component::new().into(),
-                    _ => return Ok(()),
+                    // The original LiveSplit doesn't ship a component for
+                    // showing images, so any other component is treated as
+                    // one. It is only kept if its settings contain an image.
+                    _ => image::Component::new().into(),
                 });
                 Ok(())
             })
@@ -374,6 +380,9 @@ where
             if let Some(component) = &mut component {
                 match component {
                     Component::BlankSpace(c) => blank_space::settings(reader, tag.into_buf(), c),
+                    Component::Clock(_) => end_tag(reader, tag.into_buf()),
+                    Component::ComparisonTable(_) => end_tag(reader, tag.into_buf()),
+                    Component::Counter(c) => counter::settings(reader, tag.into_buf(), c),
                     Component::CurrentComparison(c) => {
                         current_comparison::settings(reader, tag.into_buf(), c)
                     }
@@ -382,7 +391,9 @@ where
                     Component::DetailedTimer(c) => {
                         detailed_timer::settings(reader, tag.into_buf(), c)
                     }
+                    Component::FinishTime(_) => end_tag(reader, tag.into_buf()),
                     Component::Graph(c) => graph::settings(reader, tag.into_buf(), c),
+                    Component::Image(c) => image::settings(reader, tag.into_buf(), c),
                     Component::PbChance(c) => pb_chance::settings(reader, tag.into_buf(), c),
                     Component::PossibleTimeSave(c) => {
                         possible_time_save::settings(reader, tag.into_buf(), c)
@@ -390,6 +401,11 @@ where
                     Component::PreviousSegment(c) => {
                         previous_segment::settings(reader, tag.into_buf(), c)
                     }
+                    Component::RunHistory(_) => end_tag(reader, tag.into_buf()),
+                    Component::RunMetadata(_) => end_tag(reader, tag.into_buf()),
+                    Component::SegmentPotential(_) => end_tag(reader, tag.into_buf()),
+                    Component::SegmentTime(_) => end_tag(reader, tag.into_buf()),
+                    Component::SegmentTrend(_) => end_tag(reader, tag.into_buf()),
                     Component::Separator(_) => end_tag(reader, tag.into_buf()),
                     Component::Splits(c) => splits::settings(reader, tag.into_buf(), c),
                     Component::SumOfBest(c) => sum_of_best::settings(reader, tag.into_buf(), c),
@@ -408,8 +424,10 @@ where
         }
     })?;
 
-    if let Some(component) = component {
-        f(component);
+    match component {
+        Some(Component::Image(c)) if c.settings().image.is_empty() => {}
+        Some(component) => f(component),
+        None => {}
     }
 
     Ok(())
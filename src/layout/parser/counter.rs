@@ -0,0 +1,70 @@
+use super::{
+    color, end_tag, parse_bool, parse_children, text, text_parsed, GradientBuilder, Result,
+};
+use quick_xml::Reader;
+use std::io::BufRead;
+
+pub use crate::component::counter::Component;
+
+pub fn settings<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    component: &mut Component,
+) -> Result<()>
+where
+    R: BufRead,
+{
+    let settings = component.settings_mut();
+    let mut background_builder = GradientBuilder::new();
+    let mut override_color = false;
+
+    parse_children(reader, buf, |reader, tag| {
+        if let Some(tag) = background_builder.parse_background(reader, tag)? {
+            if tag.name() == b"CounterTextColor" {
+                color(reader, tag.into_buf(), |c| settings.label_color = Some(c))
+            } else if tag.name() == b"CounterValueColor" {
+                color(reader, tag.into_buf(), |c| settings.value_color = Some(c))
+            } else if tag.name() == b"OverrideTextColor" {
+                parse_bool(reader, tag.into_buf(), |b| override_color = b)
+            } else if tag.name() == b"CounterText" {
+                text(reader, tag.into_buf(), |v| settings.text = v.into_owned())
+            } else if tag.name() == b"InitialValue" {
+                text_parsed(reader, tag.into_buf(), |v| settings.start_value = v)
+            } else if tag.name() == b"Increment" {
+                text_parsed(reader, tag.into_buf(), |v| settings.step = v)
+            } else if tag.name() == b"Display2Rows" {
+                parse_bool(reader, tag.into_buf(), |b| settings.display_two_rows = b)
+            } else if tag.name() == b"IncrementKey" {
+                text(reader, tag.into_buf(), |v| {
+                    settings.increment_key = v.parse().ok()
+                })
+            } else if tag.name() == b"DecrementKey" {
+                text(reader, tag.into_buf(), |v| {
+                    settings.decrement_key = v.parse().ok()
+                })
+            } else if tag.name() == b"ResetKey" {
+                text(reader, tag.into_buf(), |v| {
+                    settings.reset_key = v.parse().ok()
+                })
+            } else {
+                // FIXME:
+                // CounterFont
+                // OverrideCounterFont
+                // GlobalHotkeysEnabled
+                end_tag(reader, tag.into_buf())
+            }
+        } else {
+            Ok(())
+        }
+    })?;
+
+    if !override_color {
+        settings.label_color = None;
+        settings.value_color = None;
+    }
+    settings.background = background_builder.build();
+
+    component.reset();
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use super::{end_tag, parse_children, text_parsed, Error, GradientBuilder, Result};
+use crate::xml_util::image;
+use quick_xml::Reader;
+use std::io::BufRead;
+
+pub use crate::component::image::Component;
+
+pub fn settings<R>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    component: &mut Component,
+) -> Result<()>
+where
+    R: BufRead,
+{
+    let mut background_builder = GradientBuilder::new();
+    let mut image_buf = Vec::new();
+
+    parse_children::<_, _, Error>(reader, buf, |reader, tag| {
+        if let Some(tag) = background_builder.parse_background(reader, tag)? {
+            if tag.name() == b"Image" {
+                image(reader, tag.into_buf(), &mut image_buf, |i| {
+                    component.set_image(i)
+                })
+            } else if tag.name() == b"Height" {
+                text_parsed(reader, tag.into_buf(), |h| {
+                    component.settings_mut().height = h
+                })
+            } else {
+                end_tag(reader, tag.into_buf())
+            }
+        } else {
+            Ok(())
+        }
+    })?;
+
+    component.settings_mut().background = background_builder.build();
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+use super::{ComponentState, Editor, Layout, LayoutSettings, Visibility};
+use crate::component::{blank_space, text};
+use crate::settings::Value;
+use crate::tests_helper::{create_timer, start_run};
+
+fn layout() -> Layout {
+    let mut layout = Layout::new();
+    layout.push(text::Component::new());
+    layout.push(blank_space::Component::new());
+    layout
+}
+
+#[test]
+fn components_are_hidden_based_on_the_timer_phase() {
+    let mut timer = create_timer(&["A", "B"]);
+    let mut layout = layout();
+    layout.set_visibility(
+        1,
+        Visibility {
+            when_not_running: false,
+            ..Default::default()
+        },
+    );
+
+    let state = layout.state(&timer);
+    assert_eq!(state.components.len(), 1);
+    if let ComponentState::BlankSpace(_) = state.components[0] {
+        panic!("The Blank Space Component should be hidden");
+    }
+
+    start_run(&mut timer);
+    assert_eq!(layout.state(&timer).components.len(), 2);
+}
+
+#[test]
+fn components_can_be_shown_only_on_the_last_split() {
+    let mut timer = create_timer(&["A", "B"]);
+    let mut layout = layout();
+    layout.set_visibility(
+        1,
+        Visibility {
+            only_on_last_split: true,
+            ..Default::default()
+        },
+    );
+
+    start_run(&mut timer);
+    assert_eq!(layout.state(&timer).components.len(), 1);
+
+    timer.split();
+    assert_eq!(layout.state(&timer).components.len(), 2);
+
+    timer.split();
+    assert_eq!(layout.state(&timer).components.len(), 2);
+}
+
+#[test]
+fn visibility_is_stored_in_the_settings() {
+    let mut layout = layout();
+    layout.set_visibility(
+        0,
+        Visibility {
+            when_ended: false,
+            ..Default::default()
+        },
+    );
+
+    let mut json = Vec::new();
+    layout.settings().write_json(&mut json).unwrap();
+    let settings = LayoutSettings::from_json(&*json).unwrap();
+    let layout = Layout::from_settings(settings);
+
+    assert!(!layout.visibility(0).when_ended);
+    assert_eq!(layout.visibility(1), Visibility::default());
+}
+
+#[test]
+fn layouts_without_visibility_show_all_components() {
+    let mut json = Vec::new();
+    layout().settings().write_json(&mut json).unwrap();
+    let mut value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    value.as_object_mut().unwrap().remove("visibility");
+    let settings = LayoutSettings::from_json(value.to_string().as_bytes()).unwrap();
+
+    let mut layout = Layout::from_settings(settings);
+    layout.components.push(text::Component::new().into());
+    assert_eq!(layout.visibility(2), Visibility::default());
+    let timer = create_timer(&["A", "B"]);
+    assert_eq!(layout.state(&timer).components.len(), 3);
+}
+
+#[test]
+fn editor_keeps_the_visibility_with_its_component() {
+    let mut editor = Editor::new(layout()).unwrap();
+    editor.set_component_visibility_value(2, Value::Bool(false));
+    match editor.state().component_visibility.fields[2].value {
+        Value::Bool(shown) => assert!(!shown),
+        _ => panic!("Expected a boolean"),
+    }
+
+    editor.move_component_down();
+    editor.duplicate_component();
+    editor.select(0);
+    editor.remove_component();
+
+    let layout = editor.close();
+    assert_eq!(layout.components.len(), 2);
+    assert!(!layout.visibility(0).when_paused);
+    assert!(!layout.visibility(1).when_paused);
+}
+
+#[test]
+fn editor_shows_hidden_components() {
+    let timer = create_timer(&["A", "B"]);
+    let mut layout = layout();
+    layout.set_visibility(
+        0,
+        Visibility {
+            when_not_running: false,
+            ..Default::default()
+        },
+    );
+    assert_eq!(layout.state(&timer).components.len(), 1);
+
+    let mut editor = Editor::new(layout).unwrap();
+    let state = editor.layout_state(&timer);
+    assert_eq!(state.components.len(), editor.state().components.len());
+    if let ComponentState::BlankSpace(_) = state.components[0] {
+        panic!("The hidden Text Component should be shown in the editor");
+    }
+
+    let mut layout = editor.close();
+    assert_eq!(layout.state(&timer).components.len(), 1);
+}
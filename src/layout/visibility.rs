@@ -0,0 +1,87 @@
+use crate::settings::{Field, SettingsDescription, Value};
+use crate::{Timer, TimerPhase};
+use serde::{Deserialize, Serialize};
+
+/// Describes the conditions under which a component of a layout is visible.
+/// This allows layouts to adapt to the state of the timer, like showing a
+/// component only while an attempt is running or only once it ended. By
+/// default a component is always visible.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Visibility {
+    /// Specifies whether the component is visible while there is no active
+    /// attempt.
+    pub when_not_running: bool,
+    /// Specifies whether the component is visible while an attempt is running.
+    pub when_running: bool,
+    /// Specifies whether the component is visible while an attempt is paused.
+    pub when_paused: bool,
+    /// Specifies whether the component is visible once an attempt has ended.
+    pub when_ended: bool,
+    /// Specifies whether the component is only visible once the last split is
+    /// reached. An attempt that has ended is considered to have reached the
+    /// last split as well.
+    pub only_on_last_split: bool,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self {
+            when_not_running: true,
+            when_running: true,
+            when_paused: true,
+            when_ended: true,
+            only_on_last_split: false,
+        }
+    }
+}
+
+impl Visibility {
+    /// Evaluates whether a component with these conditions is visible for the
+    /// timer provided.
+    pub fn is_visible(&self, timer: &Timer) -> bool {
+        let phase_matches = match timer.current_phase() {
+            TimerPhase::NotRunning => self.when_not_running,
+            TimerPhase::Running => self.when_running,
+            TimerPhase::Paused => self.when_paused,
+            TimerPhase::Ended => self.when_ended,
+        };
+
+        let split_matches = !self.only_on_last_split
+            || timer
+                .current_split_index()
+                .map_or(false, |index| index + 1 >= timer.timed_run().len());
+
+        phase_matches && split_matches
+    }
+
+    /// Accesses a generic description of the visibility conditions and their
+    /// current values.
+    pub fn settings_description(&self) -> SettingsDescription {
+        SettingsDescription::with_fields(vec![
+            Field::new("Show When Not Running".into(), self.when_not_running.into()),
+            Field::new("Show When Running".into(), self.when_running.into()),
+            Field::new("Show When Paused".into(), self.when_paused.into()),
+            Field::new("Show When Ended".into(), self.when_ended.into()),
+            Field::new("Only On Last Split".into(), self.only_on_last_split.into()),
+        ])
+    }
+
+    /// Sets a visibility condition's value by its index to the given value.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_value(&mut self, index: usize, value: Value) {
+        match index {
+            0 => self.when_not_running = value.into(),
+            1 => self.when_running = value.into(),
+            2 => self.when_paused = value.into(),
+            3 => self.when_ended = value.into(),
+            4 => self.only_on_last_split = value.into(),
+            _ => panic!("Unsupported Setting Index"),
+        }
+    }
+}
@@ -79,7 +79,9 @@ pub use {
         layout::{
             Component, Editor as LayoutEditor, GeneralSettings as GeneralLayoutSettings, Layout,
         },
-        run::{Attempt, Editor as RunEditor, Run, RunMetadata, Segment, SegmentHistory},
+        run::{
+            Attempt, Editor as RunEditor, HistoryFilter, Run, RunMetadata, Segment, SegmentHistory,
+        },
         timing::{
             AtomicDateTime, GameTime, RealTime, SharedTimer, Time, TimeSpan, TimeStamp, Timer,
             TimerPhase, TimingMethod,
@@ -21,7 +21,7 @@ pub extern "C" fn dealloc(ptr: *mut u8, cap: usize) {
     }
 }
 
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Offset, Utc};
 use std::mem::MaybeUninit;
 
 #[repr(C)]
@@ -33,6 +33,7 @@ struct FFIDateTime {
 
 extern "C" {
     fn Date_now(data: *mut FFIDateTime);
+    fn Date_utc_offset(secs: f64) -> i32;
 }
 
 pub fn utc_now() -> DateTime<Utc> {
@@ -46,3 +47,8 @@ pub fn utc_now() -> DateTime<Utc> {
         )
     }
 }
+
+pub fn local_utc_offset(time: DateTime<Utc>) -> FixedOffset {
+    let offset = unsafe { Date_utc_offset(time.timestamp() as f64) };
+    FixedOffset::east_opt(offset).unwrap_or_else(|| Utc.fix())
+}
@@ -0,0 +1,33 @@
+use crate::{
+    component::clock::State,
+    layout::LayoutState,
+    rendering::{Backend, RenderContext, DEFAULT_COMPONENT_HEIGHT},
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let label_color = component.label_color.unwrap_or(layout_state.text_color);
+    let value_color = component.value_color.unwrap_or(layout_state.text_color);
+
+    let transform = context.transform;
+
+    for row in &component.rows {
+        context.render_numerical_key_value_component(
+            &[&row.label],
+            &row.value,
+            [width, DEFAULT_COMPONENT_HEIGHT],
+            label_color,
+            value_color,
+            false,
+        );
+        context.translate(0.0, DEFAULT_COMPONENT_HEIGHT);
+    }
+
+    context.transform = transform;
+}
@@ -0,0 +1,67 @@
+use super::splits::COLUMN_WIDTH;
+use crate::{
+    component::comparison_table::State,
+    layout::LayoutState,
+    rendering::{
+        Backend, RenderContext, DEFAULT_COMPONENT_HEIGHT, DEFAULT_TEXT_SIZE, PADDING,
+        TEXT_ALIGN_BOTTOM, TEXT_ALIGN_TOP,
+    },
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let label_color = component.label_color.unwrap_or(layout_state.text_color);
+    let columns_left_x = width - PADDING - COLUMN_WIDTH * component.column_labels.len() as f32;
+
+    let mut right_x = width - PADDING;
+    for label in component.column_labels.iter().rev() {
+        context.render_text_right_align(
+            label,
+            [right_x, TEXT_ALIGN_TOP],
+            DEFAULT_TEXT_SIZE,
+            [label_color; 2],
+        );
+        right_x -= COLUMN_WIDTH;
+    }
+
+    context.render_text_ellipsis(
+        &component.split_name,
+        [PADDING, TEXT_ALIGN_TOP],
+        DEFAULT_TEXT_SIZE,
+        [label_color; 2],
+        columns_left_x - PADDING,
+    );
+
+    let transform = context.transform;
+
+    for row in &component.rows {
+        context.translate(0.0, DEFAULT_COMPONENT_HEIGHT);
+
+        let mut right_x = width - PADDING;
+        for cell in row.cells.iter().rev() {
+            context.render_numbers(
+                &cell.value,
+                [right_x, DEFAULT_COMPONENT_HEIGHT + TEXT_ALIGN_BOTTOM],
+                DEFAULT_TEXT_SIZE,
+                [cell.visual_color; 2],
+            );
+            right_x -= COLUMN_WIDTH;
+        }
+
+        context.render_text_ellipsis(
+            &row.name,
+            [PADDING, TEXT_ALIGN_TOP],
+            DEFAULT_TEXT_SIZE,
+            [label_color; 2],
+            columns_left_x - PADDING,
+        );
+    }
+
+    context.transform = transform;
+}
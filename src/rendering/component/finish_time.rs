@@ -0,0 +1,22 @@
+use crate::{
+    component::finish_time::State,
+    layout::{LayoutDirection, LayoutState},
+    rendering::{Backend, RenderContext},
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    dim: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], dim, &component.background);
+    context.render_textual_key_value_component(
+        &[&component.text, "Finish"],
+        &component.finish_time,
+        dim,
+        component.label_color.unwrap_or(layout_state.text_color),
+        component.value_color.unwrap_or(layout_state.text_color),
+        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
+    );
+}
@@ -56,6 +56,11 @@ pub(in crate::rendering) fn render(
         );
     }
 
+    if component.points.is_empty() {
+        context.transform = old_transform;
+        return;
+    }
+
     let mut mesh = Mesh::new();
 
     let len = if component.is_live_delta_active {
@@ -133,27 +138,31 @@ pub(in crate::rendering) fn render(
         context.free_mesh(line_mesh);
     }
 
-    for (i, point) in component.points.iter().enumerate().skip(1) {
-        if i != component.points.len() - 1 || !component.is_live_delta_active {
-            mesh.clear();
-
-            fill_circle(
-                [width * point.x, point.y].into(),
-                CIRCLE_RADIUS,
-                &FillOptions::tolerance(0.005).with_normals(false),
-                &mut fill_builder(&mut mesh),
-            )
-            .unwrap();
-
-            let color = if point.is_best_segment {
-                component.best_segment_color
-            } else {
-                component.graph_lines_color
-            };
-
-            let circle_mesh = context.create_mesh(&mesh);
-            context.render_mesh(&circle_mesh, color);
-            context.free_mesh(circle_mesh);
+    // The points of a bar graph describe the edges of the bars, so they are
+    // not visualized as circles.
+    if !component.is_bar_graph {
+        for (i, point) in component.points.iter().enumerate().skip(1) {
+            if i != component.points.len() - 1 || !component.is_live_delta_active {
+                mesh.clear();
+
+                fill_circle(
+                    [width * point.x, point.y].into(),
+                    CIRCLE_RADIUS,
+                    &FillOptions::tolerance(0.005).with_normals(false),
+                    &mut fill_builder(&mut mesh),
+                )
+                .unwrap();
+
+                let color = if point.is_best_segment {
+                    component.best_segment_color
+                } else {
+                    component.graph_lines_color
+                };
+
+                let circle_mesh = context.create_mesh(&mesh);
+                context.render_mesh(&circle_mesh, color);
+                context.free_mesh(circle_mesh);
+            }
         }
     }
 
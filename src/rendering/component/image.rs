@@ -0,0 +1,39 @@
+use crate::{
+    component::image::{ImageScaling, State},
+    layout::LayoutState,
+    rendering::{icon::Icon, Backend, RenderContext},
+};
+
+pub(in crate::rendering) fn render<B: Backend>(
+    context: &mut RenderContext<'_, B>,
+    [width, height]: [f32; 2],
+    component: &State,
+    _layout_state: &LayoutState,
+    image: &mut Option<Icon<B::Texture>>,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    if let Some(image_data) = &component.image_change {
+        if let Some(old_image) = image.take() {
+            context.backend.free_texture(old_image.texture);
+        }
+        *image = context.create_icon(image_data);
+    }
+
+    if let Some(image) = image {
+        match component.scaling {
+            ImageScaling::Fit => {
+                let image_width = (height * image.aspect_ratio).min(width);
+                let image_height = image_width / image.aspect_ratio;
+                let x = if component.is_centered {
+                    0.5 * (width - image_width)
+                } else {
+                    0.0
+                };
+                let y = 0.5 * (height - image_height);
+                context.render_image([x, y], [image_width, image_height], image);
+            }
+            ImageScaling::Stretch => context.render_image([0.0, 0.0], [width, height], image),
+        }
+    }
+}
@@ -1,12 +1,22 @@
 pub mod blank_space;
+pub mod clock;
+pub mod comparison_table;
+pub mod counter;
 pub mod current_comparison;
 pub mod current_pace;
 pub mod delta;
 pub mod detailed_timer;
+pub mod finish_time;
 pub mod graph;
+pub mod image;
 pub mod pb_chance;
 pub mod possible_time_save;
 pub mod previous_segment;
+pub mod run_history;
+pub mod run_metadata;
+pub mod segment_potential;
+pub mod segment_time;
+pub mod segment_trend;
 pub mod separator;
 pub mod splits;
 pub mod sum_of_best;
@@ -0,0 +1,136 @@
+use {
+    crate::{
+        component::run_history::State,
+        layout::LayoutState,
+        rendering::{
+            mesh::{fill_builder, stroke_builder},
+            Backend, Mesh, RenderContext,
+        },
+        settings::{Color, Gradient},
+    },
+    lyon::tessellation::{
+        basic_shapes::{fill_circle, stroke_polyline},
+        FillOptions, StrokeOptions,
+    },
+    std::iter,
+};
+
+const LINE_WIDTH: f32 = 0.025;
+const MARKER_WIDTH: f32 = 0.0125;
+const CIRCLE_RADIUS: f32 = 0.035;
+const INSET: f32 = 0.1;
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    _layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let old_transform = context.transform;
+    context.scale(height);
+    let width = width / height;
+
+    // The points are inset, so that the circles aren't cut off at the edges.
+    let to_pos = |x: f32, y: f32| {
+        [
+            INSET + x * (width - 2.0 * INSET),
+            INSET + y * (1.0 - 2.0 * INSET),
+        ]
+    };
+
+    if let Some(y) = component.personal_best {
+        let [_, y] = to_pos(0.0, y);
+        context.render_rectangle(
+            [0.0, y - MARKER_WIDTH],
+            [width, y + MARKER_WIDTH],
+            &Gradient::Plain(component.personal_best_color),
+        );
+    }
+
+    let mut mesh = Mesh::new();
+
+    for points in component.points.windows(2) {
+        render_line(
+            context,
+            &mut mesh,
+            to_pos(points[0].x, points[0].y),
+            to_pos(points[1].x, points[1].y),
+            component.line_color,
+        );
+    }
+
+    if let (Some(projected), Some(last)) = (&component.projected, component.points.last()) {
+        render_line(
+            context,
+            &mut mesh,
+            to_pos(last.x, last.y),
+            to_pos(projected.x, projected.y),
+            component.projected_color,
+        );
+    }
+
+    for point in &component.points {
+        let color = if point.is_personal_best {
+            component.personal_best_color
+        } else {
+            component.line_color
+        };
+        render_circle(context, &mut mesh, to_pos(point.x, point.y), color);
+    }
+
+    if let Some(projected) = &component.projected {
+        render_circle(
+            context,
+            &mut mesh,
+            to_pos(projected.x, projected.y),
+            component.projected_color,
+        );
+    }
+
+    context.transform = old_transform;
+}
+
+fn render_line(
+    context: &mut RenderContext<'_, impl Backend>,
+    mesh: &mut Mesh,
+    [x1, y1]: [f32; 2],
+    [x2, y2]: [f32; 2],
+    color: Color,
+) {
+    mesh.clear();
+
+    stroke_polyline(
+        iter::once([x1, y1].into()).chain(iter::once([x2, y2].into())),
+        false,
+        &StrokeOptions::default().with_line_width(LINE_WIDTH),
+        &mut stroke_builder(mesh),
+    )
+    .unwrap();
+
+    let line_mesh = context.create_mesh(mesh);
+    context.render_mesh(&line_mesh, color);
+    context.free_mesh(line_mesh);
+}
+
+fn render_circle(
+    context: &mut RenderContext<'_, impl Backend>,
+    mesh: &mut Mesh,
+    [x, y]: [f32; 2],
+    color: Color,
+) {
+    mesh.clear();
+
+    fill_circle(
+        [x, y].into(),
+        CIRCLE_RADIUS,
+        &FillOptions::tolerance(0.005).with_normals(false),
+        &mut fill_builder(mesh),
+    )
+    .unwrap();
+
+    let circle_mesh = context.create_mesh(mesh);
+    context.render_mesh(&circle_mesh, color);
+    context.free_mesh(circle_mesh);
+}
@@ -0,0 +1,39 @@
+use crate::{
+    component::run_metadata::State,
+    layout::{LayoutDirection, LayoutState},
+    rendering::{Backend, RenderContext, DEFAULT_COMPONENT_HEIGHT, TWO_ROW_HEIGHT},
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let label_color = component.label_color.unwrap_or(layout_state.text_color);
+    let value_color = component.value_color.unwrap_or(layout_state.text_color);
+
+    let row_height = if component.display_two_rows {
+        TWO_ROW_HEIGHT
+    } else {
+        DEFAULT_COMPONENT_HEIGHT
+    };
+
+    let transform = context.transform;
+
+    for row in &component.rows {
+        context.render_textual_key_value_component(
+            &[&row.label],
+            &row.value,
+            [width, row_height],
+            label_color,
+            value_color,
+            component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal,
+        );
+        context.translate(0.0, row_height);
+    }
+
+    context.transform = transform;
+}
@@ -0,0 +1,70 @@
+use crate::{
+    component::segment_time::State,
+    layout::{LayoutDirection, LayoutState},
+    rendering::{
+        Backend, RenderContext, BOTH_PADDINGS, DEFAULT_TEXT_SIZE, PADDING, TEXT_ALIGN_BOTTOM,
+        TEXT_ALIGN_TOP,
+    },
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    layout_state: &LayoutState,
+) {
+    context.render_rectangle([0.0, 0.0], [width, height], &component.background);
+
+    let display_two_rows =
+        component.display_two_rows || layout_state.direction == LayoutDirection::Horizontal;
+    let y = height + TEXT_ALIGN_BOTTOM;
+
+    // The comparison's segment time and the best segment time are rendered to
+    // the right of the live segment time, without the live segment time's
+    // color.
+    let mut right_x = width - PADDING;
+    for time in [&component.best_time, &component.comparison_time]
+        .iter()
+        .filter_map(|t| t.as_ref())
+    {
+        let left_x = context.render_numbers(
+            time,
+            [right_x, y],
+            DEFAULT_TEXT_SIZE,
+            [layout_state.text_color; 2],
+        );
+        right_x = context.render_numbers(
+            "/",
+            [left_x - PADDING, y],
+            DEFAULT_TEXT_SIZE,
+            [layout_state.text_color; 2],
+        ) - PADDING;
+    }
+
+    let left_of_value_x = context.render_numbers(
+        &component.time,
+        [right_x, y],
+        DEFAULT_TEXT_SIZE,
+        [component.visual_color; 2],
+    );
+
+    let end_x = if display_two_rows {
+        width
+    } else {
+        left_of_value_x
+    };
+    let label = context.choose_abbreviation(
+        [component.text.as_str(), "Segment Time", "Seg. Time"]
+            .iter()
+            .cloned(),
+        DEFAULT_TEXT_SIZE,
+        end_x - BOTH_PADDINGS,
+    );
+    context.render_text_ellipsis(
+        label,
+        [PADDING, TEXT_ALIGN_TOP],
+        DEFAULT_TEXT_SIZE,
+        [component.label_color.unwrap_or(layout_state.text_color); 2],
+        end_x - PADDING,
+    );
+}
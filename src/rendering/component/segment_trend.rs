@@ -0,0 +1,90 @@
+use {
+    crate::{
+        component::segment_trend::{Point, State},
+        layout::LayoutState,
+        rendering::{
+            mesh::{fill_builder, stroke_builder},
+            Backend, Mesh, RenderContext,
+        },
+        settings::{Color, Gradient},
+    },
+    lyon::tessellation::{
+        basic_shapes::{fill_circle, stroke_polyline},
+        FillOptions, StrokeOptions,
+    },
+};
+
+pub(in crate::rendering) fn render(
+    context: &mut RenderContext<'_, impl Backend>,
+    [width, height]: [f32; 2],
+    component: &State,
+    _layout_state: &LayoutState,
+) {
+    let old_transform = context.transform;
+    context.scale(height);
+    let width = width / height;
+
+    const LINE_WIDTH: f32 = 0.025;
+    const CIRCLE_RADIUS: f32 = 0.025;
+
+    context.render_rectangle(
+        [0.0, 0.0],
+        [width, 1.0],
+        &Gradient::Plain(component.background_color),
+    );
+
+    let mut mesh = Mesh::new();
+
+    for point in &component.times {
+        mesh.clear();
+
+        fill_circle(
+            [width * point.x, point.y].into(),
+            CIRCLE_RADIUS,
+            &FillOptions::tolerance(0.005).with_normals(false),
+            &mut fill_builder(&mut mesh),
+        )
+        .unwrap();
+
+        let circle_mesh = context.create_mesh(&mesh);
+        context.render_mesh(&circle_mesh, component.times_color);
+        context.free_mesh(circle_mesh);
+    }
+
+    for &(points, color) in &[
+        (&component.best, component.best_color),
+        (&component.rolling, component.rolling_color),
+        (&component.trend, component.trend_color),
+    ] {
+        render_line(context, &mut mesh, width, points, color, LINE_WIDTH);
+    }
+
+    context.transform = old_transform;
+}
+
+fn render_line(
+    context: &mut RenderContext<'_, impl Backend>,
+    mesh: &mut Mesh,
+    width: f32,
+    points: &[Point],
+    color: Color,
+    line_width: f32,
+) {
+    if points.len() < 2 {
+        return;
+    }
+
+    mesh.clear();
+
+    stroke_polyline(
+        points.iter().map(|p| [width * p.x, p.y].into()),
+        false,
+        &StrokeOptions::default().with_line_width(line_width),
+        &mut stroke_builder(mesh),
+    )
+    .unwrap();
+
+    let line_mesh = context.create_mesh(mesh);
+    context.render_mesh(&line_mesh, color);
+    context.free_mesh(line_mesh);
+}
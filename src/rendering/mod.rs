@@ -195,6 +195,50 @@ struct IconCache<T> {
     game_icon: Option<Icon<T>>,
     split_icons: Vec<Option<Icon<T>>>,
     detailed_timer_icon: Option<Icon<T>>,
+    images: Vec<CachedImage<T>>,
+}
+
+/// The image of an Image Component. The images are cached by their IDs, so
+/// they stay associated with the right component, even if the components are
+/// reordered, removed or hidden.
+struct CachedImage<T> {
+    image_id: usize,
+    icon: Option<Icon<T>>,
+    is_used: bool,
+}
+
+impl<T> IconCache<T> {
+    fn image(&mut self, image_id: usize) -> &mut Option<Icon<T>> {
+        let index = match self.images.iter().position(|i| i.image_id == image_id) {
+            Some(index) => index,
+            None => {
+                self.images.push(CachedImage {
+                    image_id,
+                    icon: None,
+                    is_used: false,
+                });
+                self.images.len() - 1
+            }
+        };
+        let image = &mut self.images[index];
+        image.is_used = true;
+        &mut image.icon
+    }
+
+    /// Frees the images that haven't been rendered since the last time this
+    /// was called. If their components are shown again, they provide their
+    /// images again as well.
+    fn free_unused_images<B: Backend<Texture = T>>(&mut self, backend: &mut B) {
+        let mut index = 0;
+        while index < self.images.len() {
+            if self.images[index].is_used {
+                self.images[index].is_used = false;
+                index += 1;
+            } else if let Some(icon) = self.images.swap_remove(index).icon {
+                backend.free_texture(icon.texture);
+            }
+        }
+    }
 }
 
 impl<M, T> Default for Renderer<M, T> {
@@ -216,6 +260,7 @@ impl<M, T> Renderer<M, T> {
                 game_icon: None,
                 split_icons: Vec::new(),
                 detailed_timer_icon: None,
+                images: Vec::new(),
             },
             cached_size: None,
         }
@@ -306,6 +351,8 @@ impl<M, T> Renderer<M, T> {
             // current component in the Component Coordinate Space.
             context.translate(0.0, height);
         }
+
+        self.icons.free_unused_images(context.backend);
     }
 
     fn render_horizontal<B: Backend<Mesh = M, Texture = T>>(
@@ -381,6 +428,8 @@ impl<M, T> Renderer<M, T> {
             // current component in the Component Coordinate Space.
             context.translate(width, 0.0);
         }
+
+        self.icons.free_unused_images(context.backend);
     }
 }
 
@@ -409,6 +458,15 @@ fn render_component<B: Backend>(
             state,
             &mut icons.detailed_timer_icon,
         ),
+        ComponentState::Clock(component) => {
+            component::clock::render(context, dim, component, state)
+        }
+        ComponentState::ComparisonTable(component) => {
+            component::comparison_table::render(context, dim, component, state)
+        }
+        ComponentState::Counter(component) => {
+            component::counter::render(context, dim, component, state)
+        }
         ComponentState::CurrentComparison(component) => {
             component::current_comparison::render(context, dim, component, state)
         }
@@ -418,6 +476,10 @@ fn render_component<B: Backend>(
         ComponentState::Delta(component) => {
             component::delta::render(context, dim, component, state)
         }
+        ComponentState::Image(component) => {
+            let image = icons.image(component.image_id);
+            component::image::render(context, dim, component, state, image)
+        }
         ComponentState::PbChance(component) => {
             component::pb_chance::render(context, dim, component, state)
         }
@@ -427,6 +489,21 @@ fn render_component<B: Backend>(
         ComponentState::PreviousSegment(component) => {
             component::previous_segment::render(context, dim, component, state)
         }
+        ComponentState::RunHistory(component) => {
+            component::run_history::render(context, dim, component, state)
+        }
+        ComponentState::RunMetadata(component) => {
+            component::run_metadata::render(context, dim, component, state)
+        }
+        ComponentState::SegmentPotential(component) => {
+            component::segment_potential::render(context, dim, component, state)
+        }
+        ComponentState::SegmentTime(component) => {
+            component::segment_time::render(context, dim, component, state)
+        }
+        ComponentState::SegmentTrend(component) => {
+            component::segment_trend::render(context, dim, component, state)
+        }
         ComponentState::Separator(component) => {
             component::separator::render(context, dim, component, state)
         }
@@ -437,6 +514,9 @@ fn render_component<B: Backend>(
         ComponentState::TotalPlaytime(component) => {
             component::total_playtime::render(context, dim, component, state)
         }
+        ComponentState::FinishTime(component) => {
+            component::finish_time::render(context, dim, component, state)
+        }
         ComponentState::Graph(component) => {
             component::graph::render(context, dim, component, state)
         }
@@ -536,6 +616,10 @@ impl<B: Backend> RenderContext<'_, B> {
             height = new_height;
         }
 
+        self.render_image([x, y], [width, height], icon);
+    }
+
+    fn render_image(&mut self, [x, y]: Pos, [width, height]: Pos, icon: &Icon<B::Texture>) {
         let transform = self
             .transform
             .pre_translate([x, y].into())
@@ -821,9 +905,13 @@ fn decode_color(color: &Color) -> [f32; 4] {
 fn component_width(component: &ComponentState) -> f32 {
     match component {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Clock(_) => 6.0,
+        ComponentState::ComparisonTable(_) => 7.0,
+        ComponentState::Counter(_) => 6.0,
         ComponentState::CurrentComparison(_) => 6.0,
         ComponentState::CurrentPace(_) => 6.0,
         ComponentState::Delta(_) => 6.0,
+        ComponentState::Image(_) => 7.0,
         ComponentState::PbChance(_) => 6.0,
         ComponentState::PossibleTimeSave(_) => 6.0,
         ComponentState::PreviousSegment(_) => 6.0,
@@ -838,7 +926,13 @@ fn component_width(component: &ComponentState) -> f32 {
         }
         ComponentState::DetailedTimer(_) => 7.0,
         ComponentState::Timer(_) => 8.25,
+        ComponentState::FinishTime(_) => 6.0,
         ComponentState::Graph(_) => 7.0,
+        ComponentState::RunHistory(_) => 7.0,
+        ComponentState::RunMetadata(_) => 6.0,
+        ComponentState::SegmentPotential(_) => 6.0,
+        ComponentState::SegmentTime(_) => 6.0,
+        ComponentState::SegmentTrend(_) => 7.0,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
     }
 }
@@ -846,6 +940,17 @@ fn component_width(component: &ComponentState) -> f32 {
 fn component_height(component: &ComponentState) -> f32 {
     match component {
         ComponentState::BlankSpace(state) => state.size as f32 * PSEUDO_PIXELS,
+        ComponentState::Clock(state) => state.rows.len().max(1) as f32 * DEFAULT_COMPONENT_HEIGHT,
+        ComponentState::ComparisonTable(state) => {
+            (state.rows.len() + 1) as f32 * DEFAULT_COMPONENT_HEIGHT
+        }
+        ComponentState::Counter(state) => {
+            if state.display_two_rows {
+                TWO_ROW_HEIGHT
+            } else {
+                DEFAULT_COMPONENT_HEIGHT
+            }
+        }
         ComponentState::CurrentComparison(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
@@ -868,8 +973,40 @@ fn component_height(component: &ComponentState) -> f32 {
                 DEFAULT_COMPONENT_HEIGHT
             }
         }
+        ComponentState::FinishTime(state) => {
+            if state.display_two_rows {
+                TWO_ROW_HEIGHT
+            } else {
+                DEFAULT_COMPONENT_HEIGHT
+            }
+        }
         ComponentState::Graph(state) => state.height as f32 * PSEUDO_PIXELS,
+        ComponentState::RunHistory(state) => state.height as f32 * PSEUDO_PIXELS,
+        ComponentState::RunMetadata(state) => {
+            state.rows.len().max(1) as f32
+                * if state.display_two_rows {
+                    TWO_ROW_HEIGHT
+                } else {
+                    DEFAULT_COMPONENT_HEIGHT
+                }
+        }
+        ComponentState::SegmentPotential(state) => {
+            if state.display_two_rows {
+                TWO_ROW_HEIGHT
+            } else {
+                DEFAULT_COMPONENT_HEIGHT
+            }
+        }
+        ComponentState::SegmentTime(state) => {
+            if state.display_two_rows {
+                TWO_ROW_HEIGHT
+            } else {
+                DEFAULT_COMPONENT_HEIGHT
+            }
+        }
+        ComponentState::SegmentTrend(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::Separator(_) => SEPARATOR_THICKNESS,
+        ComponentState::Image(state) => state.height as f32 * PSEUDO_PIXELS,
         ComponentState::PbChance(state) => {
             if state.display_two_rows {
                 TWO_ROW_HEIGHT
@@ -4,8 +4,11 @@
 //! split, the combined segment time might be faster than the sum of the
 //! individual best segments. The Sum of Best Cleaner will point out all
 //! occurrences of this and allows you to delete them individually if any of
-//! them seem wrong.
+//! them seem wrong. Afterwards, segment times that are far outside of the
+//! segment's usual segment times are pointed out as well, as they are likely
+//! caused by missed or double splits.
 
+use crate::analysis::history_helper;
 use crate::analysis::sum_of_segments::{best, track_branch, Prediction};
 use crate::timing::formatter::{Short, TimeFormatter};
 use crate::{Attempt, Run, Segment, TimeSpan, TimingMethod};
@@ -18,7 +21,10 @@ use std::mem::replace;
 /// split, whenever you get to the next split, the combined segment time might
 /// be faster than the sum of the individual best segments. The Sum of Best
 /// Cleaner will point out all occurrences of this and allows you to delete them
-/// individually if any of them seem wrong.
+/// individually if any of them seem wrong. Afterwards, segment times that are
+/// far outside of the segment's usual segment times are pointed out as well, as
+/// they are likely caused by missed or double splits. These are detected among
+/// all the attempts, regardless of the Run's History Filter.
 pub struct SumOfBestCleaner<'r> {
     run: &'r mut Run,
     predictions: Vec<Option<Prediction>>,
@@ -31,6 +37,7 @@ enum State {
     WithTimingMethod(TimingMethod),
     IteratingRun(IteratingRunState),
     IteratingHistory(IteratingHistoryState),
+    DetectingOutliers(DetectingOutliersState),
 }
 
 struct IteratingRunState {
@@ -44,6 +51,27 @@ struct IteratingHistoryState {
     skip_count: usize,
 }
 
+struct DetectingOutliersState {
+    method: TimingMethod,
+    segment_index: usize,
+    outliers: Vec<Outlier>,
+}
+
+#[derive(Copy, Clone)]
+struct Outlier {
+    run_index: i32,
+    median: TimeSpan,
+    confidence: f64,
+}
+
+/// The modified z-score a segment time needs to exceed in order to be
+/// considered an outlier.
+const OUTLIER_THRESHOLD: f64 = 3.5;
+
+/// The minimum amount of segment times a segment needs to have for outliers to
+/// be detected.
+const OUTLIER_MIN_SAMPLES: usize = 5;
+
 /// Describes a potential clean up that could be applied. You can use the
 /// Display implementation to print out the details of this potential clean up.
 /// A potential clean up can then be turned into an actual clean up in order to
@@ -55,6 +83,7 @@ pub struct PotentialCleanUp<'r> {
     combined_sum_of_best: Option<TimeSpan>,
     attempt: &'r Attempt,
     method: TimingMethod,
+    outlier: Option<Outlier>,
     clean_up: CleanUp,
 }
 
@@ -88,7 +117,19 @@ impl fmt::Display for PotentialCleanUp<'_> {
 
         write!(f, " and {}", self.ending_segment.name())?;
 
-        if let Some(combined) = self.combined_sum_of_best {
+        if let Some(outlier) = self.outlier {
+            write!(
+                f,
+                ", which is much {} than the usual segment time of {} ({:.0}% confidence)",
+                if self.time_between < outlier.median {
+                    "faster"
+                } else {
+                    "slower"
+                },
+                short.format(outlier.median),
+                100.0 * outlier.confidence,
+            )?;
+        } else if let Some(combined) = self.combined_sum_of_best {
             write!(
                 f,
                 ", which is faster than the Combined Best Segments of {}",
@@ -111,6 +152,25 @@ impl fmt::Display for PotentialCleanUp<'_> {
     }
 }
 
+impl PotentialCleanUp<'_> {
+    /// Returns whether the potential clean up is about a segment time that is
+    /// far outside of the segment's usual segment times, rather than one that
+    /// is faster than the Combined Best Segments.
+    pub fn is_outlier(&self) -> bool {
+        self.outlier.is_some()
+    }
+
+    /// Returns how confident the Sum of Best Cleaner is that the segment time
+    /// is inaccurate, as a value from 0 (0%) to 1 (100%). Segment times that
+    /// are faster than the Combined Best Segments are always reported with a
+    /// confidence of 1. For outliers, the confidence starts at 0.5 and grows
+    /// towards 1 the further away the segment time is from the usual segment
+    /// times.
+    pub fn confidence(&self) -> f64 {
+        self.outlier.map_or(1.0, |o| o.confidence)
+    }
+}
+
 impl From<PotentialCleanUp<'_>> for CleanUp {
     fn from(potential: PotentialCleanUp<'_>) -> Self {
         potential.clean_up
@@ -164,7 +224,11 @@ impl<'r> SumOfBestCleaner<'r> {
                     } else if state.method == TimingMethod::RealTime {
                         State::WithTimingMethod(TimingMethod::GameTime)
                     } else {
-                        State::Done
+                        State::DetectingOutliers(DetectingOutliersState::new(
+                            self.run,
+                            TimingMethod::RealTime,
+                            0,
+                        ))
                     };
                 }
                 State::IteratingHistory(state) => {
@@ -209,6 +273,31 @@ impl<'r> SumOfBestCleaner<'r> {
                         segment_index: state.parent.segment_index + 1,
                     });
                 }
+                State::DetectingOutliers(mut state) => {
+                    while let Some(outlier) = state.outliers.pop() {
+                        if let Some(question) =
+                            check_outlier(self.run, state.segment_index, outlier, state.method)
+                        {
+                            self.state = State::DetectingOutliers(state);
+                            return Some(question);
+                        }
+                    }
+                    self.state = if state.segment_index + 1 < self.run.len() {
+                        State::DetectingOutliers(DetectingOutliersState::new(
+                            self.run,
+                            state.method,
+                            state.segment_index + 1,
+                        ))
+                    } else if state.method == TimingMethod::RealTime {
+                        State::DetectingOutliers(DetectingOutliersState::new(
+                            self.run,
+                            TimingMethod::GameTime,
+                            0,
+                        ))
+                    } else {
+                        State::Done
+                    };
+                }
             };
         }
     }
@@ -249,6 +338,7 @@ fn check_prediction<'a>(
                         .find(|attempt| attempt.index() == run_index)
                         .expect("The attempt has to exist"),
                     method,
+                    outlier: None,
                     clean_up: CleanUp {
                         ending_index,
                         run_index,
@@ -260,6 +350,102 @@ fn check_prediction<'a>(
     None
 }
 
+impl DetectingOutliersState {
+    fn new(run: &Run, method: TimingMethod, segment_index: usize) -> Self {
+        let mut outliers = if segment_index < run.len() {
+            find_outliers(run, segment_index, method)
+        } else {
+            Vec::new()
+        };
+        // The outliers are popped off the end, so they need to be reversed to
+        // be reported in the order they were achieved in.
+        outliers.reverse();
+        Self {
+            method,
+            segment_index,
+            outliers,
+        }
+    }
+}
+
+/// Finds all the segment times of the segment that are outliers. A segment
+/// time is considered an outlier if its modified z-score, which is based on
+/// the median and the median absolute deviation of all the segment times,
+/// exceeds the threshold. Combined segment times are not considered. Unlike
+/// the other analyses of the Segment History, this deliberately ignores the
+/// History Filter, as the Sum of Best Cleaner cleans up the whole Segment
+/// History, including the attempts the filter excludes.
+fn find_outliers(run: &Run, segment_index: usize, method: TimingMethod) -> Vec<Outlier> {
+    let times = history_helper::segment_times(run, None, segment_index, method)
+        .map(|(id, time)| (id, time.total_seconds()))
+        .collect::<Vec<_>>();
+
+    if times.len() < OUTLIER_MIN_SAMPLES {
+        return Vec::new();
+    }
+
+    let mut values = times.iter().map(|&(_, t)| t).collect::<Vec<_>>();
+    let median = history_helper::median(&mut values);
+
+    // The median absolute deviation reuses the buffer of the values.
+    for (value, &(_, time)) in values.iter_mut().zip(&times) {
+        *value = (time - median).abs();
+    }
+    let deviation = history_helper::median(&mut values);
+
+    if deviation <= 0.0 {
+        return Vec::new();
+    }
+
+    times
+        .into_iter()
+        .filter_map(|(run_index, time)| {
+            let z_score = (0.6745 * (time - median) / deviation).abs();
+            if z_score > OUTLIER_THRESHOLD {
+                Some(Outlier {
+                    run_index,
+                    median: TimeSpan::from_seconds(median),
+                    confidence: 1.0 - 0.5 * OUTLIER_THRESHOLD / z_score,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn check_outlier(
+    run: &Run,
+    segment_index: usize,
+    outlier: Outlier,
+    method: TimingMethod,
+) -> Option<PotentialCleanUp<'_>> {
+    // The segment time may already have been removed by a previous clean up.
+    let time_between = run
+        .segment(segment_index)
+        .segment_history()
+        .get(outlier.run_index)?[method]?;
+
+    let attempt = run
+        .attempt_history()
+        .iter()
+        .find(|attempt| attempt.index() == outlier.run_index)?;
+
+    Some(PotentialCleanUp {
+        starting_segment: segment_index.checked_sub(1).map(|i| run.segment(i)),
+        ending_segment: run.segment(segment_index),
+        time_between,
+        combined_sum_of_best: None,
+        attempt,
+        method,
+        outlier: Some(outlier),
+        clean_up: CleanUp {
+            ending_index: segment_index,
+            run_index: outlier.run_index,
+        },
+    })
+}
+
 fn next_timing_method(run: &Run, predictions: &mut Vec<Option<Prediction>>, method: TimingMethod) {
     let segments = run.segments();
 
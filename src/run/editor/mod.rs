@@ -4,11 +4,12 @@
 //! current state of the editor as state objects that can be visualized by any
 //! kind of User Interface.
 
-use super::{ComparisonError, ComparisonResult};
+use super::{ComparisonError, ComparisonGeneratorError, ComparisonResult, HistoryFilter};
 use crate::timing::ParseError as ParseTimeSpanError;
 use crate::{
     comparison,
-    settings::{CachedImageId, Image},
+    run::IndividualLevelBest,
+    settings::{CachedImageId, Image, SettingsDescription, Value},
     Run, Segment, Time, TimeSpan, TimingMethod,
 };
 use odds::slice::rotate_left;
@@ -22,11 +23,13 @@ mod segment_row;
 mod state;
 #[cfg(test)]
 mod tests;
+pub mod validation;
 
 pub use self::cleaning::SumOfBestCleaner;
 pub use self::fuzzy_list::FuzzyList;
 pub use self::segment_row::SegmentRow;
 pub use self::state::{Buttons as ButtonsState, Segment as SegmentState, State};
+pub use self::validation::{Fix, FixError, Issue};
 
 /// Describes an Error that occurred while parsing a time.
 #[derive(Debug, snafu::Snafu, derive_more::From)]
@@ -424,6 +427,7 @@ impl Editor {
             segment.segment_history_mut().insert(x, Default::default());
         }
         self.run.segments_mut().insert(active_segment, segment);
+        self.fix_individual_level_bests(|b| b.segment_inserted(active_segment));
 
         self.select_only(active_segment);
 
@@ -449,6 +453,7 @@ impl Editor {
             segment.segment_history_mut().insert(x, Default::default());
         }
         self.run.segments_mut().insert(next_segment, segment);
+        self.fix_individual_level_bests(|b| b.segment_inserted(next_segment));
 
         self.select_only(next_segment);
 
@@ -548,6 +553,7 @@ impl Editor {
                 let segment_index = i - removed;
                 self.fix_after_deletion(segment_index);
                 self.run.segments_mut().remove(segment_index);
+                self.fix_individual_level_bests(|b| b.segment_removed(segment_index));
                 removed += 1;
             }
         }
@@ -569,7 +575,23 @@ impl Editor {
         self.fix();
     }
 
+    fn fix_individual_level_bests<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut IndividualLevelBest) -> bool,
+    {
+        // Individual Level Bests that are affected by a change to their
+        // segments don't describe a meaningful time anymore.
+        let bests = self.run.individual_level_bests_mut();
+        let retained = bests
+            .drain(..)
+            .filter_map(|mut b| if f(&mut b) { Some(b) } else { None })
+            .collect();
+        *bests = retained;
+    }
+
     fn switch_segments(&mut self, index: usize) {
+        self.fix_individual_level_bests(|b| b.segments_swapped(index));
+
         let max_index = self.run.max_attempt_history_index().unwrap_or(0);
         let min_index = self.run.min_segment_history_index().unwrap();
 
@@ -770,6 +792,79 @@ impl Editor {
         Ok(())
     }
 
+    /// Enables or disables the Comparison Generator with the name provided. A
+    /// Comparison Generator that gets enabled starts out with its default
+    /// settings. It can't be enabled if there's a custom comparison with the
+    /// same name.
+    pub fn set_comparison_generator_enabled(
+        &mut self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<(), ComparisonGeneratorError> {
+        self.run.set_comparison_generator_enabled(name, enabled)?;
+        self.raise_run_edited();
+        Ok(())
+    }
+
+    /// Accesses a generic description of the settings available for the
+    /// Comparison Generator with the name provided and their current values.
+    /// If the Comparison Generator is not enabled, `None` is returned.
+    pub fn comparison_generator_settings_description(
+        &self,
+        name: &str,
+    ) -> Option<SettingsDescription> {
+        self.run
+            .comparison_generators()
+            .iter()
+            .find(|g| g.name() == name)
+            .map(|g| g.settings_description())
+    }
+
+    /// Sets a setting's value of the Comparison Generator with the name
+    /// provided by its index to the given value. The comparison is regenerated
+    /// afterwards. If the Comparison Generator is not enabled, nothing happens.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the type of the value to be set is not compatible with
+    /// the type of the setting's value. A panic can also occur if the index of
+    /// the setting provided is out of bounds.
+    pub fn set_comparison_generator_value(&mut self, name: &str, index: usize, value: Value) {
+        if let Some(generator) = self
+            .run
+            .comparison_generators_mut()
+            .iter_mut()
+            .find(|g| g.name() == name)
+        {
+            generator.set_value(index, value);
+            self.run.regenerate_comparisons();
+            self.raise_run_edited();
+        }
+    }
+
+    /// Accesses the History Filter that restricts which attempts are
+    /// considered by the history based comparisons and analysis.
+    pub fn history_filter(&self) -> &HistoryFilter {
+        self.run.history_filter()
+    }
+
+    /// Sets the History Filter that restricts which attempts are considered by
+    /// the history based comparisons and analysis. The comparisons are
+    /// regenerated afterwards.
+    pub fn set_history_filter(&mut self, history_filter: HistoryFilter) {
+        self.run.set_history_filter(history_filter);
+        self.run.regenerate_comparisons();
+        self.raise_run_edited();
+    }
+
+    /// Flags or unflags the attempt with the index provided to be excluded by
+    /// the History Filter. The comparisons are regenerated afterwards.
+    pub fn set_attempt_excluded(&mut self, index: i32, excluded: bool) {
+        let mut history_filter = self.run.history_filter().clone();
+        history_filter.set_attempt_excluded(index, excluded);
+        self.set_history_filter(history_filter);
+    }
+
     /// Reorders the custom comparisons by moving the comparison with the
     /// `src_index` specified to the `dst_index` specified. Returns `Err(())` if
     /// one of the indices is invalid. The indices are based on the
@@ -874,6 +969,25 @@ impl Editor {
     pub fn clean_sum_of_best(&mut self) -> SumOfBestCleaner<'_> {
         SumOfBestCleaner::new(&mut self.run)
     }
+
+    /// Checks the Run for inconsistencies, like Best Segment Times that are
+    /// slower than the Personal Best's segment times or comparisons with
+    /// decreasing split times. Nothing is modified by this. Each of the issues
+    /// may provide a suggested fix that can be applied with `apply_fix`.
+    pub fn validate(&self) -> Vec<Issue> {
+        validation::validate(&self.run)
+    }
+
+    /// Applies a fix suggested by one of the issues found by `validate`. Only
+    /// the single fix is applied, so other issues remain until they are fixed
+    /// individually as well. If the fix doesn't resolve any of the issues the
+    /// Run currently has, an error is returned and nothing is modified.
+    pub fn apply_fix(&mut self, fix: Fix) -> Result<(), FixError> {
+        fix.apply(&mut self.run)?;
+        self.update_segment_list();
+        self.times_modified();
+        Ok(())
+    }
 }
 
 fn parse_positive<S>(time: S) -> Result<Option<TimeSpan>, ParseError>
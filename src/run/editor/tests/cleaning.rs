@@ -0,0 +1,57 @@
+use super::super::cleaning::SumOfBestCleaner;
+use crate::tests_helper::{create_run, span};
+use crate::{Run, Time};
+
+fn run(segment_times: &[[f64; 2]]) -> Run {
+    let mut run = create_run(&["A", "B"]);
+    for (i, times) in segment_times.iter().enumerate() {
+        let index = i as i32 + 1;
+        run.add_attempt_with_index(
+            Time::new().with_game_time(Some(span(times[0] + times[1]))),
+            index,
+            None,
+            None,
+            None,
+        );
+        for (segment, &time) in run.segments_mut().iter_mut().zip(times) {
+            segment
+                .segment_history_mut()
+                .insert(index, Time::new().with_game_time(Some(span(time))));
+        }
+    }
+    run
+}
+
+#[test]
+fn reports_outliers() {
+    let mut run = run(&[
+        [10.0, 10.0],
+        [10.5, 9.9],
+        [9.8, 10.1],
+        [10.2, 9.9],
+        [10.1, 10.2],
+        [30.0, 10.0],
+    ]);
+
+    let mut cleaner = SumOfBestCleaner::new(&mut run);
+    let clean_up = {
+        let potential = cleaner.next_potential_clean_up().unwrap();
+        assert!(potential.is_outlier());
+        assert!(potential.confidence() > 0.9);
+        assert!(potential.to_string().contains("much slower"));
+        potential.into()
+    };
+    cleaner.apply(clean_up);
+    assert!(cleaner.next_potential_clean_up().is_none());
+
+    assert!(run.segment(0).segment_history().get(6).is_none());
+    assert!(run.segment(1).segment_history().get(6).is_some());
+}
+
+#[test]
+fn needs_enough_segment_times_for_outliers() {
+    let mut run = run(&[[10.0, 10.0], [10.5, 9.9], [30.0, 10.0]]);
+    assert!(SumOfBestCleaner::new(&mut run)
+        .next_potential_clean_up()
+        .is_none());
+}
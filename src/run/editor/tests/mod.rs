@@ -1,8 +1,10 @@
 use super::Editor;
 use crate::{Run, Segment};
 
+mod cleaning;
 mod dissociate_run;
 mod mark_as_modified;
+mod validation;
 
 #[test]
 fn new_best_segment() {
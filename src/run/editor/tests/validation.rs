@@ -0,0 +1,189 @@
+use super::super::{Editor, Fix, FixError, Issue};
+use crate::{Run, Segment, Time, TimeSpan, TimingMethod};
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+fn base() -> Run {
+    let mut run = Run::new();
+    for (name, split_time, best_segment) in
+        &[("A", 10.0, 8.0), ("B", 25.0, 12.0), ("C", 40.0, 14.0)]
+    {
+        let mut segment = Segment::new(*name);
+        segment.set_personal_best_split_time(time(*split_time));
+        segment.set_best_segment_time(time(*best_segment));
+        run.push_segment(segment);
+    }
+    run
+}
+
+#[test]
+fn consistent_run_has_no_issues() {
+    let editor = Editor::new(base()).unwrap();
+    assert_eq!(editor.validate(), []);
+}
+
+#[test]
+fn best_segment_slower_than_personal_best() {
+    let mut run = base();
+    run.segment_mut(1).set_best_segment_time(time(16.0));
+    let mut editor = Editor::new(run).unwrap();
+
+    let issues = editor.validate();
+    assert_eq!(
+        issues,
+        [Issue::BestSegmentSlowerThanPersonalBest {
+            segment_index: 1,
+            method: TimingMethod::RealTime,
+            best_segment_time: TimeSpan::from_seconds(16.0),
+            personal_best_segment_time: TimeSpan::from_seconds(15.0),
+        }]
+    );
+
+    let fix = issues[0].fix(editor.run()).unwrap();
+    editor.apply_fix(fix).unwrap();
+
+    assert_eq!(editor.run().segment(1).best_segment_time(), time(15.0));
+    assert_eq!(editor.validate(), []);
+}
+
+#[test]
+fn history_without_attempt() {
+    let mut run = base();
+    run.segment_mut(0)
+        .segment_history_mut()
+        .insert(3, time(9.0));
+    let mut editor = Editor::new(run).unwrap();
+
+    let issues = editor.validate();
+    assert_eq!(
+        issues,
+        [Issue::HistoryWithoutAttempt {
+            segment_index: 0,
+            attempt_index: 3,
+        }]
+    );
+
+    let fix = issues[0].fix(editor.run()).unwrap();
+    editor.apply_fix(fix).unwrap();
+    assert_eq!(editor.run().segment(0).segment_history().get(3), None);
+}
+
+#[test]
+fn personal_best_is_imported_from_faster_attempt() {
+    let mut run = base();
+    run.add_attempt_with_index(time(38.0), 1, None, None, None);
+    for (segment, &segment_time) in run.segments_mut().iter_mut().zip(&[9.0, 15.0, 14.0]) {
+        segment.segment_history_mut().insert(1, time(segment_time));
+    }
+    let mut editor = Editor::new(run).unwrap();
+
+    let issues = editor.validate();
+    assert_eq!(
+        issues,
+        [Issue::PersonalBestMismatch {
+            method: TimingMethod::RealTime,
+            personal_best_time: Some(TimeSpan::from_seconds(40.0)),
+            attempt_index: 1,
+            attempt_time: TimeSpan::from_seconds(38.0),
+        }]
+    );
+
+    let fix = issues[0].fix(editor.run()).unwrap();
+    assert_eq!(
+        fix,
+        Fix::ImportPersonalBestFromAttempt {
+            attempt_index: 1,
+            method: TimingMethod::RealTime,
+        }
+    );
+    editor.apply_fix(fix).unwrap();
+
+    let split_times = editor
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.personal_best_split_time())
+        .collect::<Vec<_>>();
+    assert_eq!(split_times, [time(9.0), time(24.0), time(38.0)]);
+}
+
+#[test]
+fn decreasing_comparison_and_duplicate_names() {
+    let mut run = base();
+    run.add_custom_comparison("Race").unwrap();
+    run.segment_mut(0).set_name("b");
+    *run.segment_mut(0).comparison_mut("Race") = time(20.0);
+    *run.segment_mut(1).comparison_mut("Race") = time(18.0);
+    *run.segment_mut(2).comparison_mut("Race") = time(30.0);
+    let mut editor = Editor::new(run).unwrap();
+
+    let issues = editor.validate();
+    assert_eq!(issues.len(), 2);
+
+    for issue in issues {
+        let fix = issue.fix(editor.run()).unwrap();
+        editor.apply_fix(fix).unwrap();
+    }
+
+    assert_eq!(editor.run().segment(1).comparison("Race"), time(20.0));
+    assert_eq!(editor.run().segment(1).name(), "B (2)");
+    assert_eq!(editor.validate(), []);
+}
+
+#[test]
+fn zero_personal_best_segment_time_is_not_a_best_segment() {
+    let mut run = base();
+    run.segment_mut(1).set_personal_best_split_time(time(10.0));
+    let editor = Editor::new(run).unwrap();
+
+    let issues = editor.validate();
+    assert_eq!(
+        issues[0],
+        Issue::BestSegmentSlowerThanPersonalBest {
+            segment_index: 1,
+            method: TimingMethod::RealTime,
+            best_segment_time: TimeSpan::from_seconds(12.0),
+            personal_best_segment_time: TimeSpan::zero(),
+        }
+    );
+    assert_eq!(issues[0].fix(editor.run()), None);
+}
+
+#[test]
+fn outdated_fixes_are_rejected() {
+    let mut run = base();
+    run.segment_mut(1).set_best_segment_time(time(16.0));
+    let mut editor = Editor::new(run).unwrap();
+
+    let fix = editor.validate()[0].fix(editor.run()).unwrap();
+    editor.apply_fix(fix.clone()).unwrap();
+    assert_eq!(editor.apply_fix(fix), Err(FixError::NotApplicable));
+
+    let invalid = [
+        Fix::SetBestSegmentTime {
+            segment_index: 3,
+            method: TimingMethod::RealTime,
+            time: None,
+        },
+        Fix::RenameSegment {
+            segment_index: 5,
+            name: String::from("D"),
+        },
+    ];
+    for fix in &invalid {
+        assert_eq!(
+            editor.apply_fix(fix.clone()),
+            Err(FixError::SegmentIndexOutOfBounds)
+        );
+    }
+
+    let unrelated = Fix::SetBestSegmentTime {
+        segment_index: 0,
+        method: TimingMethod::RealTime,
+        time: Some(TimeSpan::zero()),
+    };
+    assert_eq!(editor.apply_fix(unrelated), Err(FixError::NotApplicable));
+    assert_eq!(editor.run().segment(0).best_segment_time(), time(8.0));
+}
@@ -0,0 +1,604 @@
+//! The validation module provides a consistency check for Run objects. While
+//! `Run::fix_splits` silently repairs some problems, the validation reports all
+//! the inconsistencies it finds as a list of issues first. Most of the issues
+//! come with a suggested fix that the Run Editor can then apply individually.
+
+use crate::comparison::personal_best;
+use crate::timing::formatter::{Short, TimeFormatter};
+use crate::{Run, TimeSpan, TimingMethod};
+use std::fmt;
+
+/// Describes an inconsistency found in a Run. You can use the Display
+/// implementation to print out a description of the issue. If there's a
+/// suggested fix for the issue, it can be queried with `fix` and then applied
+/// with the Run Editor.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Issue {
+    /// The Best Segment Time of a segment is slower than the segment time of
+    /// the Personal Best.
+    BestSegmentSlowerThanPersonalBest {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The timing method the issue was found for.
+        method: TimingMethod,
+        /// The Best Segment Time stored in the segment.
+        best_segment_time: TimeSpan,
+        /// The segment time of the Personal Best.
+        personal_best_segment_time: TimeSpan,
+    },
+    /// The Best Segment Time of a segment is negative or zero.
+    NonPositiveBestSegmentTime {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The timing method the issue was found for.
+        method: TimingMethod,
+        /// The Best Segment Time stored in the segment.
+        time: TimeSpan,
+    },
+    /// A segment time in the Segment History is negative or zero.
+    NonPositiveHistoryTime {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The index of the attempt the segment time belongs to.
+        attempt_index: i32,
+        /// The timing method the issue was found for.
+        method: TimingMethod,
+        /// The segment time stored in the Segment History.
+        time: TimeSpan,
+    },
+    /// A segment time in the Segment History refers to an attempt that doesn't
+    /// exist in the Attempt History.
+    HistoryWithoutAttempt {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The index of the attempt that doesn't exist.
+        attempt_index: i32,
+    },
+    /// A finished attempt in the Attempt History is faster than the Personal
+    /// Best.
+    PersonalBestMismatch {
+        /// The timing method the issue was found for.
+        method: TimingMethod,
+        /// The final time of the Personal Best, if there is one.
+        personal_best_time: Option<TimeSpan>,
+        /// The index of the fastest finished attempt.
+        attempt_index: i32,
+        /// The final time of the fastest finished attempt.
+        attempt_time: TimeSpan,
+    },
+    /// A split time of a custom comparison is lower than the split time of a
+    /// segment before it.
+    DecreasingComparisonTime {
+        /// The name of the comparison.
+        comparison: String,
+        /// The index of the segment.
+        segment_index: usize,
+        /// The timing method the issue was found for.
+        method: TimingMethod,
+        /// The split time of the comparison at this segment.
+        time: TimeSpan,
+        /// The highest split time of the comparison before this segment.
+        previous_time: TimeSpan,
+    },
+    /// A segment has the same name as a segment before it. Names are compared
+    /// case insensitively. Segments without a name are never considered
+    /// duplicates.
+    DuplicateSegmentName {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The index of the first segment with the same name.
+        first_segment_index: usize,
+    },
+}
+
+/// Describes a fix that can be applied to a Run with the Run Editor in order to
+/// resolve an issue found by the validation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fix {
+    /// Sets the Best Segment Time of a segment for a timing method.
+    SetBestSegmentTime {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The timing method to modify.
+        method: TimingMethod,
+        /// The new Best Segment Time.
+        time: Option<TimeSpan>,
+    },
+    /// Removes the segment time of an attempt from a segment's Segment
+    /// History.
+    RemoveHistoryElement {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The index of the attempt to remove.
+        attempt_index: i32,
+    },
+    /// Replaces the Personal Best's split times of a timing method with the
+    /// split times of an attempt, reconstructed from the Segment Histories.
+    ImportPersonalBestFromAttempt {
+        /// The index of the attempt.
+        attempt_index: i32,
+        /// The timing method to modify.
+        method: TimingMethod,
+    },
+    /// Sets the split time of a custom comparison for a timing method.
+    SetComparisonTime {
+        /// The name of the comparison.
+        comparison: String,
+        /// The index of the segment.
+        segment_index: usize,
+        /// The timing method to modify.
+        method: TimingMethod,
+        /// The new split time.
+        time: TimeSpan,
+    },
+    /// Renames a segment.
+    RenameSegment {
+        /// The index of the segment.
+        segment_index: usize,
+        /// The new name of the segment.
+        name: String,
+    },
+}
+
+/// Describes an Error that occurred while applying a fix.
+#[derive(PartialEq, Debug, snafu::Snafu)]
+pub enum FixError {
+    /// The fix refers to a segment that doesn't exist.
+    SegmentIndexOutOfBounds,
+    /// The fix doesn't resolve any of the issues the Run currently has. This is
+    /// the case if the Run got modified since the fix was suggested.
+    NotApplicable,
+}
+
+/// Checks the Run for inconsistencies and returns all the issues that have
+/// been found. The issues are grouped by the kind of check that found them.
+pub fn validate(run: &Run) -> Vec<Issue> {
+    let mut issues = Vec::new();
+
+    for &method in &TimingMethod::all() {
+        check_best_segments(run, method, &mut issues);
+    }
+    for &method in &TimingMethod::all() {
+        check_best_segment_signs(run, method, &mut issues);
+    }
+    for &method in &TimingMethod::all() {
+        check_history_signs(run, method, &mut issues);
+    }
+    check_history_attempts(run, &mut issues);
+    for &method in &TimingMethod::all() {
+        check_personal_best(run, method, &mut issues);
+    }
+    for &method in &TimingMethod::all() {
+        check_comparisons(run, method, &mut issues);
+    }
+    check_segment_names(run, &mut issues);
+
+    issues
+}
+
+impl Issue {
+    /// Returns the suggested fix for this issue when validating the Run
+    /// provided. If there's no sensible fix that can be applied automatically,
+    /// `None` is returned.
+    pub fn fix(&self, run: &Run) -> Option<Fix> {
+        Some(match *self {
+            Issue::BestSegmentSlowerThanPersonalBest {
+                segment_index,
+                method,
+                personal_best_segment_time,
+                ..
+            } => {
+                // A segment time of zero can't be a Best Segment Time, so the
+                // Personal Best needs to be fixed in this case instead.
+                if personal_best_segment_time <= TimeSpan::zero() {
+                    return None;
+                }
+                Fix::SetBestSegmentTime {
+                    segment_index,
+                    method,
+                    time: Some(personal_best_segment_time),
+                }
+            }
+            Issue::NonPositiveBestSegmentTime {
+                segment_index,
+                method,
+                ..
+            } => Fix::SetBestSegmentTime {
+                segment_index,
+                method,
+                time: None,
+            },
+            Issue::NonPositiveHistoryTime {
+                segment_index,
+                attempt_index,
+                ..
+            }
+            | Issue::HistoryWithoutAttempt {
+                segment_index,
+                attempt_index,
+            } => Fix::RemoveHistoryElement {
+                segment_index,
+                attempt_index,
+            },
+            Issue::PersonalBestMismatch {
+                method,
+                attempt_index,
+                ..
+            } => {
+                attempt_split_times(run, attempt_index, method)?;
+                Fix::ImportPersonalBestFromAttempt {
+                    attempt_index,
+                    method,
+                }
+            }
+            Issue::DecreasingComparisonTime {
+                ref comparison,
+                segment_index,
+                method,
+                previous_time,
+                ..
+            } => Fix::SetComparisonTime {
+                comparison: comparison.clone(),
+                segment_index,
+                method,
+                time: previous_time,
+            },
+            Issue::DuplicateSegmentName { segment_index, .. } => Fix::RenameSegment {
+                segment_index,
+                name: unique_segment_name(run, segment_index),
+            },
+        })
+    }
+}
+
+impl Fix {
+    fn segment_index(&self) -> Option<usize> {
+        match *self {
+            Fix::SetBestSegmentTime { segment_index, .. }
+            | Fix::RemoveHistoryElement { segment_index, .. }
+            | Fix::SetComparisonTime { segment_index, .. }
+            | Fix::RenameSegment { segment_index, .. } => Some(segment_index),
+            Fix::ImportPersonalBestFromAttempt { .. } => None,
+        }
+    }
+
+    pub(super) fn apply(self, run: &mut Run) -> Result<(), FixError> {
+        if self
+            .segment_index()
+            .map_or(false, |index| index >= run.len())
+        {
+            return Err(FixError::SegmentIndexOutOfBounds);
+        }
+
+        // The fix may have been suggested for an older state of the Run, so
+        // it's only applied if it still resolves one of the current issues.
+        if !validate(run)
+            .iter()
+            .filter_map(|issue| issue.fix(run))
+            .any(|fix| fix == self)
+        {
+            return Err(FixError::NotApplicable);
+        }
+
+        match self {
+            Fix::SetBestSegmentTime {
+                segment_index,
+                method,
+                time,
+            } => {
+                run.segment_mut(segment_index).best_segment_time_mut()[method] = time;
+            }
+            Fix::RemoveHistoryElement {
+                segment_index,
+                attempt_index,
+            } => {
+                run.segment_mut(segment_index)
+                    .segment_history_mut()
+                    .remove(attempt_index);
+            }
+            Fix::ImportPersonalBestFromAttempt {
+                attempt_index,
+                method,
+            } => {
+                if let Some(split_times) = attempt_split_times(run, attempt_index, method) {
+                    for (segment, split_time) in run.segments_mut().iter_mut().zip(split_times) {
+                        segment.personal_best_split_time_mut()[method] = split_time;
+                    }
+                }
+            }
+            Fix::SetComparisonTime {
+                comparison,
+                segment_index,
+                method,
+                time,
+            } => {
+                run.segment_mut(segment_index).comparison_mut(&comparison)[method] = Some(time);
+            }
+            Fix::RenameSegment {
+                segment_index,
+                name,
+            } => {
+                run.segment_mut(segment_index).set_name(name);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn check_best_segments(run: &Run, method: TimingMethod, issues: &mut Vec<Issue>) {
+    let mut previous_time = TimeSpan::zero();
+    for (segment_index, segment) in run.segments().iter().enumerate() {
+        if let Some(split_time) = segment.personal_best_split_time()[method] {
+            let personal_best_segment_time = split_time - previous_time;
+            if let Some(best_segment_time) = segment.best_segment_time()[method] {
+                if personal_best_segment_time >= TimeSpan::zero()
+                    && best_segment_time > personal_best_segment_time
+                {
+                    issues.push(Issue::BestSegmentSlowerThanPersonalBest {
+                        segment_index,
+                        method,
+                        best_segment_time,
+                        personal_best_segment_time,
+                    });
+                }
+            }
+            previous_time = split_time;
+        }
+    }
+}
+
+fn check_best_segment_signs(run: &Run, method: TimingMethod, issues: &mut Vec<Issue>) {
+    for (segment_index, segment) in run.segments().iter().enumerate() {
+        if let Some(time) = segment.best_segment_time()[method] {
+            if time <= TimeSpan::zero() {
+                issues.push(Issue::NonPositiveBestSegmentTime {
+                    segment_index,
+                    method,
+                    time,
+                });
+            }
+        }
+    }
+}
+
+fn check_history_signs(run: &Run, method: TimingMethod, issues: &mut Vec<Issue>) {
+    for (segment_index, segment) in run.segments().iter().enumerate() {
+        for &(attempt_index, time) in segment.segment_history() {
+            if let Some(time) = time[method] {
+                if time <= TimeSpan::zero() {
+                    issues.push(Issue::NonPositiveHistoryTime {
+                        segment_index,
+                        attempt_index,
+                        method,
+                        time,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn check_history_attempts(run: &Run, issues: &mut Vec<Issue>) {
+    let attempts = run.attempt_history();
+    for (segment_index, segment) in run.segments().iter().enumerate() {
+        for &(attempt_index, _) in segment.segment_history().iter_actual_runs() {
+            if !attempts.iter().any(|a| a.index() == attempt_index) {
+                issues.push(Issue::HistoryWithoutAttempt {
+                    segment_index,
+                    attempt_index,
+                });
+            }
+        }
+    }
+}
+
+fn check_personal_best(run: &Run, method: TimingMethod, issues: &mut Vec<Issue>) {
+    let fastest = run
+        .attempt_history()
+        .iter()
+        .filter_map(|a| Some((a.index(), a.time()[method]?)))
+        .min_by_key(|&(_, time)| time);
+
+    if let Some((attempt_index, attempt_time)) = fastest {
+        let personal_best_time = run
+            .segments()
+            .last()
+            .and_then(|s| s.personal_best_split_time()[method]);
+
+        if personal_best_time.map_or(true, |pb| attempt_time < pb) {
+            issues.push(Issue::PersonalBestMismatch {
+                method,
+                personal_best_time,
+                attempt_index,
+                attempt_time,
+            });
+        }
+    }
+}
+
+fn check_comparisons(run: &Run, method: TimingMethod, issues: &mut Vec<Issue>) {
+    for comparison in run.custom_comparisons() {
+        let mut previous_time = TimeSpan::zero();
+        for (segment_index, segment) in run.segments().iter().enumerate() {
+            if let Some(time) = segment.comparison(comparison)[method] {
+                if time < previous_time {
+                    issues.push(Issue::DecreasingComparisonTime {
+                        comparison: comparison.clone(),
+                        segment_index,
+                        method,
+                        time,
+                        previous_time,
+                    });
+                } else {
+                    previous_time = time;
+                }
+            }
+        }
+    }
+}
+
+fn check_segment_names(run: &Run, issues: &mut Vec<Issue>) {
+    let segments = run.segments();
+    for (segment_index, segment) in segments.iter().enumerate() {
+        if segment.name().is_empty() {
+            continue;
+        }
+        if let Some(first_segment_index) = segments[..segment_index]
+            .iter()
+            .position(|s| unicase::eq(s.name(), segment.name()))
+        {
+            issues.push(Issue::DuplicateSegmentName {
+                segment_index,
+                first_segment_index,
+            });
+        }
+    }
+}
+
+fn attempt_split_times(
+    run: &Run,
+    attempt_index: i32,
+    method: TimingMethod,
+) -> Option<Vec<Option<TimeSpan>>> {
+    let mut split_times = Vec::with_capacity(run.len());
+    let mut previous_time = TimeSpan::zero();
+
+    for segment in run.segments() {
+        let split_time = segment.segment_history().get(attempt_index)?[method]
+            .map(|segment_time| previous_time + segment_time);
+        if let Some(split_time) = split_time {
+            previous_time = split_time;
+        }
+        split_times.push(split_time);
+    }
+
+    if split_times.last()?.is_some() {
+        Some(split_times)
+    } else {
+        None
+    }
+}
+
+fn unique_segment_name(run: &Run, segment_index: usize) -> String {
+    let name = run.segment(segment_index).name();
+    (2..)
+        .map(|number| format!("{} ({})", name, number))
+        .find(|candidate| {
+            !run.segments()
+                .iter()
+                .any(|s| unicase::eq(s.name(), candidate.as_str()))
+        })
+        .unwrap()
+}
+
+fn method_name(method: TimingMethod) -> &'static str {
+    match method {
+        TimingMethod::RealTime => "Real Time",
+        TimingMethod::GameTime => "Game Time",
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let short = Short::new();
+
+        match *self {
+            Issue::BestSegmentSlowerThanPersonalBest {
+                segment_index,
+                method,
+                best_segment_time,
+                personal_best_segment_time,
+            } => write!(
+                f,
+                "The {} Best Segment Time of {} of segment {} is slower than the {} segment time of {}.",
+                method_name(method),
+                short.format(best_segment_time),
+                segment_index + 1,
+                personal_best::NAME,
+                short.format(personal_best_segment_time),
+            ),
+            Issue::NonPositiveBestSegmentTime {
+                segment_index,
+                method,
+                time,
+            } => write!(
+                f,
+                "The {} Best Segment Time of segment {} is not positive ({}).",
+                method_name(method),
+                segment_index + 1,
+                short.format(time),
+            ),
+            Issue::NonPositiveHistoryTime {
+                segment_index,
+                attempt_index,
+                method,
+                time,
+            } => write!(
+                f,
+                "The {} segment time of segment {} in attempt {} is not positive ({}).",
+                method_name(method),
+                segment_index + 1,
+                attempt_index,
+                short.format(time),
+            ),
+            Issue::HistoryWithoutAttempt {
+                segment_index,
+                attempt_index,
+            } => write!(
+                f,
+                "The Segment History of segment {} refers to attempt {}, which doesn't exist.",
+                segment_index + 1,
+                attempt_index,
+            ),
+            Issue::PersonalBestMismatch {
+                method,
+                personal_best_time,
+                attempt_index,
+                attempt_time,
+            } => {
+                write!(
+                    f,
+                    "Attempt {} finished with a {} of {}, ",
+                    attempt_index,
+                    method_name(method),
+                    short.format(attempt_time),
+                )?;
+                if let Some(personal_best_time) = personal_best_time {
+                    write!(
+                        f,
+                        "which is faster than the {} of {}.",
+                        personal_best::NAME,
+                        short.format(personal_best_time),
+                    )
+                } else {
+                    write!(f, "but there is no {}.", personal_best::NAME)
+                }
+            }
+            Issue::DecreasingComparisonTime {
+                ref comparison,
+                segment_index,
+                method,
+                time,
+                previous_time,
+            } => write!(
+                f,
+                "The {} split time of {} of the comparison {} at segment {} is lower than the previous split time of {}.",
+                method_name(method),
+                short.format(time),
+                comparison,
+                segment_index + 1,
+                short.format(previous_time),
+            ),
+            Issue::DuplicateSegmentName {
+                segment_index,
+                first_segment_index,
+            } => write!(
+                f,
+                "Segment {} has the same name as segment {}.",
+                segment_index + 1,
+                first_segment_index + 1,
+            ),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use super::{Attempt, Segment, SegmentHistory};
+use crate::{Time, TimingMethod};
+use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+
+/// A History Filter restricts which attempts of a Run's history are considered
+/// by everything that analyzes the Segment Histories, such as the history based
+/// Comparison Generators and the PB chance. This is useful if older attempts
+/// are meaningless, because the route changed significantly. All the criteria
+/// are combined, so an attempt needs to fulfill all of them in order to be
+/// considered. If a filter is active, only segment times of attempts that are
+/// part of the Attempt History are considered.
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct HistoryFilter {
+    since: Option<DateTime<Utc>>,
+    last_attempts: Option<u32>,
+    since_attempt: Option<i32>,
+    excluded_attempts: Vec<i32>,
+}
+
+impl HistoryFilter {
+    /// Creates a new History Filter that doesn't filter out any attempts.
+    #[inline]
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns whether the History Filter filters out anything at all.
+    pub fn is_active(&self) -> bool {
+        self.since.is_some()
+            || self.last_attempts.is_some()
+            || self.since_attempt.is_some()
+            || !self.excluded_attempts.is_empty()
+    }
+
+    /// Accesses the point in time from which on attempts are considered.
+    /// Attempts that started earlier or where it is unknown when they started
+    /// are filtered out.
+    #[inline]
+    pub fn since(&self) -> Option<DateTime<Utc>> {
+        self.since
+    }
+
+    /// Sets the point in time from which on attempts are considered. Attempts
+    /// that started earlier or where it is unknown when they started are
+    /// filtered out.
+    #[inline]
+    pub fn set_since(&mut self, since: Option<DateTime<Utc>>) {
+        self.since = since;
+    }
+
+    /// Accesses the amount of most recent attempts that are considered.
+    #[inline]
+    pub fn last_attempts(&self) -> Option<u32> {
+        self.last_attempts
+    }
+
+    /// Sets the amount of most recent attempts that are considered.
+    #[inline]
+    pub fn set_last_attempts(&mut self, last_attempts: Option<u32>) {
+        self.last_attempts = last_attempts;
+    }
+
+    /// Accesses the index of the first attempt that is considered. All the
+    /// attempts with a lower index are filtered out.
+    #[inline]
+    pub fn since_attempt(&self) -> Option<i32> {
+        self.since_attempt
+    }
+
+    /// Sets the index of the first attempt that is considered. All the attempts
+    /// with a lower index are filtered out.
+    #[inline]
+    pub fn set_since_attempt(&mut self, since_attempt: Option<i32>) {
+        self.since_attempt = since_attempt;
+    }
+
+    /// Accesses the indices of the attempts that are flagged to be excluded.
+    #[inline]
+    pub fn excluded_attempts(&self) -> &[i32] {
+        &self.excluded_attempts
+    }
+
+    /// Returns whether the attempt with the index provided is flagged to be
+    /// excluded.
+    pub fn is_attempt_excluded(&self, index: i32) -> bool {
+        self.excluded_attempts.contains(&index)
+    }
+
+    /// Flags or unflags the attempt with the index provided to be excluded.
+    pub fn set_attempt_excluded(&mut self, index: i32, excluded: bool) {
+        let pos = self.excluded_attempts.binary_search(&index);
+        match (pos, excluded) {
+            (Err(pos), true) => self.excluded_attempts.insert(pos, index),
+            (Ok(pos), false) => {
+                self.excluded_attempts.remove(pos);
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the indices of all the attempts of the Attempt History that are
+    /// considered by the History Filter.
+    pub fn allowed_attempts(&self, attempts: &[Attempt]) -> HashSet<i32> {
+        let mut indices = attempts
+            .iter()
+            .filter(|a| {
+                self.since.map_or(true, |since| {
+                    a.started().map_or(false, |started| started.time >= since)
+                })
+            })
+            .map(Attempt::index)
+            .filter(|&index| self.since_attempt.map_or(true, |since| index >= since))
+            .filter(|&index| !self.is_attempt_excluded(index))
+            .collect::<Vec<_>>();
+
+        if let Some(last_attempts) = self.last_attempts {
+            let last_attempts = last_attempts as usize;
+            indices.sort_unstable();
+            if indices.len() > last_attempts {
+                indices.drain(..indices.len() - last_attempts);
+            }
+        }
+
+        indices.into_iter().collect()
+    }
+
+    /// Creates copies of the segments provided, where the Segment Histories
+    /// only contain the segment times of the attempts considered by the History
+    /// Filter. The Best Segment Times are recalculated from the remaining
+    /// segment times.
+    pub fn apply(&self, segments: &[Segment], attempts: &[Attempt]) -> Vec<Segment> {
+        let histories = self.filtered_histories(segments, attempts);
+        let mut segments = segments.to_vec();
+        for (segment, (history, best)) in segments.iter_mut().zip(histories) {
+            *segment.segment_history_mut() = history;
+            segment.set_best_segment_time(best);
+        }
+        segments
+    }
+
+    pub(super) fn filtered_histories(
+        &self,
+        segments: &[Segment],
+        attempts: &[Attempt],
+    ) -> Vec<(SegmentHistory, Time)> {
+        let allowed = self.allowed_attempts(attempts);
+        segments
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let previous = i.checked_sub(1).map(|i| segments[i].segment_history());
+                filter_history(s.segment_history(), previous, &allowed)
+            })
+            .collect()
+    }
+}
+
+fn filter_history(
+    history: &SegmentHistory,
+    previous: Option<&SegmentHistory>,
+    allowed: &HashSet<i32>,
+) -> (SegmentHistory, Time) {
+    let mut filtered = history.clone();
+    filtered.retain(|&(index, _)| allowed.contains(&index));
+
+    let mut best = Time::default();
+    for &(index, time) in &filtered {
+        for &method in &TimingMethod::all() {
+            // Skip all the combined segments
+            let combined = catch! { previous?.get(index)?[method].is_none() }.unwrap_or(false);
+            if combined {
+                continue;
+            }
+            if let Some(time) = time[method] {
+                if best[method].map_or(true, |b| time < b) {
+                    best[method] = Some(time);
+                }
+            }
+        }
+    }
+
+    (filtered, best)
+}
@@ -0,0 +1,88 @@
+use crate::Time;
+
+/// An Individual Level Best stores the best time achieved for running a range
+/// of segments on its own, starting at the beginning of one segment and ending
+/// at the end of another. These times are stored separately from the history
+/// of the full run, so individual level attempts never influence the Personal
+/// Best, the Best Segments or the Segment History.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IndividualLevelBest {
+    start_index: usize,
+    end_index: usize,
+    time: Time,
+}
+
+impl IndividualLevelBest {
+    /// Creates a new Individual Level Best for the segments from the start
+    /// index to the end index, both inclusive.
+    pub fn new(start_index: usize, end_index: usize, time: Time) -> Self {
+        Self {
+            start_index,
+            end_index,
+            time,
+        }
+    }
+
+    /// Accesses the index of the first segment of the individual level.
+    #[inline]
+    pub fn start_index(&self) -> usize {
+        self.start_index
+    }
+
+    /// Accesses the index of the last segment of the individual level.
+    #[inline]
+    pub fn end_index(&self) -> usize {
+        self.end_index
+    }
+
+    /// Accesses the best time achieved for the individual level.
+    #[inline]
+    pub fn time(&self) -> Time {
+        self.time
+    }
+
+    /// Sets the best time achieved for the individual level.
+    #[inline]
+    pub fn set_time(&mut self, time: Time) {
+        self.time = time;
+    }
+
+    /// Returns whether the individual level covers the segment range provided.
+    #[inline]
+    pub fn is_range(&self, start_index: usize, end_index: usize) -> bool {
+        self.start_index == start_index && self.end_index == end_index
+    }
+
+    /// Adjusts the indices for a segment being inserted at the index provided.
+    /// Returns `false` if the segment is inserted into the individual level,
+    /// which means that its time isn't meaningful anymore.
+    pub(crate) fn segment_inserted(&mut self, index: usize) -> bool {
+        if index <= self.start_index {
+            self.start_index += 1;
+            self.end_index += 1;
+            true
+        } else {
+            index > self.end_index
+        }
+    }
+
+    /// Adjusts the indices for the segment with the index provided being
+    /// removed. Returns `false` if the segment is part of the individual level,
+    /// which means that its time isn't meaningful anymore.
+    pub(crate) fn segment_removed(&mut self, index: usize) -> bool {
+        if index < self.start_index {
+            self.start_index -= 1;
+            self.end_index -= 1;
+            true
+        } else {
+            index > self.end_index
+        }
+    }
+
+    /// Returns `false` if swapping the segment with the index provided and the
+    /// one after it moves a segment into or out of the individual level.
+    pub(crate) fn segments_swapped(&self, index: usize) -> bool {
+        let contains = |i| self.start_index <= i && i <= self.end_index;
+        contains(index) == contains(index + 1)
+    }
+}
@@ -17,6 +17,8 @@
 
 mod attempt;
 pub mod editor;
+mod history_filter;
+mod individual_level_best;
 pub mod parser;
 mod run_metadata;
 pub mod saver;
@@ -28,16 +30,19 @@ mod tests;
 
 pub use attempt::Attempt;
 pub use editor::{Editor, RenameError};
+pub use history_filter::HistoryFilter;
+pub use individual_level_best::IndividualLevelBest;
 pub use run_metadata::RunMetadata;
 pub use segment::Segment;
 pub use segment_history::SegmentHistory;
 
-use crate::comparison::{default_generators, personal_best, ComparisonGenerator};
+use crate::comparison::{self, default_generators, personal_best, ComparisonGenerator};
 use crate::{settings::Image, AtomicDateTime, Time, TimeSpan, TimingMethod};
 use ordered_float::OrderedFloat;
 use std::borrow::Cow;
 use std::cmp::max;
 use std::collections::HashSet;
+use std::mem;
 use std::path::PathBuf;
 use unicase;
 
@@ -71,6 +76,8 @@ pub struct Run {
     custom_comparisons: Vec<String>,
     comparison_generators: ComparisonGenerators,
     auto_splitter_settings: Vec<u8>,
+    individual_level_bests: Vec<IndividualLevelBest>,
+    history_filter: HistoryFilter,
 }
 
 #[derive(Clone, Debug)]
@@ -97,6 +104,16 @@ pub enum ComparisonError {
 /// Result type for an invalid comparison name
 pub type ComparisonResult<T> = Result<T, ComparisonError>;
 
+/// Error type for enabling or disabling a Comparison Generator.
+#[derive(PartialEq, Debug, snafu::Snafu)]
+pub enum ComparisonGeneratorError {
+    /// There is no Comparison Generator with the name provided.
+    UnknownGenerator,
+    /// There is a custom comparison with the same name as the Comparison
+    /// Generator.
+    CustomComparisonExists,
+}
+
 impl Run {
     /// Creates a new Run object with no segments.
     #[inline]
@@ -115,6 +132,8 @@ impl Run {
             custom_comparisons: vec![personal_best::NAME.to_string()],
             comparison_generators: ComparisonGenerators(default_generators()),
             auto_splitter_settings: Vec::new(),
+            individual_level_bests: Vec::new(),
+            history_filter: HistoryFilter::new(),
         }
     }
 
@@ -303,6 +322,61 @@ impl Run {
         &mut self.comparison_generators.0
     }
 
+    /// Returns whether the Comparison Generator with the name provided is
+    /// enabled for this Run.
+    pub fn is_comparison_generator_enabled(&self, name: &str) -> bool {
+        self.comparison_generators
+            .0
+            .iter()
+            .any(|g| g.name() == name)
+    }
+
+    /// Enables or disables the Comparison Generator with the name provided. A
+    /// Comparison Generator that gets enabled starts out with its default
+    /// settings and its comparison is generated right away. Disabling a
+    /// Comparison Generator removes its comparison times from all the
+    /// segments. A Comparison Generator can't be enabled if there's a custom
+    /// comparison with the same name.
+    pub fn set_comparison_generator_enabled(
+        &mut self,
+        name: &str,
+        enabled: bool,
+    ) -> Result<(), ComparisonGeneratorError> {
+        let generator = comparison::generator_by_name(name)
+            .ok_or(ComparisonGeneratorError::UnknownGenerator)?;
+
+        if enabled == self.is_comparison_generator_enabled(name) {
+            return Ok(());
+        }
+
+        if enabled {
+            if self.custom_comparisons.iter().any(|c| c == name) {
+                return Err(ComparisonGeneratorError::CustomComparisonExists);
+            }
+
+            let order = comparison::all_generators();
+            let position = |name: &str| order.iter().position(|g| g.name() == name);
+            let new_position = position(name);
+            let index = self
+                .comparison_generators
+                .0
+                .iter()
+                .position(|g| position(g.name()) > new_position)
+                .unwrap_or(self.comparison_generators.0.len());
+
+            self.comparison_generators.0.insert(index, generator);
+            self.regenerate_comparisons();
+        } else {
+            self.comparison_generators.0.retain(|g| g.name() != name);
+            for segment in &mut self.segments {
+                segment.comparisons_mut().remove(name);
+            }
+        }
+
+        self.has_been_modified = true;
+        Ok(())
+    }
+
     /// Accesses the Auto Splitter Settings that are encoded as XML.
     #[inline]
     pub fn auto_splitter_settings(&self) -> &[u8] {
@@ -320,6 +394,131 @@ impl Run {
         &mut self.auto_splitter_settings
     }
 
+    /// Accesses the best times of the individual levels that have been run.
+    /// These are stored separately from the history of the full run.
+    #[inline]
+    pub fn individual_level_bests(&self) -> &[IndividualLevelBest] {
+        &self.individual_level_bests
+    }
+
+    /// Grants mutable access to the best times of the individual levels that
+    /// have been run.
+    ///
+    /// # Warning
+    ///
+    /// You need to ensure that the segment ranges of the individual levels are
+    /// within the bounds of the segments and that each range is only stored
+    /// once.
+    #[inline]
+    pub fn individual_level_bests_mut(&mut self) -> &mut Vec<IndividualLevelBest> {
+        &mut self.individual_level_bests
+    }
+
+    /// Accesses the best time of the individual level from the start index to
+    /// the end index, both inclusive. If that individual level hasn't been run
+    /// yet, `None` is returned.
+    pub fn individual_level_best(
+        &self,
+        start_index: usize,
+        end_index: usize,
+    ) -> Option<&IndividualLevelBest> {
+        self.individual_level_bests
+            .iter()
+            .find(|b| b.is_range(start_index, end_index))
+    }
+
+    /// Stores the time provided as the best time of the individual level from
+    /// the start index to the end index, both inclusive, if it is faster than
+    /// the currently stored best time for the timing method provided. Only the
+    /// time of that timing method is replaced in an existing best time. Returns
+    /// whether the time got stored.
+    pub fn update_individual_level_best(
+        &mut self,
+        start_index: usize,
+        end_index: usize,
+        time: Time,
+        method: TimingMethod,
+    ) -> bool {
+        let new_time = match time[method] {
+            Some(new_time) => new_time,
+            None => return false,
+        };
+
+        if let Some(best) = self
+            .individual_level_bests
+            .iter_mut()
+            .find(|b| b.is_range(start_index, end_index))
+        {
+            let mut best_time = best.time();
+            if best_time[method].map_or(false, |t| t <= new_time) {
+                return false;
+            }
+            // Only the timing method compared is replaced, so that a better
+            // time stored for the other timing method is kept.
+            best_time[method] = Some(new_time);
+            best.set_time(best_time);
+        } else {
+            self.individual_level_bests.push(IndividualLevelBest::new(
+                start_index,
+                end_index,
+                time,
+            ));
+        }
+
+        self.has_been_modified = true;
+        true
+    }
+
+    /// Creates a Run that only consists of the segments from the start index
+    /// to the end index, both inclusive. This is useful for timing individual
+    /// levels. All the comparisons are shifted, such that they start at zero
+    /// at the beginning of the first segment, so they represent the
+    /// corresponding slice of the full run. The Best Segments and Segment
+    /// Histories of the segments are kept as they are. If the range is not
+    /// within the bounds of the segments, `None` is returned.
+    pub fn individual_level(&self, start_index: usize, end_index: usize) -> Option<Run> {
+        if start_index > end_index || end_index >= self.len() {
+            return None;
+        }
+
+        let previous_segments = &self.segments[..start_index];
+        let mut segments = self.segments[start_index..=end_index].to_vec();
+
+        for segment in &mut segments {
+            segment.clear_split_time();
+            for (comparison, time) in segment.comparisons_mut() {
+                for &method in &TimingMethod::all() {
+                    let start_time = previous_segments
+                        .iter()
+                        .rev()
+                        .find_map(|s| s.comparison(comparison)[method]);
+
+                    if let (Some(time), Some(start_time)) = (&mut time[method], start_time) {
+                        *time -= start_time;
+                    }
+                }
+            }
+        }
+
+        Some(Run {
+            game_icon: self.game_icon.clone(),
+            game_name: self.game_name.clone(),
+            category_name: self.category_name.clone(),
+            offset: TimeSpan::zero(),
+            attempt_count: self.attempt_count,
+            attempt_history: self.attempt_history.clone(),
+            metadata: self.metadata.clone(),
+            has_been_modified: false,
+            path: None,
+            segments,
+            custom_comparisons: self.custom_comparisons.clone(),
+            comparison_generators: self.comparison_generators.clone(),
+            auto_splitter_settings: self.auto_splitter_settings.clone(),
+            individual_level_bests: Vec::new(),
+            history_filter: self.history_filter.clone(),
+        })
+    }
+
     /// Returns the amount of segments stored in this Run.
     #[inline]
     pub fn len(&self) -> usize {
@@ -415,8 +614,81 @@ impl Run {
     /// Recalculates all the comparison times the Comparison Generators provide.
     #[inline]
     pub fn regenerate_comparisons(&mut self) {
+        if !self.history_filter.is_active() {
+            for generator in &mut self.comparison_generators.0 {
+                generator.generate(&mut self.segments, &self.attempt_history);
+            }
+            return;
+        }
+
+        let allowed = self.history_filter.allowed_attempts(&self.attempt_history);
+        let attempts = self
+            .attempt_history
+            .iter()
+            .filter(|a| allowed.contains(&a.index()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let mut histories = self
+            .history_filter
+            .filtered_histories(&self.segments, &self.attempt_history);
+
+        // The comparisons are generated with the filtered Segment Histories
+        // swapped in, which are then swapped back out again afterwards.
+        self.swap_segment_histories(&mut histories);
         for generator in &mut self.comparison_generators.0 {
-            generator.generate(&mut self.segments, &self.attempt_history);
+            generator.generate(&mut self.segments, &attempts);
+        }
+        self.swap_segment_histories(&mut histories);
+    }
+
+    fn swap_segment_histories(&mut self, histories: &mut [(SegmentHistory, Time)]) {
+        for (segment, (history, best)) in self.segments.iter_mut().zip(histories) {
+            mem::swap(segment.segment_history_mut(), history);
+            mem::swap(segment.best_segment_time_mut(), best);
+        }
+    }
+
+    /// Accesses the History Filter that restricts which attempts are
+    /// considered by the history based comparisons and analysis.
+    #[inline]
+    pub fn history_filter(&self) -> &HistoryFilter {
+        &self.history_filter
+    }
+
+    /// Sets the History Filter that restricts which attempts are considered by
+    /// the history based comparisons and analysis. You need to regenerate the
+    /// comparisons afterwards for the filter to take effect.
+    #[inline]
+    pub fn set_history_filter(&mut self, history_filter: HistoryFilter) {
+        self.history_filter = history_filter;
+    }
+
+    /// Returns the indices of the attempts considered by the History Filter. If
+    /// the filter isn't active, all attempts are considered and `None` is
+    /// returned. This is cheaper than filtering the segments themselves, as the
+    /// Segment Histories don't need to be copied.
+    pub fn allowed_attempts(&self) -> Option<HashSet<i32>> {
+        if self.history_filter.is_active() {
+            Some(self.history_filter.allowed_attempts(&self.attempt_history))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the segments as seen through the History Filter. If the filter
+    /// is active, the Segment Histories only contain the segment times of the
+    /// attempts it considers and the Best Segment Times are based on those.
+    /// This copies all the segments, so prefer `allowed_attempts` for
+    /// calculations that are done frequently.
+    pub fn filtered_segments(&self) -> Cow<'_, [Segment]> {
+        if self.history_filter.is_active() {
+            Cow::Owned(
+                self.history_filter
+                    .apply(&self.segments, &self.attempt_history),
+            )
+        } else {
+            Cow::Borrowed(&self.segments)
         }
     }
 
@@ -595,10 +867,11 @@ impl Run {
 
     /// Clears out the Attempt History, the Segment Histories, all the times,
     /// sets the Attempt Count to 0 and clears the speedrun.com run id
-    /// association. All Custom Comparisons other than `Personal Best` are
-    /// deleted as well.
+    /// association. All Custom Comparisons other than `Personal Best` and the
+    /// Individual Level Bests are deleted as well.
     pub fn clear_times(&mut self) {
         self.clear_history();
+        self.individual_level_bests.clear();
         self.custom_comparisons.retain(|c| c == personal_best::NAME);
         for segment in &mut self.segments {
             segment.comparisons_mut().clear();
@@ -1,9 +1,11 @@
 //! Provides the parser for LiveSplit splits files.
 
 use super::super::ComparisonError;
+use crate::run::{HistoryFilter, IndividualLevelBest};
+use crate::settings::Value;
 use crate::xml_util::{
-    attribute, attribute_err, end_tag, optional_attribute_err, parse_attributes, parse_base,
-    parse_children, reencode_children, text, text_as_bytes_err, text_err, text_parsed,
+    attribute, attribute_err, end_tag, image, optional_attribute_err, parse_attributes,
+    parse_base, parse_children, reencode_children, text, text_err, text_parsed, Tag,
 };
 use crate::{AtomicDateTime, Run, RunMetadata, Segment, Time, TimeSpan};
 use chrono::{DateTime, TimeZone, Utc};
@@ -89,29 +91,6 @@ fn parse_date_time<S: AsRef<str>>(text: S) -> Result<DateTime<Utc>> {
         .map_err(Into::into)
 }
 
-fn image<R, F>(
-    reader: &mut Reader<R>,
-    result: &mut Vec<u8>,
-    image_buf: &mut Vec<u8>,
-    f: F,
-) -> Result<()>
-where
-    R: BufRead,
-    F: FnOnce(&[u8]),
-{
-    text_as_bytes_err(reader, result, |text| {
-        if text.len() >= 216 {
-            image_buf.clear();
-            if base64::decode_config_buf(&text[212..], base64::STANDARD, image_buf).is_ok() {
-                f(&image_buf[2..image_buf.len() - 1]);
-                return Ok(());
-            }
-        }
-        f(&[]);
-        Ok(())
-    })
-}
-
 fn time_span<R, F>(reader: &mut Reader<R>, buf: &mut Vec<u8>, f: F) -> Result<()>
 where
     R: BufRead,
@@ -429,6 +408,131 @@ fn parse_attempt_history<R: BufRead>(
     }
 }
 
+fn parse_individual_level_bests<R: BufRead>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    run: &mut Run,
+) -> Result<()> {
+    parse_children(reader, buf, |reader, tag| {
+        let (mut start_index, mut end_index) = (None, None);
+
+        type_hint(parse_attributes(&tag, |k, v| {
+            if k == b"start" {
+                start_index = Some(v.get::<Error>()?.parse()?);
+            } else if k == b"end" {
+                end_index = Some(v.get::<Error>()?.parse()?);
+            }
+            Ok(true)
+        }))?;
+
+        let (start_index, end_index) = catch! { (start_index?, end_index?) }.ok_or(Error::Xml {
+            source: XmlError::AttributeNotFound,
+        })?;
+
+        time(reader, tag.into_buf(), |time| {
+            run.individual_level_bests_mut()
+                .push(IndividualLevelBest::new(start_index, end_index, time));
+        })
+    })
+}
+
+fn parse_history_filter<R: BufRead>(
+    reader: &mut Reader<R>,
+    tag: Tag<'_>,
+    run: &mut Run,
+) -> Result<()> {
+    let mut filter = HistoryFilter::new();
+
+    type_hint(parse_attributes(&tag, |k, v| {
+        if k == b"since" {
+            filter.set_since(Some(parse_date_time(v.get::<Error>()?)?));
+        } else if k == b"lastAttempts" {
+            filter.set_last_attempts(Some(v.get::<Error>()?.parse()?));
+        } else if k == b"sinceAttempt" {
+            filter.set_since_attempt(Some(v.get::<Error>()?.parse()?));
+        }
+        Ok(true)
+    }))?;
+
+    parse_children::<_, _, Error>(reader, tag.into_buf(), |reader, tag| {
+        if tag.name() == b"ExcludedAttempt" {
+            type_hint(attribute_err(&tag, b"id", |t| {
+                filter.set_attempt_excluded(t.parse()?, true);
+                Ok(())
+            }))?;
+        }
+        end_tag(reader, tag.into_buf())
+    })?;
+
+    run.set_history_filter(filter);
+
+    Ok(())
+}
+
+fn parse_setting_value(current: Value, text: &str) -> Result<Option<Value>> {
+    Ok(Some(match current {
+        Value::Bool(_) => Value::Bool(parse_bool(text.as_bytes())?),
+        Value::UInt(_) => Value::UInt(text.parse()?),
+        Value::Int(_) => Value::Int(text.parse()?),
+        Value::Float(_) => Value::Float(text.parse()?),
+        Value::String(_) => Value::String(text.to_owned()),
+        Value::OptionalString(_) => {
+            Value::OptionalString(Some(text.to_owned()).filter(|t| !t.is_empty()))
+        }
+        _ => return Ok(None),
+    }))
+}
+
+fn parse_comparison_generators<R: BufRead>(
+    reader: &mut Reader<R>,
+    buf: &mut Vec<u8>,
+    run: &mut Run,
+) -> Result<()> {
+    parse_children(reader, buf, |reader, tag| {
+        let mut name = String::new();
+        type_hint(attribute(&tag, b"name", |t| name = t.into_owned()))?;
+
+        let mut enabled = true;
+        type_hint(optional_attribute_err(&tag, b"enabled", |t| {
+            enabled = parse_bool(t.as_bytes())?;
+            Ok(())
+        }))?;
+
+        // Unknown Comparison Generators are skipped.
+        let _ = run.set_comparison_generator_enabled(&name, enabled);
+
+        let mut generator = run
+            .comparison_generators_mut()
+            .iter_mut()
+            .find(|g| g.name() == name);
+
+        parse_children(reader, tag.into_buf(), |reader, tag| {
+            let mut setting = String::new();
+            type_hint(attribute(&tag, b"name", |t| setting = t.into_owned()))?;
+
+            text_err(reader, tag.into_buf(), |text| {
+                // Settings of unknown or disabled generators are skipped.
+                if let Some(generator) = &mut generator {
+                    // The settings are identified by their keys, so that they
+                    // survive renaming the fields.
+                    let index = generator
+                        .setting_keys()
+                        .iter()
+                        .position(|&key| key == setting);
+
+                    if let Some(index) = index {
+                        let field = generator.settings_description().fields.swap_remove(index);
+                        if let Some(value) = parse_setting_value(field.value, &text)? {
+                            generator.set_value(index, value);
+                        }
+                    }
+                }
+                Ok(())
+            })
+        })
+    })
+}
+
 /// Attempts to parse a LiveSplit splits file. In addition to the source to
 /// parse, you can provide a path to the splits file, which helps saving the
 /// splits file again later.
@@ -485,6 +589,12 @@ pub fn parse<R: BufRead>(source: R, path: Option<PathBuf>) -> Result<Run> {
                         end_tag(reader, tag.into_buf())
                     }
                 })
+            } else if tag.name() == b"IndividualLevelBests" {
+                parse_individual_level_bests(reader, tag.into_buf(), &mut run)
+            } else if tag.name() == b"HistoryFilter" {
+                parse_history_filter(reader, tag, &mut run)
+            } else if tag.name() == b"ComparisonGenerators" {
+                parse_comparison_generators(reader, tag.into_buf(), &mut run)
             } else if tag.name() == b"AutoSplitterSettings" {
                 let settings = run.auto_splitter_settings_mut();
                 reencode_children(reader, tag.into_buf(), settings).map_err(Into::into)
@@ -500,7 +610,15 @@ pub fn parse<R: BufRead>(source: R, path: Option<PathBuf>) -> Result<Run> {
         });
     }
 
+    let len = run.len();
+    run.individual_level_bests_mut()
+        .retain(|b| b.start_index() <= b.end_index() && b.end_index() < len);
+
     run.set_path(path);
 
+    // Applying the stored Comparison Generators marks the Run as modified, even
+    // though it matches what is stored in the file.
+    run.mark_as_unmodified();
+
     Ok(run)
 }
@@ -24,8 +24,10 @@
 //! livesplit::save_run(&run, writer).expect("Couldn't save the splits file");
 //! ```
 
+use crate::comparison;
+use crate::settings::{Image, Value};
 use crate::timing::formatter::{Complete, TimeFormatter};
-use crate::{settings::Image, Run, Time, TimeSpan, Timer, TimerPhase};
+use crate::{Run, Time, TimeSpan, Timer, TimerPhase};
 use byteorder::{WriteBytesExt, LE};
 use chrono::{DateTime, Utc};
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
@@ -226,6 +228,26 @@ fn time<W: Write>(
     )
 }
 
+fn setting_value<W: Write>(
+    writer: &mut Writer<W>,
+    tag: BytesStart<'_>,
+    value: &Value,
+    buf: &mut Vec<u8>,
+) -> Result<()> {
+    match value {
+        Value::Bool(value) => text(writer, tag, bool(*value)),
+        Value::UInt(value) => write_display(writer, tag, value, buf),
+        Value::Int(value) => write_display(writer, tag, value, buf),
+        Value::Float(value) => write_display(writer, tag, value, buf),
+        Value::String(value) => text(writer, tag, value),
+        Value::OptionalString(value) => {
+            text(writer, tag, value.as_ref().map_or("", String::as_str))
+        }
+        // Comparison Generators don't use any of the other kinds of settings.
+        _ => Ok(()),
+    }
+}
+
 /// Saves the Run in use by the Timer provided as a LiveSplit splits file
 /// (*.lss).
 pub fn save_timer<W: Write>(timer: &Timer, writer: W) -> Result<()> {
@@ -372,6 +394,79 @@ pub fn save_run<W: Write>(run: &Run, writer: W) -> Result<()> {
         },
     )?;
 
+    if !run.individual_level_bests().is_empty() {
+        scoped_iter(
+            writer,
+            new_tag(b"IndividualLevelBests"),
+            run.individual_level_bests(),
+            |writer, best| {
+                let mut tag = new_tag(b"IndividualLevelBest");
+                tag.push_attribute((&b"start"[..], fmt_buf(best.start_index(), buf)));
+                tag.push_attribute((&b"end"[..], fmt_buf(best.end_index(), buf)));
+                time(writer, tag, best.time(), buf)
+            },
+        )?;
+    }
+
+    let history_filter = run.history_filter();
+    if history_filter.is_active() {
+        let mut tag = new_tag(b"HistoryFilter");
+        if let Some(since) = history_filter.since() {
+            tag.push_attribute((&b"since"[..], fmt_date(since, buf)));
+        }
+        if let Some(last_attempts) = history_filter.last_attempts() {
+            tag.push_attribute((&b"lastAttempts"[..], fmt_buf(last_attempts, buf)));
+        }
+        if let Some(since_attempt) = history_filter.since_attempt() {
+            tag.push_attribute((&b"sinceAttempt"[..], fmt_buf(since_attempt, buf)));
+        }
+        scoped_iter(
+            writer,
+            tag,
+            history_filter.excluded_attempts(),
+            |writer, index| {
+                let mut tag = new_tag(b"ExcludedAttempt");
+                tag.push_attribute((&b"id"[..], fmt_buf(index, buf)));
+                writer.write_event(Event::Empty(tag))?;
+                Ok(())
+            },
+        )?;
+    }
+
+    // The Comparison Generators are only stored if they differ from the
+    // default ones, which is what a Run without this information starts out
+    // with.
+    if !comparison::are_default_generators(run.comparison_generators()) {
+        scoped_iter(
+            writer,
+            new_tag(b"ComparisonGenerators"),
+            comparison::all_generators(),
+            |writer, generator| {
+                let name = generator.name();
+                let enabled = run
+                    .comparison_generators()
+                    .iter()
+                    .find(|g| g.name() == name);
+
+                let mut tag = new_tag(b"ComparisonGenerator");
+                tag.push_attribute((&b"name"[..], name.as_bytes()));
+                tag.push_attribute((&b"enabled"[..], bool(enabled.is_some())));
+
+                let settings = enabled.map_or_else(Vec::new, |g| {
+                    g.setting_keys()
+                        .iter()
+                        .zip(g.settings_description().fields)
+                        .collect()
+                });
+                scoped_iter(writer, tag, settings, |writer, (key, field)| {
+                    let mut tag = new_tag(b"Setting");
+                    tag.push_attribute((&b"name"[..], key.as_bytes()));
+                    setting_value(writer, tag, &field.value, buf)
+                })
+            },
+        )?;
+    }
+
     scoped(
         writer,
         new_tag(b"AutoSplitterSettings"),
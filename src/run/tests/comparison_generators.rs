@@ -0,0 +1,107 @@
+use crate::comparison::{average_segments, best_segments, median_segments, none};
+use crate::run::{parser, saver, ComparisonGeneratorError, Editor};
+use crate::settings::Value;
+use crate::{Run, Segment};
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run
+}
+
+fn save_and_parse(run: &Run) -> Run {
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(run, &mut buf).unwrap();
+    parser::livesplit::parse(buf.as_slice(), None).unwrap()
+}
+
+#[test]
+fn enabling_keeps_the_order() {
+    let mut run = run();
+    run.set_comparison_generator_enabled(average_segments::NAME, false)
+        .unwrap();
+    assert!(!run.is_comparison_generator_enabled(average_segments::NAME));
+    assert!(!run.comparisons().any(|c| c == average_segments::NAME));
+
+    run.set_comparison_generator_enabled(average_segments::NAME, true)
+        .unwrap();
+    let default_run = self::run();
+    assert!(run.comparisons().eq(default_run.comparisons()));
+}
+
+#[test]
+fn invalid_generators_are_rejected() {
+    let mut run = run();
+    assert_eq!(
+        run.set_comparison_generator_enabled("Foo", true),
+        Err(ComparisonGeneratorError::UnknownGenerator)
+    );
+
+    run.set_comparison_generator_enabled(none::NAME, false)
+        .unwrap();
+    run.add_custom_comparison(none::NAME).unwrap();
+    assert_eq!(
+        run.set_comparison_generator_enabled(none::NAME, true),
+        Err(ComparisonGeneratorError::CustomComparisonExists)
+    );
+}
+
+#[test]
+fn selection_and_settings_survive_saving_and_parsing() {
+    let mut editor = Editor::new(run()).unwrap();
+    editor
+        .set_comparison_generator_enabled(best_segments::NAME, false)
+        .unwrap();
+    editor.set_comparison_generator_value(median_segments::NAME, 0, Value::Float(0.5));
+
+    let parsed = save_and_parse(&editor.close());
+
+    assert!(!parsed.is_comparison_generator_enabled(best_segments::NAME));
+    assert!(parsed.is_comparison_generator_enabled(median_segments::NAME));
+
+    let editor = Editor::new(parsed).unwrap();
+    let weight = editor
+        .comparison_generator_settings_description(median_segments::NAME)
+        .unwrap()
+        .fields
+        .remove(0)
+        .value
+        .into_float()
+        .unwrap();
+    assert_eq!(weight, 0.5);
+    assert!(editor
+        .comparison_generator_settings_description(best_segments::NAME)
+        .is_none());
+}
+
+#[test]
+fn changing_the_selection_marks_the_run_as_modified() {
+    let mut run = run();
+    run.set_comparison_generator_enabled(average_segments::NAME, true)
+        .unwrap();
+    assert!(!run.has_been_modified());
+
+    run.set_comparison_generator_enabled(average_segments::NAME, false)
+        .unwrap();
+    assert!(run.has_been_modified());
+
+    let parsed = save_and_parse(&run);
+    assert!(!parsed.has_been_modified());
+    assert!(!parsed.is_comparison_generator_enabled(average_segments::NAME));
+}
+
+#[test]
+fn default_generators_are_not_saved() {
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run(), &mut buf).unwrap();
+    let xml = String::from_utf8(buf).unwrap();
+    assert!(!xml.contains("ComparisonGenerators"));
+
+    let mut run = run();
+    run.set_comparison_generator_enabled(average_segments::NAME, false)
+        .unwrap();
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let xml = String::from_utf8(buf).unwrap();
+    assert!(xml.contains("ComparisonGenerators"));
+}
@@ -0,0 +1,191 @@
+use crate::analysis::pb_chance;
+use crate::comparison::{average_segments, best_segments};
+use crate::run::{parser, saver, Editor, HistoryFilter};
+use crate::{AtomicDateTime, Run, Segment, Time, TimeSpan, TimingMethod};
+use chrono::{DateTime, Utc};
+
+fn seconds(s: f64) -> Time {
+    Time::new().with_real_time(Some(TimeSpan::from_seconds(s)))
+}
+
+fn date(day: u32) -> DateTime<Utc> {
+    format!("2019-01-0{}T12:00:00Z", day).parse().unwrap()
+}
+
+fn run() -> Run {
+    let mut run = Run::new();
+    let mut segment = Segment::new("A");
+    segment.set_best_segment_time(seconds(10.0));
+    for (index, &time) in [10.0, 20.0, 30.0, 40.0].iter().enumerate() {
+        let index = index as i32 + 1;
+        segment.segment_history_mut().insert(index, seconds(time));
+    }
+    run.push_segment(segment);
+
+    for day in 1..=4 {
+        let started = AtomicDateTime::new(date(day), false);
+        run.add_attempt(Time::new(), Some(started), None, None);
+    }
+
+    run.regenerate_comparisons();
+    run
+}
+
+fn best_segments(run: &Run) -> Option<TimeSpan> {
+    run.segment(0).comparison(best_segments::NAME).real_time
+}
+
+#[test]
+fn no_filter_uses_the_whole_history() {
+    let run = run();
+    assert!(!run.history_filter().is_active());
+    assert_eq!(best_segments(&run), Some(TimeSpan::from_seconds(10.0)));
+}
+
+#[test]
+fn last_attempts() {
+    let mut run = run();
+    let mut filter = HistoryFilter::new();
+    filter.set_last_attempts(Some(2));
+    run.set_history_filter(filter);
+    run.regenerate_comparisons();
+
+    assert_eq!(best_segments(&run), Some(TimeSpan::from_seconds(30.0)));
+    // The stored history is left untouched.
+    assert_eq!(run.segment(0).segment_history().iter().count(), 4);
+    assert_eq!(
+        run.segment(0).best_segment_time().real_time,
+        Some(TimeSpan::from_seconds(10.0))
+    );
+}
+
+#[test]
+fn enabling_a_generator_respects_the_filter() {
+    let mut run = run();
+    run.set_comparison_generator_enabled(average_segments::NAME, false)
+        .unwrap();
+    let mut filter = HistoryFilter::new();
+    filter.set_last_attempts(Some(2));
+    run.set_history_filter(filter);
+
+    let mut regenerated = run.clone();
+    regenerated
+        .set_comparison_generator_enabled(average_segments::NAME, true)
+        .unwrap();
+    regenerated.regenerate_comparisons();
+
+    run.set_comparison_generator_enabled(average_segments::NAME, true)
+        .unwrap();
+    let average = run.segment(0).comparison(average_segments::NAME).real_time;
+    assert!(average >= Some(TimeSpan::from_seconds(30.0)));
+    assert_eq!(
+        average,
+        regenerated
+            .segment(0)
+            .comparison(average_segments::NAME)
+            .real_time
+    );
+}
+
+#[test]
+fn since_attempt_and_excluded_attempts() {
+    let mut run = run();
+    let mut filter = HistoryFilter::new();
+    filter.set_since_attempt(Some(2));
+    filter.set_attempt_excluded(2, true);
+    run.set_history_filter(filter);
+    run.regenerate_comparisons();
+
+    assert_eq!(best_segments(&run), Some(TimeSpan::from_seconds(30.0)));
+
+    let segments = run.filtered_segments();
+    let indices = segments[0]
+        .segment_history()
+        .iter()
+        .map(|&(index, _)| index)
+        .collect::<Vec<_>>();
+    assert_eq!(indices, [3, 4]);
+}
+
+#[test]
+fn pb_chance_only_considers_the_allowed_attempts() {
+    let mut run = run();
+    run.segment_mut(0).personal_best_split_time_mut().real_time =
+        Some(TimeSpan::from_seconds(25.0));
+    let unfiltered = pb_chance::for_run(&run, TimingMethod::RealTime);
+
+    let mut filter = HistoryFilter::new();
+    filter.set_last_attempts(Some(2));
+    run.set_history_filter(filter);
+
+    let mut filtered = run.clone();
+    let segments = run.filtered_segments().into_owned();
+    *filtered.segments_mut() = segments;
+    filtered.set_history_filter(HistoryFilter::new());
+
+    let chance = pb_chance::for_run(&run, TimingMethod::RealTime);
+    assert!(chance < unfiltered);
+    assert_eq!(
+        chance,
+        pb_chance::for_run(&filtered, TimingMethod::RealTime)
+    );
+}
+
+#[test]
+fn since_date() {
+    let mut editor = Editor::new(run()).unwrap();
+    let mut filter = HistoryFilter::new();
+    filter.set_since(Some(date(2)));
+    editor.set_history_filter(filter);
+    editor.set_attempt_excluded(2, true);
+
+    let run = editor.close();
+    assert!(run.has_been_modified());
+    assert_eq!(best_segments(&run), Some(TimeSpan::from_seconds(30.0)));
+}
+
+#[test]
+fn filter_survives_saving_and_parsing() {
+    let mut run = run();
+    let mut filter = HistoryFilter::new();
+    filter.set_since(Some(date(2)));
+    filter.set_last_attempts(Some(3));
+    filter.set_since_attempt(Some(2));
+    filter.set_attempt_excluded(3, true);
+    run.set_history_filter(filter.clone());
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let mut parsed = parser::livesplit::parse(buf.as_slice(), None).unwrap();
+    parsed.regenerate_comparisons();
+
+    assert_eq!(parsed.history_filter(), &filter);
+    assert_eq!(best_segments(&parsed), Some(TimeSpan::from_seconds(20.0)));
+}
+
+#[test]
+fn combined_segment_times_are_not_best_segments() {
+    let mut run = Run::new();
+    let mut first = Segment::new("A");
+    first.segment_history_mut().insert(1, seconds(10.0));
+    // The split got skipped in the second attempt.
+    first.segment_history_mut().insert(2, Time::new());
+    let mut second = Segment::new("B");
+    second.segment_history_mut().insert(1, seconds(20.0));
+    second.segment_history_mut().insert(2, seconds(15.0));
+    run.push_segment(first);
+    run.push_segment(second);
+    for _ in 0..2 {
+        run.add_attempt(Time::new(), None, None, None);
+    }
+
+    let mut filter = HistoryFilter::new();
+    filter.set_last_attempts(Some(2));
+    run.set_history_filter(filter);
+
+    let segments = run.filtered_segments();
+    assert_eq!(
+        segments[1].best_segment_time().real_time,
+        Some(TimeSpan::from_seconds(20.0))
+    );
+}
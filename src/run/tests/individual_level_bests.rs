@@ -0,0 +1,54 @@
+use crate::run::{parser, saver};
+use crate::{Run, Segment, Time, TimeSpan, TimingMethod};
+
+fn run() -> Run {
+    let mut run = Run::new();
+    run.push_segment(Segment::new("A"));
+    run.push_segment(Segment::new("B"));
+    run.push_segment(Segment::new("C"));
+    run
+}
+
+fn time(seconds: f64) -> Time {
+    Time::new().with_real_time(Some(TimeSpan::from_seconds(seconds)))
+}
+
+#[test]
+fn only_faster_times_are_stored() {
+    let mut run = run();
+    assert!(run.update_individual_level_best(0, 1, time(10.0), TimingMethod::RealTime));
+    assert!(!run.update_individual_level_best(0, 1, time(11.0), TimingMethod::RealTime));
+    assert!(run.update_individual_level_best(0, 1, time(9.0), TimingMethod::RealTime));
+    assert!(!run.update_individual_level_best(0, 1, time(8.0), TimingMethod::GameTime));
+    assert_eq!(run.individual_level_bests().len(), 1);
+    assert_eq!(run.individual_level_best(0, 1).unwrap().time(), time(9.0));
+}
+
+#[test]
+fn only_the_time_of_the_timing_method_compared_is_replaced() {
+    let mut run = run();
+    let both = time(10.0).with_game_time(Some(TimeSpan::from_seconds(8.0)));
+    assert!(run.update_individual_level_best(0, 1, both, TimingMethod::RealTime));
+
+    let faster_game_time = time(12.0).with_game_time(Some(TimeSpan::from_seconds(7.0)));
+    assert!(run.update_individual_level_best(0, 1, faster_game_time, TimingMethod::GameTime));
+    assert_eq!(
+        run.individual_level_best(0, 1).unwrap().time(),
+        time(10.0).with_game_time(Some(TimeSpan::from_seconds(7.0)))
+    );
+}
+
+#[test]
+fn survive_saving_and_parsing() {
+    let mut run = run();
+    run.update_individual_level_best(1, 2, time(12.5), TimingMethod::RealTime);
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(&run, &mut buf).unwrap();
+    let parsed = parser::livesplit::parse(buf.as_slice(), None).unwrap();
+
+    assert_eq!(
+        parsed.individual_level_bests(),
+        run.individual_level_bests()
+    );
+}
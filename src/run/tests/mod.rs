@@ -1,2 +1,5 @@
+mod comparison_generators;
 mod empty_run;
+mod history_filter;
+mod individual_level_bests;
 mod metadata;
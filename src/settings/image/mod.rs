@@ -39,23 +39,30 @@ where
     }
 }
 
+fn serialize_data<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    if serializer.is_human_readable() {
+        if !data.is_empty() {
+            serializer.collect_str(&format_args!(
+                "data:;base64,{}",
+                Base64Display::with_config(data, STANDARD)
+            ))
+        } else {
+            serializer.serialize_str("")
+        }
+    } else {
+        serializer.serialize_bytes(data)
+    }
+}
+
 impl Serialize for ImageData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        if serializer.is_human_readable() {
-            if !self.0.is_empty() {
-                serializer.collect_str(&format_args!(
-                    "data:;base64,{}",
-                    Base64Display::with_config(&self.0, STANDARD)
-                ))
-            } else {
-                serializer.serialize_str("")
-            }
-        } else {
-            serializer.serialize_bytes(&self.0)
-        }
+        serialize_data(&self.0, serializer)
     }
 }
 
@@ -81,6 +88,30 @@ impl<'de> Deserialize<'de> for ImageData {
     }
 }
 
+impl Serialize for Image {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_data(&self.data, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Image {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // The image has already been shrunk before it got serialized, so it
+        // is stored as is.
+        let ImageData(data) = Deserialize::deserialize(deserializer)?;
+        Ok(Image {
+            data: data.into_vec(),
+            id: LAST_IMAGE_ID.fetch_add(1, Ordering::Relaxed),
+        })
+    }
+}
+
 impl PartialEq for Image {
     fn eq(&self, other: &Image) -> bool {
         self.id == other.id || self.data == other.data
@@ -110,6 +141,19 @@ impl Image {
         image
     }
 
+    /// Creates a new image with a unique ID with the image data provided.
+    /// Unlike icons, which are shrunk to a small size, the image is only
+    /// shrunk if its width or height exceed the maximum size provided. This is
+    /// useful for images that are shown a lot larger than icons.
+    pub fn with_max_size(data: &[u8], max_size: u32) -> Self {
+        let mut image = Image {
+            data: Vec::new(),
+            id: 0,
+        };
+        image.modify_with_max_size(data, max_size);
+        image
+    }
+
     /// Loads an image from the file system. You need to provide a buffer used
     /// for temporarily storing the image's data.
     pub fn from_file<P, B>(path: P, mut buf: B) -> io::Result<Image>
@@ -153,12 +197,15 @@ impl Image {
     /// Modifies an image by replacing its image data with the new image data
     /// provided. The image's ID changes to a new unique ID.
     pub fn modify(&mut self, data: &[u8]) {
-        #[cfg(feature = "image-shrinking")]
-        let data = {
-            const MAX_IMAGE_SIZE: u32 = 128;
+        const MAX_ICON_SIZE: u32 = 128;
 
-            shrinking::shrink(data, MAX_IMAGE_SIZE)
-        };
+        self.modify_with_max_size(data, MAX_ICON_SIZE);
+    }
+
+    #[cfg_attr(not(feature = "image-shrinking"), allow(unused_variables))]
+    fn modify_with_max_size(&mut self, data: &[u8], max_size: u32) {
+        #[cfg(feature = "image-shrinking")]
+        let data = shrinking::shrink(data, max_size);
         self.id = LAST_IMAGE_ID.fetch_add(1, Ordering::Relaxed);
         self.data.clear();
         self.data.extend_from_slice(&*data);
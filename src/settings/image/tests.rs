@@ -1,7 +1,55 @@
-use super::ImageData;
+use super::{Image, ImageData};
 
 #[test]
 fn serializes_to_json_as_data_url() {
     let json = serde_json::to_string(&ImageData(vec![1, 2, 3].into_boxed_slice())).unwrap();
     assert_eq!(r#""data:;base64,AQID""#, json);
 }
+
+#[test]
+fn image_roundtrips_through_json() {
+    let image = Image::from([1, 2, 3]);
+    let json = serde_json::to_string(&image).unwrap();
+    assert_eq!(r#""data:;base64,AQID""#, json);
+
+    let deserialized: Image = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.data(), image.data());
+    assert_ne!(deserialized.id(), image.id());
+}
+
+#[cfg(feature = "image-shrinking")]
+fn png(width: u32, height: u32) -> Vec<u8> {
+    use image::{png::PNGEncoder, ColorType};
+
+    let mut data = Vec::new();
+    PNGEncoder::new(&mut data)
+        .encode(
+            &vec![0xFF; (width * height * 4) as usize],
+            width,
+            height,
+            ColorType::RGBA(8),
+        )
+        .unwrap();
+    data
+}
+
+#[cfg(feature = "image-shrinking")]
+fn dimensions(data: &[u8]) -> (u64, u64) {
+    use image::{png::PNGDecoder, ImageDecoder};
+
+    PNGDecoder::new(data).unwrap().dimensions()
+}
+
+#[cfg(feature = "image-shrinking")]
+#[test]
+fn only_images_exceeding_the_max_size_are_shrunk() {
+    let small = png(200, 100);
+    assert_eq!(Image::with_max_size(&small, 1024).data(), &*small);
+    assert_eq!(dimensions(Image::new(&small).data()), (128, 64));
+
+    let large = png(2048, 512);
+    assert_eq!(
+        dimensions(Image::with_max_size(&large, 1024).data()),
+        (1024, 256)
+    );
+}
@@ -1,8 +1,12 @@
 use crate::{
-    component::splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+    component::{
+        graph::GraphMode,
+        image::ImageScaling,
+        splits::{ColumnStartWith, ColumnUpdateTrigger, ColumnUpdateWith},
+    },
     hotkey::KeyCode,
     layout::LayoutDirection,
-    settings::{Alignment, Color, Gradient, ListGradient},
+    settings::{Alignment, Color, Gradient, Image, ListGradient},
     timing::formatter::{Accuracy, DigitsFormat},
     TimingMethod,
 };
@@ -55,6 +59,12 @@ pub enum Value {
     Hotkey(Option<KeyCode>),
     /// A value describing the direction of a layout.
     LayoutDirection(LayoutDirection),
+    /// An image.
+    Image(Image),
+    /// A value describing how the Image Component scales its image.
+    ImageScaling(ImageScaling),
+    /// A value describing what the Graph Component visualizes.
+    GraphMode(GraphMode),
 }
 
 /// The Error type for values that couldn't be converted.
@@ -228,6 +238,32 @@ impl Value {
             _ => Err(Error::WrongType),
         }
     }
+
+    /// Tries to convert the value into an image.
+    pub fn into_image(self) -> Result<Image> {
+        match self {
+            Value::Image(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a value describing how the Image
+    /// Component scales its image.
+    pub fn into_image_scaling(self) -> Result<ImageScaling> {
+        match self {
+            Value::ImageScaling(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
+
+    /// Tries to convert the value into a value describing what the Graph
+    /// Component visualizes.
+    pub fn into_graph_mode(self) -> Result<GraphMode> {
+        match self {
+            Value::GraphMode(v) => Ok(v),
+            _ => Err(Error::WrongType),
+        }
+    }
 }
 
 impl Into<bool> for Value {
@@ -343,3 +379,21 @@ impl Into<LayoutDirection> for Value {
         self.into_layout_direction().unwrap()
     }
 }
+
+impl Into<Image> for Value {
+    fn into(self) -> Image {
+        self.into_image().unwrap()
+    }
+}
+
+impl Into<ImageScaling> for Value {
+    fn into(self) -> ImageScaling {
+        self.into_image_scaling().unwrap()
+    }
+}
+
+impl Into<GraphMode> for Value {
+    fn into(self) -> GraphMode {
+        self.into_graph_mode().unwrap()
+    }
+}
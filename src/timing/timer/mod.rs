@@ -1,6 +1,7 @@
 use crate::comparison::personal_best;
 use crate::TimerPhase::*;
 use crate::{AtomicDateTime, Run, Segment, Time, TimeSpan, TimeStamp, TimerPhase, TimingMethod};
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use std::mem;
 use std::sync::Arc;
@@ -57,6 +58,22 @@ pub struct Timer {
     is_game_time_paused: bool,
     game_time_pause_time: Option<TimeSpan>,
     loading_times: Option<TimeSpan>,
+    practice_mode: bool,
+    individual_level: Option<IndividualLevel>,
+    countdown: Option<Countdown>,
+    runtime_values: IndexMap<String, String>,
+}
+
+#[derive(Debug, Clone)]
+struct IndividualLevel {
+    start_index: usize,
+    end_index: usize,
+    run: Run,
+}
+
+#[derive(Debug, Clone)]
+struct Countdown {
+    run_was_modified: bool,
 }
 
 /// A Shared Timer is a wrapper around the Timer that can be shared across
@@ -70,6 +87,45 @@ pub enum CreationError {
     EmptyRun,
 }
 
+/// The Error type for starting an individual level attempt.
+#[derive(Debug, snafu::Snafu)]
+pub enum IndividualLevelError {
+    /// There already is an attempt in progress.
+    AttemptInProgress,
+    /// The range of segments is not within the bounds of the Run's segments.
+    InvalidRange,
+}
+
+/// The Error type for correcting the split time of a segment.
+#[derive(Debug, PartialEq, snafu::Snafu)]
+pub enum SplitTimeError {
+    /// There is no attempt in progress.
+    NoAttemptInProgress,
+    /// The segment has not been completed yet in the current attempt.
+    SegmentNotCompleted,
+    /// The split time is not in between the split times of the surrounding
+    /// segments.
+    OutOfOrder,
+}
+
+/// Describes what happens to the split times of the later segments when the
+/// split time of a segment gets corrected.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LaterSplitTimes {
+    /// The split times of the later segments are kept as they are. The
+    /// correction is rejected if they wouldn't be in order anymore.
+    Keep,
+    /// The split times of the later segments are shifted by the same amount
+    /// as the corrected split time, so that their segment times are kept. A
+    /// skipped segment has no split time to shift from, so the later split
+    /// times are kept in that case.
+    Shift,
+    /// The split times of the later segments that would be lower than the
+    /// corrected split time are cleared, as if the segments were skipped.
+    Clear,
+}
+
 impl Timer {
     /// Creates a new Timer based on a Run object storing all the information
     /// about the splits. The Run object needs to have at least one segment, so
@@ -99,6 +155,10 @@ impl Timer {
             is_game_time_paused: false,
             game_time_pause_time: None,
             loading_times: None,
+            practice_mode: false,
+            individual_level: None,
+            countdown: None,
+            runtime_values: IndexMap::new(),
         })
     }
 
@@ -146,12 +206,33 @@ impl Timer {
         self.replace_run(run, false).map(drop)
     }
 
-    /// Accesses the Run in use by the Timer.
+    /// Accesses the Run in use by the Timer. This is always the full Run, even
+    /// while an individual level attempt is in progress, so it can be saved at
+    /// any time.
     #[inline]
     pub fn run(&self) -> &Run {
         &self.run
     }
 
+    /// Accesses the Run that the current attempt is timed against. While an
+    /// individual level attempt is in progress, this is the Run consisting of
+    /// only the segments of the individual level. Otherwise this is the same
+    /// as the Run in use by the Timer. All the components and the analysis of
+    /// the current attempt are based on this Run.
+    #[inline]
+    pub fn timed_run(&self) -> &Run {
+        self.individual_level
+            .as_ref()
+            .map_or(&self.run, |individual_level| &individual_level.run)
+    }
+
+    fn timed_run_mut(&mut self) -> &mut Run {
+        match &mut self.individual_level {
+            Some(individual_level) => &mut individual_level.run,
+            None => &mut self.run,
+        }
+    }
+
     /// Marks the Run as unmodified, so that it is known that all the changes
     /// have been saved.
     #[inline]
@@ -165,19 +246,104 @@ impl Timer {
         self.phase
     }
 
+    /// Returns whether the Timer is in practice mode. Attempts done in practice
+    /// mode don't count as attempts and are never stored in the Run's history.
+    /// Everything else, like the comparisons and the analysis of the current
+    /// attempt, works the same way as usual.
+    #[inline]
+    pub fn is_in_practice_mode(&self) -> bool {
+        self.practice_mode
+    }
+
+    /// Enables or disables practice mode. In practice mode starting an attempt
+    /// doesn't increment the attempt count and resetting discards the attempt
+    /// entirely, so no Attempt History, Segment History, Best Segments or
+    /// Personal Best are updated. Practice mode can only be changed while there
+    /// is no attempt in progress. If that's not the case, nothing happens.
+    pub fn set_practice_mode(&mut self, practice_mode: bool) {
+        if self.phase == NotRunning {
+            self.practice_mode = practice_mode;
+        }
+    }
+
+    /// Toggles practice mode if there is no attempt in progress. If that's not
+    /// the case, nothing happens.
+    pub fn toggle_practice_mode(&mut self) {
+        let practice_mode = !self.practice_mode;
+        self.set_practice_mode(practice_mode);
+    }
+
+    /// Returns the range of segments of the full Run that the current
+    /// individual level attempt covers, as the index of the first and the
+    /// index of the last segment. If there's no individual level attempt in
+    /// progress, `None` is returned.
+    #[inline]
+    pub fn individual_level(&self) -> Option<(usize, usize)> {
+        self.individual_level
+            .as_ref()
+            .map(|il| (il.start_index, il.end_index))
+    }
+
+    /// Starts an individual level attempt that covers the segments from the
+    /// start index to the end index of the Run, both inclusive. For the
+    /// duration of the attempt, the Timer is timed against a Run that only
+    /// consists of these segments, with all the comparisons shifted to start at the
+    /// beginning of the first segment. So all the deltas, the possible time
+    /// save and the components are calculated relative to the individual
+    /// level. Individual level attempts are never stored in the Run's history.
+    /// Instead only the Individual Level Best of the range is updated when a
+    /// finished attempt is reset with the splits being updated.
+    pub fn start_individual_level(
+        &mut self,
+        start_index: usize,
+        end_index: usize,
+    ) -> Result<(), IndividualLevelError> {
+        if self.phase != NotRunning {
+            return Err(IndividualLevelError::AttemptInProgress);
+        }
+
+        let run = self
+            .run
+            .individual_level(start_index, end_index)
+            .ok_or(IndividualLevelError::InvalidRange)?;
+
+        self.individual_level = Some(IndividualLevel {
+            start_index,
+            end_index,
+            run,
+        });
+        self.start();
+
+        Ok(())
+    }
+
     /// Returns the current time of the Timer. The Game Time is None if the
     /// Game Time has not been initialized.
     pub fn current_time(&self) -> Time {
         let real_time = match self.phase {
-            NotRunning => Some(self.run.offset()),
+            NotRunning => Some(self.timed_run().offset()),
             Running => Some(TimeStamp::now() - self.adjusted_start_time),
             Paused => Some(self.time_paused_at),
-            Ended => self.run.segments().last().unwrap().split_time().real_time,
+            Ended => {
+                self.timed_run()
+                    .segments()
+                    .last()
+                    .unwrap()
+                    .split_time()
+                    .real_time
+            }
         };
 
         let game_time = match self.phase {
-            NotRunning => Some(self.run.offset()),
-            Ended => self.run.segments().last().unwrap().split_time().game_time,
+            NotRunning => Some(self.timed_run().offset()),
+            Ended => {
+                self.timed_run()
+                    .segments()
+                    .last()
+                    .unwrap()
+                    .split_time()
+                    .game_time
+            }
             _ => {
                 if self.is_game_time_paused() {
                     self.game_time_pause_time
@@ -227,7 +393,7 @@ impl Timer {
     #[inline]
     pub fn set_current_comparison<S: AsRef<str>>(&mut self, comparison: S) -> Result<(), ()> {
         let comparison = comparison.as_ref();
-        if self.run.comparisons().any(|c| c == comparison) {
+        if self.timed_run().comparisons().any(|c| c == comparison) {
             self.current_comparison.clear();
             self.current_comparison.push_str(comparison);
             Ok(())
@@ -240,12 +406,12 @@ impl Timer {
     /// progress or the run finished, `None` is returned instead.
     pub fn current_split(&self) -> Option<&Segment> {
         self.current_split_index
-            .and_then(|i| self.run.segments().get(i))
+            .and_then(|i| self.timed_run().segments().get(i))
     }
 
     fn current_split_mut(&mut self) -> Option<&mut Segment> {
         self.current_split_index
-            .and_then(move |i| self.run.segments_mut().get_mut(i))
+            .and_then(move |i| self.timed_run_mut().segments_mut().get_mut(i))
     }
 
     /// Accesses the index of the split the attempt is currently on. If there's
@@ -263,16 +429,83 @@ impl Timer {
             self.current_split_index = Some(0);
             self.attempt_started = Some(AtomicDateTime::now());
             self.start_time = TimeStamp::now();
-            self.start_time_with_offset = self.start_time - self.run.offset();
+            self.start_time_with_offset = self.start_time - self.timed_run().offset();
             self.adjusted_start_time = self.start_time_with_offset;
-            self.time_paused_at = self.run.offset();
+            self.time_paused_at = self.timed_run().offset();
             self.deinitialize_game_time();
-            self.run.start_next_run();
+            self.countdown = if self.timed_run().offset() < TimeSpan::zero() {
+                Some(Countdown {
+                    run_was_modified: self.timed_run().has_been_modified(),
+                })
+            } else {
+                None
+            };
+            if !self.practice_mode {
+                self.timed_run_mut().start_next_run();
+            }
 
             // FIXME: OnStart
         }
     }
 
+    /// Returns whether the current attempt is counting down. This is the case
+    /// if the attempt got started with a negative offset and the current time
+    /// is still negative.
+    pub fn is_counting_down(&self) -> bool {
+        self.countdown.is_some()
+            && self.phase != NotRunning
+            && self
+                .current_time()
+                .real_time
+                .map_or(false, |t| t < TimeSpan::zero())
+    }
+
+    /// Checks whether the countdown of the current attempt has reached zero.
+    /// This returns `true` exactly once for each attempt that started with a
+    /// countdown, the first time this gets checked after the countdown ended
+    /// while the Timer is running. The countdown can't end while the Timer is
+    /// paused, so resuming the Timer is necessary for this to ever return
+    /// `true` again. Resetting or cancelling the attempt before the countdown
+    /// reached zero means that this never returns `true` for the attempt.
+    pub fn poll_countdown_end(&mut self) -> bool {
+        if self.countdown.is_some()
+            && self.phase == Running
+            && self
+                .current_time()
+                .real_time
+                .map_or(false, |t| t >= TimeSpan::zero())
+        {
+            self.countdown = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Cancels the current attempt if it's still counting down. Unlike
+    /// resetting, this doesn't consume an attempt, as the attempt count is
+    /// restored to what it was before the attempt got started. If the Timer is
+    /// not counting down, nothing happens.
+    pub fn cancel_countdown(&mut self) {
+        if !self.is_counting_down() {
+            return;
+        }
+
+        let countdown = self.countdown.take().unwrap();
+
+        self.resume_game_time();
+        self.set_loading_times(TimeSpan::zero());
+        self.reset_splits();
+
+        if !self.practice_mode {
+            let attempt_count = self.run.attempt_count();
+            self.run.set_attempt_count(attempt_count.saturating_sub(1));
+            if !countdown.run_was_modified {
+                self.run.mark_as_unmodified();
+            }
+        }
+    }
+
     /// If an attempt is in progress, stores the current time as the time of the
     /// current split. The attempt ends if the last split time is stored.
     pub fn split(&mut self) {
@@ -286,11 +519,11 @@ impl Timer {
                 .unwrap()
                 .set_split_time(current_time);
             *self.current_split_index.as_mut().unwrap() += 1;
-            if Some(self.run.len()) == self.current_split_index {
+            if Some(self.timed_run().len()) == self.current_split_index {
                 self.phase = Ended;
                 self.attempt_ended = Some(AtomicDateTime::now());
             }
-            self.run.mark_as_modified();
+            self.mark_attempt_as_modified();
 
             // FIXME: OnSplit
         }
@@ -310,11 +543,11 @@ impl Timer {
     /// current split is not the last split.
     pub fn skip_split(&mut self) {
         if (self.phase == Running || self.phase == Paused)
-            && self.current_split_index < self.run.len().checked_sub(1)
+            && self.current_split_index < self.timed_run().len().checked_sub(1)
         {
             self.current_split_mut().unwrap().clear_split_time();
             self.current_split_index = self.current_split_index.map(|i| i + 1);
-            self.run.mark_as_modified();
+            self.mark_attempt_as_modified();
 
             // FIXME: OnSkipSplit
         }
@@ -330,30 +563,128 @@ impl Timer {
             }
             self.current_split_index = self.current_split_index.map(|i| i - 1);
             self.current_split_mut().unwrap().clear_split_time();
-            self.run.mark_as_modified();
+            self.mark_attempt_as_modified();
 
             // FIXME: OnUndoSplit
         }
     }
 
+    /// Corrects the split time of an already completed segment of the current
+    /// attempt for the Timing Method provided. This can be used when a split
+    /// happened too late or too early and the correct time is known. The split
+    /// times of the later segments are kept, shifted or cleared as specified,
+    /// so that the split times keep increasing monotonically. The split time
+    /// can't be lower than the split time of the segment before it and, while
+    /// the attempt is still going, the split times also can't exceed the
+    /// current time. Skipped segments can be assigned a split time this way as
+    /// well. All the deltas and segment times are based on the split times, so
+    /// they immediately reflect the correction. New best segments get stored
+    /// when the attempt is reset, like with any other split.
+    pub fn set_split_time(
+        &mut self,
+        segment_index: usize,
+        method: TimingMethod,
+        time: TimeSpan,
+        later_split_times: LaterSplitTimes,
+    ) -> Result<(), SplitTimeError> {
+        let current_split_index = match self.current_split_index {
+            Some(index) if self.phase != NotRunning => index,
+            _ => return Err(SplitTimeError::NoAttemptInProgress),
+        };
+
+        if segment_index >= current_split_index {
+            return Err(SplitTimeError::SegmentNotCompleted);
+        }
+
+        let current_time = if self.phase == Ended {
+            None
+        } else {
+            self.current_time()[method]
+        };
+
+        let segments = self.timed_run().segments();
+
+        let lower_bound = segments[..segment_index]
+            .iter()
+            .rev()
+            .find_map(|s| s.split_time()[method])
+            .unwrap_or_else(TimeSpan::zero);
+
+        let mut later_times = segments[segment_index + 1..current_split_index]
+            .iter()
+            .map(|s| s.split_time()[method])
+            .collect::<Vec<_>>();
+
+        match later_split_times {
+            LaterSplitTimes::Keep => {}
+            LaterSplitTimes::Shift => {
+                if let Some(old_time) = segments[segment_index].split_time()[method] {
+                    for later_time in later_times.iter_mut().flatten() {
+                        *later_time += time - old_time;
+                    }
+                }
+            }
+            LaterSplitTimes::Clear => {
+                for later_time in &mut later_times {
+                    if later_time.map_or(false, |t| t < time) {
+                        *later_time = None;
+                    }
+                }
+            }
+        }
+
+        let next_time = later_times.iter().find_map(|&t| t);
+        let last_time = later_times.iter().rev().find_map(|&t| t).unwrap_or(time);
+
+        if time < lower_bound
+            || next_time.map_or(false, |next| time > next)
+            || current_time.map_or(false, |current| last_time > current)
+        {
+            return Err(SplitTimeError::OutOfOrder);
+        }
+
+        let run = self.timed_run_mut();
+        let times = Some(Some(time)).into_iter().chain(later_times);
+        for (segment, time) in run.segments_mut()[segment_index..].iter_mut().zip(times) {
+            let mut split_time = segment.split_time();
+            split_time[method] = time;
+            segment.set_split_time(split_time);
+        }
+
+        self.mark_attempt_as_modified();
+
+        Ok(())
+    }
+
     /// Resets the current attempt if there is one in progress. If the splits
     /// are to be updated, all the information of the current attempt is stored
     /// in the Run's history. Otherwise the current attempt's information is
-    /// discarded.
+    /// discarded. In practice mode the attempt is always discarded.
     pub fn reset(&mut self, update_splits: bool) {
         if self.phase != NotRunning {
-            self.reset_state(update_splits);
-            self.reset_splits();
+            if self.individual_level.is_some() {
+                self.reset_individual_level(update_splits, false);
+            } else {
+                self.reset_state(update_splits);
+                self.reset_splits();
+            }
         }
     }
 
     /// Resets the current attempt if there is one in progress. The splits are
     /// updated such that the current attempt's split times are being stored as
-    /// the new Personal Best.
+    /// the new Personal Best. In practice mode the attempt is discarded
+    /// instead.
     pub fn reset_and_set_attempt_as_pb(&mut self) {
         if self.phase != NotRunning {
+            if self.individual_level.is_some() {
+                self.reset_individual_level(true, true);
+                return;
+            }
             self.reset_state(true);
-            self.set_run_as_pb();
+            if !self.practice_mode {
+                self.set_run_as_pb();
+            }
             self.reset_splits();
         }
     }
@@ -365,7 +696,7 @@ impl Timer {
         self.resume_game_time();
         self.set_loading_times(TimeSpan::zero());
 
-        if update_times {
+        if update_times && !self.practice_mode {
             self.update_attempt_history();
             self.update_best_segments();
             self.update_pb_splits();
@@ -373,9 +704,51 @@ impl Timer {
         }
     }
 
+    fn reset_individual_level(&mut self, update_best: bool, force_best: bool) {
+        if self.phase != Ended {
+            self.attempt_ended = Some(AtomicDateTime::now());
+        }
+        self.resume_game_time();
+        self.set_loading_times(TimeSpan::zero());
+
+        let individual_level = self.individual_level.take().unwrap();
+
+        if update_best && !self.practice_mode && self.phase == Ended {
+            let time = individual_level.run.segments().last().unwrap().split_time();
+            let (start_index, end_index) =
+                (individual_level.start_index, individual_level.end_index);
+
+            let method = self.current_timing_method;
+
+            // The stored best time is only discarded if there's a new time to
+            // replace it with.
+            if force_best && time[method].is_some() {
+                if let Some(best) = self
+                    .run
+                    .individual_level_bests_mut()
+                    .iter_mut()
+                    .find(|b| b.is_range(start_index, end_index))
+                {
+                    let mut best_time = best.time();
+                    best_time[method] = None;
+                    best.set_time(best_time);
+                }
+            }
+            self.run
+                .update_individual_level_best(start_index, end_index, time, method);
+        }
+
+        self.phase = NotRunning;
+        self.current_split_index = None;
+        self.countdown = None;
+
+        // FIXME: OnReset
+    }
+
     fn reset_splits(&mut self) {
         self.phase = NotRunning;
         self.current_split_index = None;
+        self.countdown = None;
 
         // Reset Splits
         for segment in self.run.segments_mut() {
@@ -445,7 +818,7 @@ impl Timer {
                 let pause_time = Some(self.get_pause_time().unwrap_or_default());
 
                 let split_time = self
-                    .run
+                    .timed_run_mut()
                     .segments_mut()
                     .iter_mut()
                     .last()
@@ -466,26 +839,36 @@ impl Timer {
 
     /// Switches the current comparison to the next comparison in the list.
     pub fn switch_to_next_comparison(&mut self) {
-        let mut comparisons = self.run.comparisons();
+        let mut comparisons = self.timed_run().comparisons();
         let len = comparisons.len();
         let index = comparisons
             .position(|c| c == self.current_comparison)
             .unwrap();
         let index = (index + 1) % len;
-        self.current_comparison = self.run.comparisons().nth(index).unwrap().to_owned();
+        self.current_comparison = self
+            .timed_run()
+            .comparisons()
+            .nth(index)
+            .unwrap()
+            .to_owned();
 
         // FIXME: OnNextComparison
     }
 
     /// Switches the current comparison to the previous comparison in the list.
     pub fn switch_to_previous_comparison(&mut self) {
-        let mut comparisons = self.run.comparisons();
+        let mut comparisons = self.timed_run().comparisons();
         let len = comparisons.len();
         let index = comparisons
             .position(|c| c == self.current_comparison)
             .unwrap();
         let index = (index + len - 1) % len;
-        self.current_comparison = self.run.comparisons().nth(index).unwrap().to_owned();
+        self.current_comparison = self
+            .timed_run()
+            .comparisons()
+            .nth(index)
+            .unwrap()
+            .to_owned();
 
         // FIXME: OnPreviousComparison
     }
@@ -505,6 +888,16 @@ impl Timer {
         }
     }
 
+    /// Returns the point in time the current attempt was started at. If there
+    /// is no active attempt, `None` is returned.
+    pub fn attempt_started(&self) -> Option<AtomicDateTime> {
+        if self.current_phase() == NotRunning {
+            None
+        } else {
+            self.attempt_started
+        }
+    }
+
     /// Returns the total amount of time the current attempt has been paused
     /// for. None is returned if there have not been any pauses.
     pub fn get_pause_time(&self) -> Option<TimeSpan> {
@@ -595,6 +988,42 @@ impl Timer {
         }
     }
 
+    /// Accesses the runtime values in the order they were first set. Runtime
+    /// values are named values that describe the current state of the game,
+    /// like the amount of collectibles, and are usually provided by an auto
+    /// splitter. Unlike the variables in the Run's metadata, they are never
+    /// stored in the Run.
+    #[inline]
+    pub fn runtime_values(&self) -> &IndexMap<String, String> {
+        &self.runtime_values
+    }
+
+    /// Sets the runtime value with the name provided. If there's no runtime
+    /// value with that name yet, it is added after all the other ones.
+    pub fn set_runtime_value<N, V>(&mut self, name: N, value: V)
+    where
+        N: Into<String>,
+        V: Into<String>,
+    {
+        self.runtime_values.insert(name.into(), value.into());
+    }
+
+    /// Removes the runtime value with the name provided. If there's no runtime
+    /// value with that name, nothing happens.
+    pub fn remove_runtime_value<S>(&mut self, name: S)
+    where
+        S: AsRef<str>,
+    {
+        self.runtime_values.shift_remove(name.as_ref());
+    }
+
+    fn mark_attempt_as_modified(&mut self) {
+        // Practice attempts are discarded, so they never modify the Run.
+        if !self.practice_mode {
+            self.timed_run_mut().mark_as_modified();
+        }
+    }
+
     fn update_attempt_history(&mut self) {
         let time = if self.phase == Ended {
             self.current_time()
@@ -644,7 +1073,7 @@ impl Timer {
     fn update_pb_splits(&mut self) {
         let method = self.current_timing_method;
         let (split_time, pb_split_time) = {
-            let last_segment = self.run.segments().last().unwrap();
+            let last_segment = self.timed_run().segments().last().unwrap();
             (
                 last_segment.split_time()[method],
                 last_segment.personal_best_split_time()[method],
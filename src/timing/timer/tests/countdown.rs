@@ -0,0 +1,90 @@
+use super::run;
+use crate::component::timer::{Component, Settings};
+use crate::{TimeSpan, Timer};
+use std::thread;
+use std::time::Duration;
+
+fn timer_with_offset(offset: TimeSpan) -> Timer {
+    let mut run = run();
+    run.set_offset(offset);
+    Timer::new(run).unwrap()
+}
+
+#[test]
+fn cancelling_does_not_consume_an_attempt() {
+    let mut timer = timer_with_offset(TimeSpan::from_seconds(-10.0));
+
+    timer.start();
+    assert!(timer.is_counting_down());
+    assert_eq!(timer.run().attempt_count(), 1);
+
+    timer.cancel_countdown();
+    assert!(!timer.is_counting_down());
+    assert_eq!(timer.run().attempt_count(), 0);
+    assert!(timer.run().attempt_history().is_empty());
+    assert!(!timer.run().has_been_modified());
+}
+
+#[test]
+fn cancelling_only_works_while_counting_down() {
+    let mut timer = timer_with_offset(TimeSpan::zero());
+
+    timer.start();
+    assert!(!timer.is_counting_down());
+
+    timer.cancel_countdown();
+    assert_eq!(timer.run().attempt_count(), 1);
+    assert!(timer.current_split_index().is_some());
+}
+
+#[test]
+fn countdown_end_is_reported_once() {
+    let mut timer = timer_with_offset(TimeSpan::from_milliseconds(-1.0));
+
+    assert!(!timer.poll_countdown_end());
+    timer.start();
+    timer.pause();
+    assert!(!timer.poll_countdown_end());
+
+    // The countdown is short enough that waiting for it to end barely slows
+    // down the test. Sleeping guarantees that at least that much time passed.
+    timer.resume();
+    thread::sleep(Duration::from_millis(2));
+
+    assert!(!timer.is_counting_down());
+    assert!(timer.poll_countdown_end());
+    assert!(!timer.poll_countdown_end());
+}
+
+#[test]
+fn countdown_end_is_not_reported_while_paused() {
+    let mut timer = timer_with_offset(TimeSpan::from_seconds(-10.0));
+
+    timer.start();
+    timer.pause();
+
+    assert!(timer.is_counting_down());
+    assert!(!timer.poll_countdown_end());
+}
+
+#[test]
+fn timer_component_shows_the_countdown() {
+    let mut timer = timer_with_offset(TimeSpan::from_seconds(-10.0));
+    let layout_settings = Default::default();
+    let mut component = Component::with_settings(Settings {
+        countdown_color: Some((1.0, 0.0, 0.0, 1.0).into()),
+        show_gradient: false,
+        ..Default::default()
+    });
+
+    timer.start();
+    timer.pause();
+
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.time, "−9");
+    assert_eq!(state.top_color, (1.0, 0.0, 0.0, 1.0).into());
+
+    component.settings_mut().show_countdown_sign = false;
+    let state = component.state(&timer, &layout_settings);
+    assert_eq!(state.time, "9");
+}
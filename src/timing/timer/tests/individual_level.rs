@@ -0,0 +1,186 @@
+use super::timer;
+use crate::analysis::delta;
+use crate::comparison::{best_segments, personal_best};
+use crate::run::{parser, saver};
+use crate::tests_helper::run_with_splits;
+use crate::{TimeSpan, Timer, TimerPhase, TimingMethod};
+use std::io::Cursor;
+
+fn timer_with_pb() -> Timer {
+    let mut timer = timer();
+    run_with_splits(&mut timer, &[5.0, 15.0, 30.0]);
+    timer
+}
+
+fn run_individual_level(timer: &mut Timer, splits: &[f64]) {
+    timer.initialize_game_time();
+    timer.pause_game_time();
+    timer.set_game_time(TimeSpan::zero());
+    for &split in splits {
+        timer.set_game_time(TimeSpan::from_seconds(split));
+        timer.split();
+    }
+}
+
+#[test]
+fn comparisons_are_relative_to_the_range() {
+    let mut timer = timer_with_pb();
+    timer.start_individual_level(1, 2).unwrap();
+
+    assert_eq!(timer.individual_level(), Some((1, 2)));
+    assert_eq!(timer.run().len(), 3);
+    assert_eq!(timer.timed_run().len(), 2);
+
+    let split_times = |comparison| {
+        timer
+            .timed_run()
+            .segments()
+            .iter()
+            .map(|s| s.comparison(comparison).game_time)
+            .collect::<Vec<_>>()
+    };
+    let expected = [
+        Some(TimeSpan::from_seconds(10.0)),
+        Some(TimeSpan::from_seconds(25.0)),
+    ];
+    assert_eq!(split_times(personal_best::NAME), expected);
+    assert_eq!(split_times(best_segments::NAME), expected);
+
+    run_individual_level(&mut timer, &[9.0, 23.0]);
+    assert_eq!(timer.current_phase(), TimerPhase::Ended);
+    assert_eq!(
+        delta::calculate(&timer, personal_best::NAME).0,
+        Some(TimeSpan::from_seconds(-2.0))
+    );
+}
+
+#[test]
+fn resetting_only_stores_the_individual_level_best() {
+    let mut timer = timer_with_pb();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    timer.start_individual_level(1, 2).unwrap();
+    run_individual_level(&mut timer, &[9.0, 23.0]);
+    timer.reset(true);
+
+    timer.start_individual_level(1, 2).unwrap();
+    run_individual_level(&mut timer, &[9.0, 24.0]);
+    timer.reset(true);
+
+    assert_eq!(timer.individual_level(), None);
+    let run = timer.run();
+    assert_eq!(run.len(), 3);
+    assert_eq!(run.attempt_count(), 1);
+    assert_eq!(run.attempt_history().len(), 1);
+    assert_eq!(
+        run.segment(1).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(10.0))
+    );
+    assert_eq!(
+        run.segment(2).personal_best_split_time().game_time,
+        Some(TimeSpan::from_seconds(30.0))
+    );
+    assert_eq!(
+        run.individual_level_best(1, 2).unwrap().time().game_time,
+        Some(TimeSpan::from_seconds(23.0))
+    );
+}
+
+#[test]
+fn saving_during_an_attempt_keeps_the_full_run() {
+    let mut timer = timer_with_pb();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+    timer.start_individual_level(1, 2).unwrap();
+    run_individual_level(&mut timer, &[9.0]);
+
+    let mut buf = Vec::new();
+    saver::livesplit::save_run(timer.run(), &mut buf).unwrap();
+    let run = parser::livesplit::parse(Cursor::new(&buf), None).unwrap();
+    assert_eq!(run.len(), 3);
+    assert_eq!(run.attempt_history().len(), 1);
+
+    timer.mark_as_unmodified();
+    assert!(!timer.run().has_been_modified());
+    timer.set_game_time(TimeSpan::from_seconds(23.0));
+    timer.split();
+    timer.reset(true);
+    assert_eq!(timer.run().len(), 3);
+    assert!(timer.run().individual_level_best(1, 2).is_some());
+    assert!(timer.run().has_been_modified());
+}
+
+#[test]
+fn unfinished_attempts_are_discarded() {
+    let mut timer = timer_with_pb();
+    timer.start_individual_level(0, 1).unwrap();
+    run_individual_level(&mut timer, &[4.0]);
+    timer.reset(true);
+
+    assert!(timer.run().individual_level_bests().is_empty());
+    assert_eq!(timer.run().len(), 3);
+}
+
+#[test]
+fn invalid_ranges_are_rejected() {
+    let mut timer = timer_with_pb();
+    assert!(timer.start_individual_level(2, 1).is_err());
+    assert!(timer.start_individual_level(1, 3).is_err());
+    assert_eq!(timer.current_phase(), TimerPhase::NotRunning);
+
+    timer.start();
+    assert!(timer.start_individual_level(0, 1).is_err());
+}
+
+#[test]
+fn forcing_the_best_keeps_it_without_a_new_time() {
+    let mut timer = timer_with_pb();
+    timer.set_current_timing_method(TimingMethod::GameTime);
+
+    timer.start_individual_level(1, 2).unwrap();
+    run_individual_level(&mut timer, &[9.0, 23.0]);
+    timer.reset(true);
+
+    // Without Game Time, there's no time to replace the best time with.
+    timer.deinitialize_game_time();
+    timer.start_individual_level(1, 2).unwrap();
+    timer.split();
+    timer.split();
+    timer.reset_and_set_attempt_as_pb();
+
+    assert_eq!(
+        timer
+            .run()
+            .individual_level_best(1, 2)
+            .unwrap()
+            .time()
+            .game_time,
+        Some(TimeSpan::from_seconds(23.0))
+    );
+}
+
+#[test]
+fn undoing_all_pauses_adjusts_the_final_time() {
+    let mut timer = timer_with_pb();
+    timer.start_individual_level(1, 2).unwrap();
+    timer.initialize_game_time();
+    timer.split();
+    timer.pause();
+    timer.resume();
+    timer.split();
+    let final_time = timer.current_time().real_time.unwrap();
+    let pause_time = timer.get_pause_time().unwrap();
+
+    timer.undo_all_pauses();
+
+    assert_eq!(
+        timer
+            .timed_run()
+            .segments()
+            .last()
+            .unwrap()
+            .split_time()
+            .real_time,
+        Some(final_time + pause_time)
+    );
+    assert!(timer.run().segment(2).split_time().real_time.is_none());
+}
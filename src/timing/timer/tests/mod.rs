@@ -2,7 +2,11 @@ use crate::run::Editor;
 use crate::tests_helper::{run_with_splits, run_with_splits_opt, start_run};
 use crate::{Run, Segment, TimeSpan, Timer, TimerPhase, TimingMethod};
 
+mod countdown;
+mod individual_level;
 mod mark_as_modified;
+mod practice_mode;
+mod split_time_correction;
 
 fn run() -> Run {
     let mut run = Run::new();
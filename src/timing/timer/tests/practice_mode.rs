@@ -0,0 +1,70 @@
+use super::timer;
+use crate::tests_helper::{run_with_splits, start_run};
+use crate::{TimeSpan, TimerPhase};
+
+#[test]
+fn resetting_discards_the_attempt() {
+    let mut timer = timer();
+    timer.set_practice_mode(true);
+
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+
+    let run = timer.run();
+    assert_eq!(run.attempt_count(), 0);
+    assert!(run.attempt_history().is_empty());
+    assert!(!run.has_been_modified());
+    for segment in run.segments() {
+        assert!(segment.segment_history().iter().next().is_none());
+        assert_eq!(segment.best_segment_time().game_time, None);
+        assert_eq!(segment.personal_best_split_time().game_time, None);
+    }
+}
+
+#[test]
+fn setting_attempt_as_pb_discards_the_attempt() {
+    let mut timer = timer();
+    timer.set_practice_mode(true);
+
+    start_run(&mut timer);
+    timer.set_game_time(TimeSpan::from_seconds(5.0));
+    timer.split();
+    timer.reset_and_set_attempt_as_pb();
+
+    let segment = timer.run().segment(0);
+    assert_eq!(segment.personal_best_split_time().game_time, None);
+    assert_eq!(segment.best_segment_time().game_time, None);
+}
+
+#[test]
+fn can_only_be_toggled_without_an_attempt() {
+    let mut timer = timer();
+
+    timer.toggle_practice_mode();
+    assert!(timer.is_in_practice_mode());
+
+    timer.start();
+    timer.toggle_practice_mode();
+    assert!(timer.is_in_practice_mode());
+    assert_eq!(timer.current_phase(), TimerPhase::Running);
+
+    timer.reset(true);
+    timer.toggle_practice_mode();
+    assert!(!timer.is_in_practice_mode());
+}
+
+#[test]
+fn regular_attempts_are_stored_again_after_practicing() {
+    let mut timer = timer();
+    timer.set_practice_mode(true);
+    run_with_splits(&mut timer, &[5.0, 10.0, 15.0]);
+    timer.set_practice_mode(false);
+    run_with_splits(&mut timer, &[6.0, 12.0, 18.0]);
+
+    let run = timer.run();
+    assert_eq!(run.attempt_count(), 1);
+    assert_eq!(run.attempt_history().len(), 1);
+    assert_eq!(
+        run.segment(0).best_segment_time().game_time,
+        Some(TimeSpan::from_seconds(6.0))
+    );
+}
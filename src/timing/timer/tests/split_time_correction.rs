@@ -0,0 +1,147 @@
+use super::timer;
+use crate::tests_helper::{make_progress_run_with_splits_opt, span, start_run};
+use crate::timing::LaterSplitTimes::{Clear, Keep, Shift};
+use crate::timing::SplitTimeError;
+use crate::TimingMethod;
+
+#[test]
+fn correcting_a_split_updates_the_best_segments() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(12.0), Some(15.0)]);
+
+    timer
+        .set_split_time(1, TimingMethod::GameTime, span(9.0), Keep)
+        .unwrap();
+    assert_eq!(
+        timer.run().segment(1).split_time().game_time,
+        Some(span(9.0))
+    );
+    assert_eq!(
+        timer.run().segment(2).split_time().game_time,
+        Some(span(15.0))
+    );
+
+    timer.reset(true);
+
+    let run = timer.run();
+    assert_eq!(
+        run.segment(1).best_segment_time().game_time,
+        Some(span(4.0))
+    );
+    assert_eq!(
+        run.segment(2).best_segment_time().game_time,
+        Some(span(6.0))
+    );
+    assert_eq!(
+        run.segment(2).personal_best_split_time().game_time,
+        Some(span(15.0))
+    );
+}
+
+#[test]
+fn split_times_need_to_stay_in_order() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), None, Some(15.0)]);
+
+    assert_eq!(
+        timer.set_split_time(2, TimingMethod::GameTime, span(4.0), Keep),
+        Err(SplitTimeError::OutOfOrder)
+    );
+    assert_eq!(
+        timer.set_split_time(0, TimingMethod::GameTime, span(16.0), Keep),
+        Err(SplitTimeError::OutOfOrder)
+    );
+
+    // Skipped segments can be assigned a split time as well.
+    timer
+        .set_split_time(1, TimingMethod::GameTime, span(10.0), Keep)
+        .unwrap();
+    assert_eq!(
+        timer.run().segment(1).split_time().game_time,
+        Some(span(10.0))
+    );
+}
+
+#[test]
+fn only_completed_segments_can_be_corrected() {
+    let mut timer = timer();
+
+    assert_eq!(
+        timer.set_split_time(0, TimingMethod::GameTime, span(1.0), Keep),
+        Err(SplitTimeError::NoAttemptInProgress)
+    );
+
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0)]);
+
+    assert_eq!(
+        timer.set_split_time(1, TimingMethod::GameTime, span(6.0), Keep),
+        Err(SplitTimeError::SegmentNotCompleted)
+    );
+
+    // The split time can't be ahead of the current time.
+    assert_eq!(
+        timer.set_split_time(0, TimingMethod::GameTime, span(6.0), Keep),
+        Err(SplitTimeError::OutOfOrder)
+    );
+    timer
+        .set_split_time(0, TimingMethod::GameTime, span(4.0), Keep)
+        .unwrap();
+}
+
+#[test]
+fn later_split_times_can_be_shifted() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(12.0)]);
+
+    // The shifted split times can't be ahead of the current time.
+    assert_eq!(
+        timer.set_split_time(0, TimingMethod::GameTime, span(6.0), Shift),
+        Err(SplitTimeError::OutOfOrder)
+    );
+    timer
+        .set_split_time(0, TimingMethod::GameTime, span(4.0), Shift)
+        .unwrap();
+    make_progress_run_with_splits_opt(&mut timer, &[Some(15.0)]);
+
+    timer
+        .set_split_time(0, TimingMethod::GameTime, span(13.0), Shift)
+        .unwrap();
+    timer
+        .set_split_time(1, TimingMethod::GameTime, span(17.0), Shift)
+        .unwrap();
+    let split_times = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time().game_time)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        split_times,
+        [Some(span(13.0)), Some(span(17.0)), Some(span(21.0))]
+    );
+}
+
+#[test]
+fn later_split_times_out_of_order_can_be_cleared() {
+    let mut timer = timer();
+    start_run(&mut timer);
+    make_progress_run_with_splits_opt(&mut timer, &[Some(5.0), Some(12.0), Some(15.0)]);
+
+    timer
+        .set_split_time(0, TimingMethod::GameTime, span(13.0), Clear)
+        .unwrap();
+    let split_times = timer
+        .run()
+        .segments()
+        .iter()
+        .map(|s| s.split_time().game_time)
+        .collect::<Vec<_>>();
+    assert_eq!(split_times, [Some(span(13.0)), None, Some(span(15.0))]);
+
+    // The Real Time split times aren't affected.
+    assert!(timer.run().segment(1).split_time().real_time.is_some());
+}
@@ -191,6 +191,33 @@ where
     }
 }
 
+/// Parses an image the way the original LiveSplit stores them, which is as a
+/// Base64 encoded, serialized .NET object. If the image can't be decoded, an
+/// empty image is passed on instead.
+pub fn image<R, F, E>(
+    reader: &mut Reader<R>,
+    result: &mut Vec<u8>,
+    image_buf: &mut Vec<u8>,
+    f: F,
+) -> Result<(), E>
+where
+    R: BufRead,
+    F: FnOnce(&[u8]),
+    E: From<Error>,
+{
+    text_as_bytes_err(reader, result, |text| {
+        if text.len() >= 216 {
+            image_buf.clear();
+            if base64::decode_config_buf(&text[212..], base64::STANDARD, image_buf).is_ok() {
+                f(&image_buf[2..image_buf.len() - 1]);
+                return Ok(());
+            }
+        }
+        f(&[]);
+        Ok(())
+    })
+}
+
 fn end_tag_immediately<R, E>(reader: &mut Reader<R>, buf: &mut Vec<u8>) -> Result<(), E>
 where
     R: BufRead,
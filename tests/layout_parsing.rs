@@ -30,6 +30,53 @@ mod parse {
         livesplit("tests/layout_files/WSplit.lsl");
     }
 
+    #[test]
+    fn counter() {
+        use livesplit_core::layout::Component;
+
+        let layout = livesplit("tests/layout_files/Counter.lsl");
+        let component = match &layout.components[0] {
+            Component::Counter(component) => component,
+            _ => panic!("Expected a Counter Component"),
+        };
+        let settings = component.settings();
+        assert_eq!(settings.text, "Deaths:");
+        assert_eq!(settings.start_value, 3);
+        assert_eq!(settings.step, 2);
+        // LiveSplit stores the names of the keys on Windows, which mostly don't
+        // match the names of the keys on the other platforms.
+        #[cfg(windows)]
+        {
+            use livesplit_core::hotkey::KeyCode;
+            assert_eq!(settings.increment_key, Some(KeyCode::Add));
+            assert_eq!(settings.decrement_key, Some(KeyCode::Subtract));
+            assert_eq!(settings.reset_key, Some(KeyCode::Multiply));
+        }
+        assert!(settings.value_color.is_some());
+        assert_eq!(component.counter().value(), 3);
+    }
+
+    #[test]
+    fn image() {
+        use livesplit_core::layout::Component;
+        use livesplit_core::settings::Gradient;
+
+        let layout = livesplit("tests/layout_files/Image.lsl");
+        // The Auto Splitting component doesn't show an image, so it's skipped.
+        assert_eq!(layout.components.len(), 1);
+        let component = match &layout.components[0] {
+            Component::Image(component) => component,
+            _ => panic!("Expected an Image Component"),
+        };
+        let settings = component.settings();
+        assert_eq!(settings.height, 120);
+        assert!(settings.image.data().starts_with(b"\x89PNG"));
+        match settings.background {
+            Gradient::Plain(_) => {}
+            _ => panic!("Expected a plain background"),
+        }
+    }
+
     #[test]
     fn assert_order_of_default_columns() {
         use livesplit_core::component::splits;